@@ -1,23 +1,45 @@
 use std::collections::HashMap;
 use std::env;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{anyhow, Context, Result};
-use tracing::warn;
-
-use crate::domain::commands::{PlayTarget, SlashCommand};
-use crate::domain::models::{Station, StationFilters, StationSearchQuery, StationSort};
+use tracing::{debug, warn};
+
+use crate::domain::actions::{self, Action};
+use crate::domain::columns::{self, Column, DEFAULT_COLUMNS};
+use crate::domain::commands::{FilterUpdate, PlayTarget, SlashCommand};
+use crate::domain::completion::{self, CompletionSources};
+use crate::domain::help::{self, HelpLine};
+use crate::domain::keymap::{KeyChord, Keymap};
+use crate::domain::models::{
+    Facet, FacetValue, Station, StationFilters, StationSearchQuery, StationSort,
+};
 use crate::domain::palette::{fuzzy_filter, PaletteItem};
-use crate::integrations::playback::{PlaybackController, PlaybackState};
+use crate::integrations::catalog_worker::{CatalogResponse, CatalogResult, CatalogWorker};
+use crate::integrations::playback::{PlaybackController, PlaybackEvent, PlaybackState};
+use crate::integrations::playback_factory::build_playback_controller;
 use crate::integrations::station_catalog::{RadioBrowserCatalog, StaticCatalog, StationCatalog};
-use crate::integrations::vlc_process::VlcProcessController;
-use crate::storage::config::RuntimeConfig;
+use crate::logging;
+use crate::storage::config::{LogFormat, RadioBrowserConfig, RuntimeConfig};
 use crate::storage::favorites::FavoritesStore;
+use crate::storage::history::{HistoryEntry, HistoryStore, MAX_HISTORY_ENTRIES};
+use crate::storage::searches::{SavedSearch, SearchesStore};
+use crate::storage::station_cache::{StationCache, DEFAULT_STATION_CACHE_TTL};
 use crate::ui::Tui;
 
+const SEARCH_PAGE_SIZE: usize = 50;
+/// Fetch the next page once the selection is this close to the end of the list.
+const LOAD_MORE_THRESHOLD: usize = 5;
+/// Rows moved by PageUp/PageDown until the UI reports the real list height.
+const DEFAULT_LIST_PAGE: usize = 10;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Focus {
     Search,
+    /// The results (or browse) list; plain-character bindings like `q` and `j` live here.
+    Results,
     Slash,
     Palette,
 }
@@ -26,6 +48,7 @@ impl Focus {
     pub fn label(self) -> &'static str {
         match self {
             Self::Search => "Search",
+            Self::Results => "Results",
             Self::Slash => "Slash",
             Self::Palette => "Palette",
         }
@@ -36,6 +59,7 @@ impl Focus {
 pub enum ResultsSource {
     Stations,
     Favorites,
+    History,
 }
 
 impl ResultsSource {
@@ -43,14 +67,107 @@ impl ResultsSource {
         match self {
             Self::Stations => "Stations",
             Self::Favorites => "Favorites",
+            Self::History => "History",
         }
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct AppDefaults {
     pub sort: StationSort,
     pub filters: StationFilters,
+    /// Presets from the config file; `/load` also finds these.
+    pub searches: Vec<SavedSearch>,
+    pub columns: Vec<Column>,
+}
+
+impl Default for AppDefaults {
+    fn default() -> Self {
+        Self {
+            sort: StationSort::default(),
+            filters: StationFilters::default(),
+            searches: Vec::new(),
+            columns: DEFAULT_COLUMNS.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AppOptions {
+    /// Run catalog searches on a worker thread; results are applied by `App::tick`.
+    pub background_search: bool,
+    /// Cached station metadata older than this is refreshed when favorites are opened.
+    pub station_cache_ttl: Duration,
+    pub keymap: Keymap,
+}
+
+impl Default for AppOptions {
+    fn default() -> Self {
+        Self {
+            background_search: false,
+            station_cache_ttl: DEFAULT_STATION_CACHE_TTL,
+            keymap: Keymap::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchPurpose {
+    Startup,
+    Refresh,
+    Search,
+    Filter,
+    ClearFilters,
+    Sort,
+    LoadSearch,
+    More,
+}
+
+/// Next/Previous wrap around the list; paging and First/Last stop at its ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelectionMove {
+    Next,
+    Previous,
+    PageDown,
+    PageUp,
+    First,
+    Last,
+}
+
+impl SelectionMove {
+    fn apply(self, current: usize, len: usize, page: usize) -> usize {
+        match self {
+            Self::Next => (current + 1) % len,
+            Self::Previous => (current + len - 1) % len,
+            Self::PageDown => (current + page).min(len - 1),
+            Self::PageUp => current.saturating_sub(page),
+            Self::First => 0,
+            Self::Last => len - 1,
+        }
+    }
+}
+
+/// Full-screen help opened by `/help`; `scroll` is the first visible line.
+#[derive(Debug, Clone)]
+pub struct HelpOverlay {
+    pub title: String,
+    pub lines: Vec<HelpLine>,
+    pub scroll: usize,
+}
+
+/// Facet values listed by `/browse`; choosing one applies it as a filter.
+#[derive(Debug, Clone)]
+pub struct BrowseView {
+    pub facet: Facet,
+    /// Narrows the list; plain characters are typed here while it is open.
+    pub query: String,
+    pub selected: usize,
+}
+
+struct PendingSearch {
+    id: u64,
+    purpose: SearchPurpose,
+    query: StationSearchQuery,
 }
 
 pub struct App {
@@ -66,17 +183,42 @@ pub struct App {
     results_source: ResultsSource,
     palette_selected_index: usize,
     filtered: Vec<Station>,
+    next_offset: usize,
+    has_more_results: bool,
     favorites: Vec<String>,
     favorites_view: Vec<Station>,
-    station_cache: HashMap<String, Station>,
+    history: Vec<HistoryEntry>,
+    history_view: Vec<Station>,
+    station_cache: StationCache,
     filters: StationFilters,
     sort: StationSort,
+    sort_descending: bool,
+    columns: Vec<Column>,
     pending_volume: Option<u8>,
     now_playing: Option<Station>,
+    stream_title: Option<String>,
+    last_playback_error: Option<String>,
     palette_items: Vec<PaletteItem>,
+    keymap: Keymap,
+    help: Option<HelpOverlay>,
+    browse: Option<BrowseView>,
+    list_page: usize,
+    /// Facet values for slash completion, fetched once per facet on first use.
+    facet_values: HashMap<Facet, Vec<FacetValue>>,
+    loading_facets: Vec<Facet>,
     playback: Box<dyn PlaybackController>,
     favorites_store: FavoritesStore,
-    station_catalog: Box<dyn StationCatalog>,
+    history_store: HistoryStore,
+    saved_searches: Vec<SavedSearch>,
+    search_presets: Vec<SavedSearch>,
+    searches_store: SearchesStore,
+    station_catalog: Arc<dyn StationCatalog>,
+    catalog_worker: Option<CatalogWorker>,
+    next_request_id: u64,
+    pending_search: Option<PendingSearch>,
+    pending_lookup: Option<u64>,
+    /// Request id and station name of the vote awaiting the server's reply.
+    pending_vote: Option<(u64, String)>,
 }
 
 impl App {
@@ -110,9 +252,69 @@ impl App {
         station_catalog: Box<dyn StationCatalog>,
         defaults: AppDefaults,
     ) -> Result<Self> {
+        Self::new_with_options(
+            playback,
+            favorites_store,
+            station_catalog,
+            defaults,
+            AppOptions::default(),
+        )
+    }
+
+    pub fn new_with_options(
+        playback: Box<dyn PlaybackController>,
+        favorites_store: FavoritesStore,
+        station_catalog: Box<dyn StationCatalog>,
+        defaults: AppDefaults,
+        options: AppOptions,
+    ) -> Result<Self> {
+        let station_catalog: Arc<dyn StationCatalog> = Arc::from(station_catalog);
+        let catalog_worker = if options.background_search {
+            Some(CatalogWorker::spawn(station_catalog.clone())?)
+        } else {
+            None
+        };
         let favorites = favorites_store
             .load()
             .context("load favorites on startup")?;
+        // Problems with the files below are reported once the app is up instead of
+        // refusing to launch.
+        let mut startup_notices = Vec::new();
+        let history_store = HistoryStore::beside(favorites_store.path());
+        let history = history_store.load().unwrap_or_else(|err| {
+            warn!(error = ?err, "ignoring unreadable track history");
+            startup_notices.push(format!("Track history unreadable, starting empty: {err}"));
+            Vec::new()
+        });
+        let searches_store = SearchesStore::beside(favorites_store.path());
+        let saved_searches = match searches_store.load_with_warnings() {
+            Ok((searches, warnings)) => {
+                for warning in &warnings {
+                    warn!(%warning, "ignoring invalid saved search");
+                }
+                if !warnings.is_empty() {
+                    startup_notices.push(format!(
+                        "Skipped {} invalid saved search(es) in {}",
+                        warnings.len(),
+                        searches_store.path().display()
+                    ));
+                }
+                searches
+            }
+            Err(err) => {
+                warn!(error = ?err, "ignoring unreadable saved searches");
+                startup_notices.push(format!("Saved searches unreadable: {err}"));
+                Vec::new()
+            }
+        };
+        let mut station_cache =
+            StationCache::beside(favorites_store.path(), options.station_cache_ttl);
+        if let Err(err) = station_cache.load() {
+            // The cache is disposable; start cold rather than refusing to launch.
+            warn!(error = ?err, "ignoring unreadable station cache");
+            startup_notices.push(format!("Station cache unreadable: {err}"));
+            station_cache = StationCache::beside(favorites_store.path(), options.station_cache_ttl);
+        }
 
         let mut app = Self {
             running: true,
@@ -127,23 +329,50 @@ impl App {
             results_source: ResultsSource::Stations,
             palette_selected_index: 0,
             filtered: Vec::new(),
+            next_offset: 0,
+            has_more_results: false,
             favorites,
             favorites_view: Vec::new(),
-            station_cache: HashMap::new(),
+            history,
+            history_view: Vec::new(),
+            station_cache,
             filters: defaults.filters,
             sort: defaults.sort,
+            sort_descending: defaults.sort.descending_by_default(),
+            columns: defaults.columns,
             pending_volume: None,
             now_playing: None,
-            palette_items: default_palette_items(),
+            stream_title: None,
+            last_playback_error: None,
+            palette_items: Vec::new(),
+            keymap: options.keymap,
+            help: None,
+            list_page: DEFAULT_LIST_PAGE,
+            browse: None,
+            facet_values: HashMap::new(),
+            loading_facets: Vec::new(),
             playback,
             favorites_store,
+            history_store,
+            saved_searches,
+            search_presets: defaults.searches,
+            searches_store,
             station_catalog,
+            catalog_worker,
+            next_request_id: 0,
+            pending_search: None,
+            pending_lookup: None,
+            pending_vote: None,
         };
 
-        if let Err(err) = app.refresh_stations() {
+        app.refresh_favorites_view();
+        app.refresh_history_view();
+        app.refresh_palette_items();
+        if let Err(err) = app.start_search(SearchPurpose::Startup) {
             app.status_message = format!("Station discovery unavailable: {err}");
-        } else {
-            app.status_message = format!("Loaded {} stations", app.filtered.len());
+        }
+        if !startup_notices.is_empty() {
+            app.status_message = startup_notices.join(" | ");
         }
 
         Ok(app)
@@ -153,9 +382,19 @@ impl App {
         match self.results_source {
             ResultsSource::Stations => &self.filtered,
             ResultsSource::Favorites => &self.favorites_view,
+            ResultsSource::History => &self.history_view,
         }
     }
 
+    /// History entry backing row `index` of the History view (newest first).
+    pub fn history_entry(&self, index: usize) -> Option<&HistoryEntry> {
+        self.history.iter().rev().nth(index)
+    }
+
+    pub fn results_source(&self) -> ResultsSource {
+        self.results_source
+    }
+
     pub fn selected_station(&self) -> Option<&Station> {
         self.visible_stations().get(self.selected_index)
     }
@@ -174,10 +413,27 @@ impl App {
         self.playback.state()
     }
 
+    pub fn stream_title(&self) -> Option<&str> {
+        self.stream_title.as_deref()
+    }
+
+    pub fn last_playback_error(&self) -> Option<&str> {
+        self.last_playback_error.as_deref()
+    }
+
     pub fn sort(&self) -> StationSort {
         self.sort
     }
 
+    pub fn sort_descending(&self) -> bool {
+        self.sort_descending
+    }
+
+    /// Results table columns, in display order.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
     pub fn filters(&self) -> &StationFilters {
         &self.filters
     }
@@ -188,7 +444,10 @@ impl App {
 
     pub fn current_input(&self) -> String {
         match self.focus {
-            Focus::Search => self.search_input.clone(),
+            Focus::Search | Focus::Results => match &self.browse {
+                Some(browse) => browse.query.clone(),
+                None => self.search_input.clone(),
+            },
             Focus::Slash => self.slash_input.clone(),
             Focus::Palette => self.palette_input.clone(),
         }
@@ -198,10 +457,64 @@ impl App {
         self.results_source.label()
     }
 
+    /// The filters behind the station results in `/filter` syntax, for the results title.
+    pub fn active_filters_label(&self) -> Option<String> {
+        (self.results_source == ResultsSource::Stations && !self.filters.is_empty())
+            .then(|| self.filters.to_string())
+    }
+
     pub fn search_dirty(&self) -> bool {
         self.search_dirty
     }
 
+    pub fn is_searching(&self) -> bool {
+        self.pending_search.is_some()
+    }
+
+    pub fn has_more_results(&self) -> bool {
+        self.has_more_results
+    }
+
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    /// Action bound to `chord` in the current focus. Plain characters trigger bindings in the
+    /// Results focus and in an empty Search box; once a search is being typed they are text, so
+    /// finishing a station name never quits or stops playback.
+    pub fn key_action_for(&self, chord: &KeyChord) -> Option<Action> {
+        if self.help.is_some() {
+            return None;
+        }
+        let action = self.keymap.action_for(chord)?;
+        if chord.is_text() && self.is_editing_text() {
+            return None;
+        }
+        if self.focus == Focus::Slash
+            && matches!(
+                action,
+                Action::SelectNext
+                    | Action::SelectPrevious
+                    | Action::SelectPageDown
+                    | Action::SelectPageUp
+                    | Action::SelectFirst
+                    | Action::SelectLast
+            )
+        {
+            return None;
+        }
+        Some(action)
+    }
+
+    fn is_editing_text(&self) -> bool {
+        match self.focus {
+            // The browse list is narrowed by typing, whichever of its panes is focused.
+            Focus::Results => self.browse.is_some(),
+            Focus::Search => self.browse.is_some() || !self.search_input.is_empty(),
+            Focus::Slash | Focus::Palette => true,
+        }
+    }
+
     pub fn palette_selected_index(&self) -> usize {
         self.palette_selected_index
     }
@@ -216,7 +529,8 @@ impl App {
 
     pub fn toggle_focus(&mut self) {
         let next_focus = match self.focus {
-            Focus::Search => Focus::Slash,
+            Focus::Search => Focus::Results,
+            Focus::Results => Focus::Slash,
             Focus::Slash => Focus::Palette,
             Focus::Palette => Focus::Search,
         };
@@ -226,7 +540,8 @@ impl App {
     pub fn toggle_focus_backward(&mut self) {
         let prev_focus = match self.focus {
             Focus::Search => Focus::Palette,
-            Focus::Slash => Focus::Search,
+            Focus::Results => Focus::Search,
+            Focus::Slash => Focus::Results,
             Focus::Palette => Focus::Slash,
         };
         self.set_focus(prev_focus);
@@ -255,22 +570,227 @@ impl App {
         }
     }
 
+    /// Tab in the slash input: completes the command, filter key or argument being typed.
+    /// Returns false when there is nothing to complete so Tab can keep its binding.
+    pub fn complete_slash_input(&mut self) -> bool {
+        self.prefetch_facet_values();
+        let completion = completion::complete(&self.slash_input, &self.completion_sources());
+        if completion.candidates.is_empty() {
+            return match completion::wanted_facet(&self.slash_input) {
+                Some(facet) if self.loading_facets.contains(&facet) => {
+                    self.status_message = format!("Loading {} for completion…", facet.plural());
+                    true
+                }
+                _ => false,
+            };
+        }
+
+        if let Some(completed) = completion.apply(&self.slash_input) {
+            self.slash_input = completed;
+        } else {
+            const SHOWN: usize = 8;
+            let mut listed =
+                completion.candidates[..completion.candidates.len().min(SHOWN)].join(", ");
+            if completion.candidates.len() > SHOWN {
+                listed.push_str(&format!(
+                    ", … ({} more)",
+                    completion.candidates.len() - SHOWN
+                ));
+            }
+            self.status_message = format!("Completions: {listed}");
+        }
+        true
+    }
+
+    /// Ghost text after the slash input: the rest of the completion or the expected arguments.
+    pub fn slash_hint(&self) -> Option<String> {
+        if self.focus != Focus::Slash {
+            return None;
+        }
+        completion::hint(&self.slash_input, &self.completion_sources())
+    }
+
+    fn completion_sources(&self) -> CompletionSources<'_> {
+        CompletionSources {
+            station_names: self
+                .favorites_view
+                .iter()
+                .map(|station| station.name.as_str())
+                .collect(),
+            search_names: self.search_names(),
+            facets: &self.facet_values,
+        }
+    }
+
+    /// Starts fetching the values `/filter` is about to need, if they are not cached yet.
+    fn prefetch_facet_values(&mut self) {
+        if let Some(facet) = completion::wanted_facet(&self.slash_input) {
+            self.load_facet_values(facet);
+        }
+    }
+
+    /// Fetches the values of `facet` unless they are cached or already on their way.
+    fn load_facet_values(&mut self, facet: Facet) {
+        if self.facet_values.contains_key(&facet) || self.loading_facets.contains(&facet) {
+            return;
+        }
+        let Some(worker) = &self.catalog_worker else {
+            let result = self.station_catalog.facet_values(facet);
+            self.apply_facet_values(facet, result);
+            return;
+        };
+        self.next_request_id += 1;
+        match worker.submit_facets(self.next_request_id, facet) {
+            Ok(()) => self.loading_facets.push(facet),
+            Err(err) => self.status_message = format!("Error: {err}"),
+        }
+    }
+
+    fn apply_facet_values(&mut self, facet: Facet, result: Result<Vec<FacetValue>>) {
+        self.loading_facets.retain(|loading| *loading != facet);
+        match result {
+            Ok(values) => {
+                debug!(
+                    facet = facet.plural(),
+                    count = values.len(),
+                    "loaded facet values"
+                );
+                self.facet_values.insert(facet, values);
+                if self
+                    .browse
+                    .as_ref()
+                    .is_some_and(|browse| browse.facet == facet)
+                {
+                    self.status_message = self.browse_status();
+                }
+            }
+            Err(err) => {
+                // Only completion and `/browse` need these; filters can still be typed in full.
+                warn!(error = ?err, facet = facet.plural(), "failed to load facet values");
+                self.status_message = format!("Could not load {}: {err}", facet.plural());
+            }
+        }
+    }
+
+    pub fn browse(&self) -> Option<&BrowseView> {
+        self.browse.as_ref()
+    }
+
+    pub fn is_loading_facet(&self, facet: Facet) -> bool {
+        self.loading_facets.contains(&facet)
+    }
+
+    /// Values of the browsed facet containing its query, most stations first.
+    pub fn browse_values(&self) -> Vec<&FacetValue> {
+        let Some(browse) = &self.browse else {
+            return Vec::new();
+        };
+        let query = browse.query.to_lowercase();
+        self.facet_values
+            .get(&browse.facet)
+            .map(|values| {
+                values
+                    .iter()
+                    .filter(|value| value.name.to_lowercase().contains(&query))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn browse_status(&self) -> String {
+        let Some(browse) = &self.browse else {
+            return String::new();
+        };
+        format!(
+            "Browsing {} ({}): type to narrow, Enter filters, Esc closes",
+            browse.facet.plural(),
+            self.browse_values().len()
+        )
+    }
+
+    /// Adds the highlighted browse value to the current filters and searches.
+    fn apply_browse_selection(&mut self) -> Result<()> {
+        let Some(browse) = &self.browse else {
+            return Ok(());
+        };
+        let facet = browse.facet;
+        let value = self
+            .browse_values()
+            .get(browse.selected)
+            .map(|value| value.name.clone())
+            .ok_or_else(|| {
+                anyhow!(
+                    "no {} matches '{}'; Backspace to widen",
+                    facet.filter_key(),
+                    browse.query
+                )
+            })?;
+        let mut set = StationFilters::default();
+        set.set(facet, value);
+        self.execute_command(SlashCommand::Filter(Box::new(FilterUpdate {
+            incremental: true,
+            set,
+            remove: Vec::new(),
+        })))
+    }
+
+    fn show_results(&mut self, source: ResultsSource) {
+        self.results_source = source;
+        self.browse = None;
+    }
+
+    pub fn help_overlay(&self) -> Option<&HelpOverlay> {
+        self.help.as_ref()
+    }
+
+    /// Scrolls the help overlay by `delta` lines, keeping a `viewport`-high page filled.
+    pub fn scroll_help(&mut self, delta: isize, viewport: usize) {
+        if let Some(help) = self.help.as_mut() {
+            let max = help.lines.len().saturating_sub(viewport);
+            help.scroll = help.scroll.saturating_add_signed(delta).min(max);
+        }
+    }
+
     pub fn close_overlays(&mut self) {
+        if self.help.take().is_some() {
+            self.status_message = format!("Focus: {}", self.focus.label());
+            return;
+        }
         if self.focus == Focus::Palette {
             self.focus = self.focus_before_palette;
             self.palette_input.clear();
             self.palette_selected_index = 0;
             self.status_message = format!("Focus: {}", self.focus.label());
+        } else if self.browse.take().is_some() {
+            self.status_message = "Browse closed".to_string();
         }
     }
 
     pub fn push_char(&mut self, c: char) {
         match self.focus {
+            Focus::Search | Focus::Results if self.browse.is_some() => {
+                if let Some(browse) = self.browse.as_mut() {
+                    browse.query.push(c);
+                    browse.selected = 0;
+                }
+                self.status_message = self.browse_status();
+            }
             Focus::Search => {
                 self.search_input.push(c);
                 self.search_dirty = true;
+                self.cancel_pending_search();
+            }
+            // Unbound characters in the list start editing the search instead of being lost.
+            Focus::Results => {
+                self.set_focus(Focus::Search);
+                self.push_char(c);
+            }
+            Focus::Slash => {
+                self.slash_input.push(c);
+                if c == '=' {
+                    self.prefetch_facet_values();
+                }
             }
-            Focus::Slash => self.slash_input.push(c),
             Focus::Palette => {
                 self.palette_input.push(c);
                 self.palette_selected_index = 0;
@@ -280,9 +800,21 @@ impl App {
 
     pub fn backspace_input(&mut self) {
         match self.focus {
+            Focus::Search | Focus::Results if self.browse.is_some() => {
+                if let Some(browse) = self.browse.as_mut() {
+                    browse.query.pop();
+                    browse.selected = 0;
+                }
+                self.status_message = self.browse_status();
+            }
             Focus::Search => {
                 self.search_input.pop();
                 self.search_dirty = true;
+                self.cancel_pending_search();
+            }
+            Focus::Results => {
+                self.set_focus(Focus::Search);
+                self.backspace_input();
             }
             Focus::Slash => {
                 self.slash_input.pop();
@@ -296,20 +828,18 @@ impl App {
 
     pub fn submit_current_input(&mut self) -> Result<()> {
         match self.focus {
-            Focus::Search => {
-                if self.search_dirty {
-                    self.results_source = ResultsSource::Stations;
-                    self.refresh_stations()?;
-                    self.search_dirty = false;
-                    self.status_message = format!(
-                        "Search refreshed ({} results, sort={})",
-                        self.filtered.len(),
-                        sort_label(self.sort)
-                    );
-                    Ok(())
-                } else {
-                    self.execute_command(SlashCommand::Play(PlayTarget::Selected))
-                }
+            Focus::Search | Focus::Results if self.browse.is_some() => {
+                self.apply_browse_selection()
+            }
+            Focus::Search if self.search_dirty => {
+                self.results_source = ResultsSource::Stations;
+                self.search_dirty = false;
+                // Move to the list so `j`, `k`, Space and friends work on the new results.
+                self.set_focus(Focus::Results);
+                self.start_search(SearchPurpose::Refresh)
+            }
+            Focus::Search | Focus::Results => {
+                self.execute_command(SlashCommand::Play(PlayTarget::Selected))
             }
             Focus::Slash => {
                 let cmd = self.slash_input.clone();
@@ -331,32 +861,47 @@ impl App {
     }
 
     pub fn select_next(&mut self) {
-        if self.focus == Focus::Palette {
-            let len = self.palette_results().len();
-            if len == 0 {
-                return;
-            }
-            self.palette_selected_index = (self.palette_selected_index + 1) % len;
-            return;
-        }
-
-        let len = self.visible_stations().len();
-        if len == 0 {
-            return;
-        }
-        self.selected_index = (self.selected_index + 1) % len;
+        self.move_selection(SelectionMove::Next);
     }
 
     pub fn select_previous(&mut self) {
+        self.move_selection(SelectionMove::Previous);
+    }
+
+    pub fn select_page_down(&mut self) {
+        self.move_selection(SelectionMove::PageDown);
+    }
+
+    pub fn select_page_up(&mut self) {
+        self.move_selection(SelectionMove::PageUp);
+    }
+
+    pub fn select_first(&mut self) {
+        self.move_selection(SelectionMove::First);
+    }
+
+    pub fn select_last(&mut self) {
+        self.move_selection(SelectionMove::Last);
+    }
+
+    /// Rows visible in the results or browse list; PageUp/PageDown move by this much.
+    pub fn set_list_page(&mut self, rows: usize) {
+        self.list_page = rows.max(1);
+    }
+
+    fn move_selection(&mut self, step: SelectionMove) {
+        let page = self.list_page;
         if self.focus == Focus::Palette {
             let len = self.palette_results().len();
-            if len == 0 {
-                return;
+            if len > 0 {
+                self.palette_selected_index = step.apply(self.palette_selected_index, len, page);
             }
-            if self.palette_selected_index == 0 {
-                self.palette_selected_index = len - 1;
-            } else {
-                self.palette_selected_index -= 1;
+            return;
+        }
+        if self.browse.is_some() {
+            let len = self.browse_values().len();
+            if let Some(browse) = self.browse.as_mut().filter(|_| len > 0) {
+                browse.selected = step.apply(browse.selected, len, page);
             }
             return;
         }
@@ -365,11 +910,8 @@ impl App {
         if len == 0 {
             return;
         }
-        if self.selected_index == 0 {
-            self.selected_index = len - 1;
-        } else {
-            self.selected_index -= 1;
-        }
+        self.selected_index = step.apply(self.selected_index, len, page);
+        self.load_more_if_near_end();
     }
 
     pub fn toggle_selected_favorite(&mut self) -> Result<()> {
@@ -411,14 +953,185 @@ impl App {
         self.playback.shutdown()
     }
 
-    fn cache_station(&mut self, station: &Station) {
-        self.station_cache
-            .insert(station.station_uuid.clone(), station.clone());
+    pub fn tick(&mut self) {
+        for event in self.playback.poll_events() {
+            self.handle_playback_event(event);
+        }
+
+        let responses: Vec<CatalogResponse> = self
+            .catalog_worker
+            .as_ref()
+            .map(|worker| std::iter::from_fn(|| worker.try_recv()).collect())
+            .unwrap_or_default();
+        for response in responses {
+            self.handle_catalog_response(response);
+        }
+    }
+
+    fn handle_catalog_response(&mut self, response: CatalogResponse) {
+        let result = match response.result {
+            CatalogResult::Facets(facet, result) => {
+                self.apply_facet_values(facet, result);
+                return;
+            }
+            CatalogResult::Stations(result) => result,
+            CatalogResult::Click(result) => {
+                if let Err(err) = result {
+                    warn!(error = ?err, "failed to register station click");
+                }
+                return;
+            }
+            CatalogResult::Vote(result) => {
+                if let Some((id, name)) = self.pending_vote.take() {
+                    if id == response.id {
+                        self.apply_vote_result(&name, result);
+                    } else {
+                        self.pending_vote = Some((id, name));
+                    }
+                }
+                return;
+            }
+        };
+        if self.pending_lookup == Some(response.id) {
+            self.pending_lookup = None;
+            self.apply_favorites_lookup(result);
+            return;
+        }
+
+        let is_current = self
+            .pending_search
+            .as_ref()
+            .is_some_and(|pending| pending.id == response.id);
+        if !is_current {
+            debug!(id = response.id, "discarding stale station search response");
+            return;
+        }
+        let Some(pending) = self.pending_search.take() else {
+            return;
+        };
+        if let Err(err) = self.apply_search_result(pending.purpose, &pending.query, result) {
+            self.status_message = match pending.purpose {
+                SearchPurpose::Startup => format!("Station discovery unavailable: {err}"),
+                _ => format!("Error: {err}"),
+            };
+        }
+    }
+
+    /// Counts a play on Radio Browser. Failures only cost a click, so they are logged and
+    /// never reach the status bar.
+    fn register_click(&mut self, station: &Station) {
+        let Some(worker) = &self.catalog_worker else {
+            if let Err(err) = self.station_catalog.register_click(&station.station_uuid) {
+                warn!(error = ?err, "failed to register station click");
+            }
+            return;
+        };
+        self.next_request_id += 1;
+        if let Err(err) = worker.submit_click(self.next_request_id, station.station_uuid.clone()) {
+            warn!(error = ?err, "failed to queue station click");
+        }
+    }
+
+    fn apply_vote_result(&mut self, name: &str, result: Result<String>) {
+        self.status_message = match result {
+            Ok(message) => format!("Voted for {name}: {message}"),
+            Err(err) => {
+                warn!(error = ?err, station = name, "station vote failed");
+                format!("Vote for {name} failed: {err}")
+            }
+        };
+    }
+
+    fn handle_playback_event(&mut self, event: PlaybackEvent) {
+        match event {
+            PlaybackEvent::StateChanged(state) => {
+                let Some(station) = &self.now_playing else {
+                    return;
+                };
+                match state {
+                    PlaybackState::Playing => {
+                        self.status_message = format!("Playing {}", station.name);
+                    }
+                    PlaybackState::Buffering => {
+                        self.status_message = format!("Buffering {}...", station.name);
+                    }
+                    _ => {}
+                }
+            }
+            PlaybackEvent::StreamTitle(title) => {
+                let Some(station) = &self.now_playing else {
+                    return;
+                };
+                let entry = HistoryEntry::new(&station.station_uuid, &station.name, &title);
+                self.stream_title = Some(title);
+                self.record_history(entry);
+            }
+            PlaybackEvent::Error(error) => {
+                warn!(error = %error, "playback error reported by backend");
+                self.status_message = format!("Playback error: {error}");
+                self.last_playback_error = Some(error);
+            }
+        }
+    }
+
+    fn record_history(&mut self, entry: HistoryEntry) {
+        let repeated = self.history.last().is_some_and(|last| {
+            last.station_uuid == entry.station_uuid && last.title == entry.title
+        });
+        if repeated {
+            return;
+        }
+
+        self.history.push(entry);
+        let overflow = self.history.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        self.history.drain(..overflow);
+        if let Err(err) = self.history_store.save(&self.history) {
+            warn!(error = ?err, "failed to persist track history");
+            self.status_message = format!("Track history not saved: {err}");
+        }
+        self.refresh_history_view();
+    }
+
+    fn refresh_history_view(&mut self) {
+        self.history_view = self
+            .history
+            .iter()
+            .rev()
+            .map(|entry| {
+                self.station_cache
+                    .get(&entry.station_uuid)
+                    .cloned()
+                    .unwrap_or_else(|| Station {
+                        station_uuid: entry.station_uuid.clone(),
+                        name: entry.station_name.clone(),
+                        url_resolved: String::new(),
+                        homepage: None,
+                        favicon: None,
+                        tags: Vec::new(),
+                        country: None,
+                        country_code: None,
+                        language: None,
+                        codec: None,
+                        bitrate: None,
+                        votes: None,
+                        click_count: None,
+                        has_geo_info: false,
+                        last_check_ok: None,
+                        last_change_time: None,
+                    })
+            })
+            .collect();
     }
 
     fn cache_stations(&mut self, stations: &[Station]) {
         for station in stations {
-            self.cache_station(station);
+            self.station_cache.insert(station.clone());
+        }
+        if !self.station_cache.is_dirty() {
+            return;
+        }
+        if let Err(err) = self.station_cache.save(&self.favorites) {
+            warn!(error = ?err, "failed to persist station cache");
         }
     }
 
@@ -426,11 +1139,6 @@ impl App {
         self.favorites_view.clear();
         let mut unresolved = 0usize;
 
-        if let Some(now_playing) = &self.now_playing {
-            self.station_cache
-                .insert(now_playing.station_uuid.clone(), now_playing.clone());
-        }
-
         for id in &self.favorites {
             if let Some(station) = self.station_cache.get(id) {
                 self.favorites_view.push(station.clone());
@@ -451,27 +1159,183 @@ impl App {
         }
     }
 
-    fn refresh_stations(&mut self) -> Result<()> {
-        let stations = self
-            .station_catalog
-            .search(&StationSearchQuery {
-                query: self.search_input.clone(),
-                filters: self.filters.clone(),
-                sort: self.sort,
-                limit: 50,
-            })
-            .with_context(|| {
-                format!(
-                    "search failed (query='{}', sort={})",
-                    self.search_input,
-                    sort_label(self.sort)
-                )
-            })?;
+    /// Runs the current search inline, or hands it to the catalog worker when one is running.
+    /// A newer search supersedes any in-flight one; its response is discarded on arrival.
+    fn start_search(&mut self, purpose: SearchPurpose) -> Result<()> {
+        let query = StationSearchQuery {
+            query: self.search_input.clone(),
+            filters: self.filters.clone(),
+            sort: self.sort,
+            descending: self.sort_descending,
+            limit: SEARCH_PAGE_SIZE,
+            offset: if purpose == SearchPurpose::More {
+                self.next_offset
+            } else {
+                0
+            },
+        };
+
+        let Some(worker) = &self.catalog_worker else {
+            let result = self.station_catalog.search(&query);
+            return self.apply_search_result(purpose, &query, result);
+        };
+
+        self.next_request_id += 1;
+        let id = self.next_request_id;
+        worker.submit_search(id, query.clone())?;
+        self.pending_search = Some(PendingSearch { id, purpose, query });
+        self.status_message = "Searching…".to_string();
+        Ok(())
+    }
+
+    fn load_more_if_near_end(&mut self) {
+        if self.results_source != ResultsSource::Stations
+            || !self.has_more_results
+            || self.is_searching()
+            || self.selected_index + LOAD_MORE_THRESHOLD < self.filtered.len()
+        {
+            return;
+        }
+        if let Err(err) = self.start_search(SearchPurpose::More) {
+            self.status_message = format!("Error: {err}");
+        }
+    }
+
+    /// Looks up favorites that are missing from the station cache or older than its TTL, so
+    /// they can be played without a search. Cached details stay visible while this runs.
+    fn resolve_favorites(&mut self) -> Result<()> {
+        let stale: Vec<String> = self
+            .favorites
+            .iter()
+            .filter(|id| self.station_cache.is_stale(id))
+            .cloned()
+            .collect();
+        if stale.is_empty() {
+            self.status_message = format!("Showing favorites ({})", self.favorites_view.len());
+            return Ok(());
+        }
+
+        let Some(worker) = &self.catalog_worker else {
+            let result = self.station_catalog.lookup(&stale);
+            self.apply_favorites_lookup(result);
+            return Ok(());
+        };
+
+        let missing = stale
+            .iter()
+            .filter(|id| !self.station_cache.contains(id))
+            .count();
+        let count = stale.len();
+        self.next_request_id += 1;
+        let id = self.next_request_id;
+        worker.submit_lookup(id, stale)?;
+        self.pending_lookup = Some(id);
+        let shown = self.favorites_view.len();
+        self.status_message = if missing > 0 {
+            format!("Showing favorites ({shown}) | resolving {missing} favorites…")
+        } else {
+            format!("Showing favorites ({shown}) | refreshing {count} cached favorites…")
+        };
+        Ok(())
+    }
+
+    fn apply_favorites_lookup(&mut self, result: Result<Vec<Station>>) {
+        let error = match result {
+            Ok(stations) => {
+                self.cache_stations(&stations);
+                None
+            }
+            Err(err) => Some(err),
+        };
+        let unresolved = self.refresh_favorites_view();
+        self.refresh_history_view();
+        self.clamp_selected_index();
+
+        if self.results_source != ResultsSource::Favorites {
+            return;
+        }
+        let shown = self.favorites_view.len();
+        self.status_message = match (error, unresolved) {
+            (Some(err), 0) => {
+                format!("Showing favorites ({shown}) | using cached details; refresh failed: {err}")
+            }
+            (Some(err), _) => format!(
+                "Showing favorites ({shown}) | could not resolve {unresolved} favorites: {err}"
+            ),
+            (None, 0) => format!("Showing favorites ({shown})"),
+            (None, missing) => format!(
+                "Showing favorites ({shown}) | {missing} favorites are no longer listed in the station catalog"
+            ),
+        };
+    }
+
+    fn cancel_pending_search(&mut self) {
+        if let Some(pending) = self.pending_search.take() {
+            debug!(id = pending.id, "cancelled in-flight station search");
+            if let Some(worker) = &self.catalog_worker {
+                worker.cancel_search();
+            }
+            self.status_message = "Search cancelled".to_string();
+        }
+    }
+
+    fn apply_search_result(
+        &mut self,
+        purpose: SearchPurpose,
+        query: &StationSearchQuery,
+        result: Result<Vec<Station>>,
+    ) -> Result<()> {
+        let stations = result.with_context(|| {
+            format!(
+                "search failed (query='{}', sort={})",
+                query.query,
+                query.sort.name()
+            )
+        })?;
 
         self.cache_stations(&stations);
-        self.filtered = stations;
+        self.next_offset = query.offset + stations.len();
+        self.has_more_results = stations.len() >= query.limit;
+        let added = if purpose == SearchPurpose::More {
+            let before = self.filtered.len();
+            for station in stations {
+                if !self
+                    .filtered
+                    .iter()
+                    .any(|existing| existing.station_uuid == station.station_uuid)
+                {
+                    self.filtered.push(station);
+                }
+            }
+            self.filtered.len() - before
+        } else {
+            self.filtered = stations;
+            self.filtered.len()
+        };
         self.refresh_favorites_view();
+        self.refresh_history_view();
         self.clamp_selected_index();
+
+        let count = self.filtered.len();
+        self.status_message = match purpose {
+            SearchPurpose::Startup => format!("Loaded {count} stations"),
+            SearchPurpose::Refresh => format!(
+                "Search refreshed ({count} results, sort={})",
+                query.sort.label(query.descending)
+            ),
+            SearchPurpose::Search => format!("Search applied ({count} results)"),
+            SearchPurpose::Filter => format!("Filters applied ({count} results)"),
+            SearchPurpose::ClearFilters => format!("Filters cleared ({count} results)"),
+            SearchPurpose::Sort => {
+                format!(
+                    "Sort applied: {} ({count} results)",
+                    query.sort.label(query.descending)
+                )
+            }
+            SearchPurpose::LoadSearch => format!("Saved search loaded ({count} results)"),
+            SearchPurpose::More if added == 0 => format!("No more results ({count} total)"),
+            SearchPurpose::More => format!("Loaded {added} more stations ({count} total)"),
+        };
         Ok(())
     }
 
@@ -480,26 +1344,59 @@ impl App {
         self.execute_command(command)
     }
 
-    fn execute_palette_action(&mut self, action: &str) -> Result<()> {
-        let command = match action {
-            "play" => SlashCommand::Play(PlayTarget::Selected),
-            "stop" => SlashCommand::Stop,
-            "pause" => SlashCommand::Pause,
-            "resume" => SlashCommand::Resume,
-            "favorites" => SlashCommand::Favorites,
-            "favorite" => SlashCommand::Favorite,
-            "unfavorite" => SlashCommand::Unfavorite,
-            "clear-filters" => SlashCommand::ClearFilters,
-            "sort-name" => SlashCommand::Sort(StationSort::Name),
-            "sort-votes" => SlashCommand::Sort(StationSort::Votes),
-            "sort-clicks" => SlashCommand::Sort(StationSort::Clicks),
-            "sort-bitrate" => SlashCommand::Sort(StationSort::Bitrate),
-            "help" => SlashCommand::Help,
-            "quit" => SlashCommand::Quit,
-            _ => return Err(anyhow!("unsupported palette action: {action}")),
-        };
+    /// Palette items hold command text (`sort votes`); commands that need arguments
+    /// open the slash input instead of running.
+    fn execute_palette_action(&mut self, invocation: &str) -> Result<()> {
+        let action = invocation
+            .split_whitespace()
+            .next()
+            .and_then(Action::from_slash_name)
+            .ok_or_else(|| anyhow!("unsupported palette action: {invocation}"))?;
+        if invocation.trim() == action.name() {
+            self.run_action(action)
+        } else {
+            self.execute_slash(&format!("/{invocation}"))
+        }
+    }
 
-        self.execute_command(command)
+    /// Runs an action from a key binding or the palette.
+    pub fn run_action(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::TogglePalette => self.toggle_palette(),
+            Action::OpenSlash => self.open_slash_input(),
+            Action::SelectNext => self.select_next(),
+            Action::SelectPrevious => self.select_previous(),
+            Action::SelectPageDown => self.select_page_down(),
+            Action::SelectPageUp => self.select_page_up(),
+            Action::SelectFirst => self.select_first(),
+            Action::SelectLast => self.select_last(),
+            Action::FocusNext => self.toggle_focus(),
+            Action::FocusPrevious => self.toggle_focus_backward(),
+            Action::ToggleFavorite => return self.toggle_selected_favorite(),
+            Action::PauseResume => return self.pause_or_resume(),
+            Action::CycleSort => return self.cycle_sort(),
+            _ if action.requires_args() => self.prompt_for_args(action),
+            _ => return self.execute_slash(&format!("/{}", action.name())),
+        }
+        Ok(())
+    }
+
+    /// Sorts by the next results column, or flips the direction of the current one.
+    pub fn cycle_sort(&mut self) -> Result<()> {
+        (self.sort, self.sort_descending) =
+            columns::next_sort(&self.columns, self.sort, self.sort_descending);
+        self.show_results(ResultsSource::Stations);
+        self.search_dirty = false;
+        self.start_search(SearchPurpose::Sort)
+    }
+
+    fn prompt_for_args(&mut self, action: Action) {
+        if self.focus == Focus::Palette {
+            self.close_overlays();
+        }
+        self.focus = Focus::Slash;
+        self.slash_input = format!("/{} ", action.name());
+        self.status_message = format!("Usage: {}", action.usage());
     }
 
     fn station_for_play_target(&self, target: PlayTarget) -> Result<Station> {
@@ -532,11 +1429,19 @@ impl App {
         match command {
             SlashCommand::Play(target) => {
                 let station = self.station_for_play_target(target)?;
+                if station.url_resolved.is_empty() {
+                    return Err(anyhow!(
+                        "no stream URL cached for {}; search for the station to play it",
+                        station.name
+                    ));
+                }
                 if let Err(err) = self.playback.play(&station.url_resolved) {
                     self.status_message = format!("Playback play failed: {err}");
                 } else {
-                    self.cache_station(&station);
+                    self.last_playback_error = None;
+                    self.stream_title = None;
                     self.now_playing = Some(station.clone());
+                    self.register_click(&station);
                     if let Some(volume) = self.pending_volume {
                         if let Err(err) = self.playback.set_volume(volume) {
                             self.status_message = format!(
@@ -570,6 +1475,7 @@ impl App {
                     self.status_message = format!("Playback stop failed: {err}");
                 } else {
                     self.now_playing = None;
+                    self.stream_title = None;
                     self.status_message = "Playback stopped".to_string();
                 }
             }
@@ -589,51 +1495,65 @@ impl App {
             }
             SlashCommand::Search(query) => {
                 self.search_input = query;
-                self.results_source = ResultsSource::Stations;
-                self.refresh_stations()?;
+                self.show_results(ResultsSource::Stations);
                 self.search_dirty = false;
-                self.status_message = format!("Search applied ({} results)", self.filtered.len());
+                self.start_search(SearchPurpose::Search)?;
             }
-            SlashCommand::Filter(filters) => {
-                self.filters = filters;
-                self.results_source = ResultsSource::Stations;
-                self.refresh_stations()?;
+            SlashCommand::Filter(update) => {
+                self.filters = update.apply(&self.filters)?;
+                self.show_results(ResultsSource::Stations);
                 self.search_dirty = false;
-                self.status_message = format!("Filters applied ({} results)", self.filtered.len());
+                self.start_search(SearchPurpose::Filter)?;
             }
             SlashCommand::ClearFilters => {
                 self.filters = StationFilters::default();
-                self.results_source = ResultsSource::Stations;
-                self.refresh_stations()?;
+                self.show_results(ResultsSource::Stations);
                 self.search_dirty = false;
-                self.status_message = format!("Filters cleared ({} results)", self.filtered.len());
+                self.start_search(SearchPurpose::ClearFilters)?;
+            }
+            SlashCommand::Browse(facet) => {
+                self.browse = Some(BrowseView {
+                    facet,
+                    query: String::new(),
+                    selected: 0,
+                });
+                self.set_focus(Focus::Search);
+                self.load_facet_values(facet);
+                if self.loading_facets.contains(&facet) {
+                    self.status_message = format!("Loading {}…", facet.plural());
+                } else if self.facet_values.contains_key(&facet) {
+                    self.status_message = self.browse_status();
+                }
             }
-            SlashCommand::Sort(sort) => {
+            SlashCommand::Sort { sort, descending } => {
                 self.sort = sort;
-                self.results_source = ResultsSource::Stations;
-                self.refresh_stations()?;
+                self.sort_descending = descending;
+                self.show_results(ResultsSource::Stations);
                 self.search_dirty = false;
-                self.status_message = format!(
-                    "Sort applied: {} ({} results)",
-                    sort_label(sort),
-                    self.filtered.len()
-                );
+                self.start_search(SearchPurpose::Sort)?;
             }
-            SlashCommand::Favorites => {
-                self.results_source = ResultsSource::Favorites;
-                let unresolved = self.refresh_favorites_view();
+            SlashCommand::More => {
+                self.show_results(ResultsSource::Stations);
                 self.clamp_selected_index();
-                if unresolved > 0 {
-                    self.status_message = format!(
-                        "Showing favorites ({}) | {} favorites not in current cache; search to hydrate",
-                        self.favorites_view.len(),
-                        unresolved
-                    );
+                if self.has_more_results {
+                    self.start_search(SearchPurpose::More)?;
                 } else {
                     self.status_message =
-                        format!("Showing favorites ({})", self.favorites_view.len());
+                        format!("No more results ({} total)", self.filtered.len());
                 }
             }
+            SlashCommand::Favorites => {
+                self.show_results(ResultsSource::Favorites);
+                self.refresh_favorites_view();
+                self.clamp_selected_index();
+                self.resolve_favorites()?;
+            }
+            SlashCommand::History => {
+                self.show_results(ResultsSource::History);
+                self.refresh_history_view();
+                self.clamp_selected_index();
+                self.status_message = format!("Showing track history ({})", self.history.len());
+            }
             SlashCommand::Favorite => {
                 let Some(station) = self.selected_station().cloned() else {
                     return Err(anyhow!("no station selected"));
@@ -655,22 +1575,141 @@ impl App {
                 self.clamp_selected_index();
                 self.status_message = format!("Unfavorited {}", station.name);
             }
+            SlashCommand::Vote => {
+                let Some(station) = self.selected_station().cloned() else {
+                    return Err(anyhow!("no station selected"));
+                };
+                let Some(worker) = &self.catalog_worker else {
+                    let result = self.station_catalog.vote(&station.station_uuid);
+                    self.apply_vote_result(&station.name, result);
+                    return Ok(());
+                };
+                self.next_request_id += 1;
+                worker.submit_vote(self.next_request_id, station.station_uuid)?;
+                self.pending_vote = Some((self.next_request_id, station.name.clone()));
+                self.status_message = format!("Voting for {}…", station.name);
+            }
+            SlashCommand::SaveSearch(name) => {
+                let search = SavedSearch {
+                    name,
+                    query: self.search_input.clone(),
+                    filters: self.filters.clone(),
+                    sort: self.sort,
+                    descending: self.sort_descending,
+                };
+                let mut saved = self.saved_searches.clone();
+                let replaced = match saved
+                    .iter_mut()
+                    .find(|existing| existing.name.eq_ignore_ascii_case(&search.name))
+                {
+                    Some(existing) => {
+                        *existing = search.clone();
+                        true
+                    }
+                    None => {
+                        saved.push(search.clone());
+                        false
+                    }
+                };
+                self.searches_store.save(&saved)?;
+                self.saved_searches = saved;
+                self.refresh_palette_items();
+                self.status_message = format!(
+                    "{} search '{}': {}",
+                    if replaced { "Updated" } else { "Saved" },
+                    search.name,
+                    search.summary()
+                );
+            }
+            SlashCommand::Searches => {
+                self.help = Some(HelpOverlay {
+                    title: "Saved searches".to_string(),
+                    lines: help::saved_searches(&self.saved_searches, &self.search_presets),
+                    scroll: 0,
+                });
+                self.status_message = format!(
+                    "{} saved searches, {} presets; Esc closes",
+                    self.saved_searches.len(),
+                    self.search_presets.len()
+                );
+            }
+            SlashCommand::LoadSearch(name) => {
+                let search = self.find_search(&name)?.clone();
+                self.search_input = search.query;
+                self.filters = search.filters;
+                self.sort = search.sort;
+                self.sort_descending = search.descending;
+                self.show_results(ResultsSource::Stations);
+                self.search_dirty = false;
+                self.start_search(SearchPurpose::LoadSearch)?;
+            }
             SlashCommand::Quit => {
                 self.playback
                     .shutdown()
                     .context("shutdown playback while quitting")?;
                 self.running = false;
                 self.now_playing = None;
+                self.stream_title = None;
                 self.status_message = "Bye".to_string();
             }
-            SlashCommand::Help => {
-                self.status_message = "Commands: /play /volume /stop /pause /resume /search /filter /clear-filters /sort /favorites /fav /unfav /quit".to_string();
+            SlashCommand::Help(topic) => {
+                let (title, lines) = match topic {
+                    Some(action) => (
+                        format!("Help: {}", action.usage()),
+                        help::command_help(action, &self.keymap),
+                    ),
+                    None => ("Help".to_string(), help::overview(&self.keymap)),
+                };
+                self.help = Some(HelpOverlay {
+                    title,
+                    lines,
+                    scroll: 0,
+                });
+                self.status_message =
+                    "Help: Up/Down/PageUp/PageDown scroll, Esc closes".to_string();
             }
         }
 
         Ok(())
     }
 
+    /// Saved searches shadow config presets of the same name.
+    fn find_search(&self, name: &str) -> Result<&SavedSearch> {
+        self.saved_searches
+            .iter()
+            .chain(&self.search_presets)
+            .find(|search| search.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| anyhow!("no saved search named '{name}'; /searches lists them"))
+    }
+
+    /// Names offered by `/load`, saved searches first.
+    pub fn search_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for search in self.saved_searches.iter().chain(&self.search_presets) {
+            if !names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&search.name))
+            {
+                names.push(&search.name);
+            }
+        }
+        names
+    }
+
+    /// Registry actions plus one "Load search" entry per saved search and preset.
+    fn refresh_palette_items(&mut self) {
+        let searches: Vec<PaletteItem> = self
+            .search_names()
+            .into_iter()
+            .map(|name| PaletteItem {
+                label: format!("Load search: {name}"),
+                action: format!("load {name}"),
+            })
+            .collect();
+        self.palette_items = actions::palette_items();
+        self.palette_items.extend(searches);
+    }
+
     fn palette_results(&self) -> Vec<PaletteItem> {
         fuzzy_filter(&self.palette_items, &self.palette_input)
     }
@@ -684,36 +1723,60 @@ impl App {
     }
 }
 
-pub fn run(debug: bool) -> Result<()> {
-    init_tracing(debug);
+/// Command-line overrides applied on top of the runtime config.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub debug: bool,
+    pub log_file: Option<PathBuf>,
+    pub log_format: Option<LogFormat>,
+}
 
-    let config = RuntimeConfig::load().context("load runtime config")?;
-    let playback: Box<dyn PlaybackController> = Box::new(VlcProcessController::new());
+pub fn run(options: RunOptions) -> Result<()> {
+    let (mut config, config_warnings) =
+        RuntimeConfig::load_from_path_with_warnings(&RuntimeConfig::default_path())
+            .context("load runtime config")?;
+    if let Some(log_file) = options.log_file {
+        config.logging.file = Some(log_file);
+    }
+    if let Some(log_format) = options.log_format {
+        config.logging.format = log_format;
+    }
+    // Reported before the TUI takes over the terminal; the app still runs without a log.
+    if let Err(err) = logging::init(options.debug, &config.logging) {
+        eprintln!("iradio: logging disabled: {err:#}");
+    }
+    for warning in &config_warnings {
+        warn!(%warning, "config warning");
+    }
 
-    let favorites_path = env::var("IRADIO_FAVORITES_PATH")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| {
-            env::var("HOME")
-                .map(PathBuf::from)
-                .unwrap_or_else(|_| PathBuf::from("."))
-                .join(".config/internet-radio-cli/favorites.json")
-        });
+    let playback =
+        build_playback_controller(&config.playback).context("configure playback backend")?;
 
-    let store = FavoritesStore::new(favorites_path);
-    let station_catalog = Box::new(RadioBrowserCatalog::new_with_config(
-        config.radio_browser.base_url,
-        std::time::Duration::from_millis(config.radio_browser.timeout_ms),
-        config.radio_browser.retries,
-    )?);
-    let mut app = App::new_with_catalog_and_defaults(
+    let store = FavoritesStore::new(favorites_path());
+    let station_catalog = Box::new(radio_browser_catalog(&config.radio_browser)?);
+    let mut app = App::new_with_options(
         playback,
         store,
         station_catalog,
         AppDefaults {
             sort: config.defaults.sort,
             filters: config.defaults.filters,
+            searches: config.searches,
+            columns: config.ui.columns,
+        },
+        AppOptions {
+            background_search: true,
+            // Absurdly large TTLs saturate to "never stale" instead of overflowing.
+            station_cache_ttl: Duration::from_secs(config.cache.ttl_hours.saturating_mul(60 * 60)),
+            keymap: config.keys,
         },
     )?;
+    if !config_warnings.is_empty() {
+        app.status_message = format!(
+            "Config has {} warning(s); run `iradio config check`",
+            config_warnings.len()
+        );
+    }
     let mut tui = Tui::new()?;
 
     if let Err(err) = tui.run(&mut app) {
@@ -727,90 +1790,47 @@ pub fn run(debug: bool) -> Result<()> {
     Ok(())
 }
 
-fn init_tracing(debug: bool) {
-    let env_filter = tracing_subscriber::EnvFilter::new(resolve_log_filter(debug));
-
-    let _ = tracing_subscriber::fmt()
-        .with_env_filter(env_filter)
-        .try_init();
+/// `$IRADIO_FAVORITES_PATH`, or `~/.config/internet-radio-cli/favorites.json`.
+pub(crate) fn favorites_path() -> PathBuf {
+    env::var("IRADIO_FAVORITES_PATH")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            env::var("HOME")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from("."))
+                .join(".config/internet-radio-cli/favorites.json")
+        })
 }
 
-fn resolve_log_filter(debug: bool) -> String {
-    if debug {
-        "iradio=debug".to_string()
+pub(crate) fn radio_browser_catalog(config: &RadioBrowserConfig) -> Result<RadioBrowserCatalog> {
+    let catalog = RadioBrowserCatalog::new_with_config(
+        config.base_url.clone(),
+        Duration::from_millis(config.timeout_ms),
+        config.retries,
+    )?
+    .with_mirrors(&config.mirrors);
+    Ok(if config.discover {
+        catalog.with_discovery(config.servers_url.clone())
     } else {
-        env::var("RUST_LOG").unwrap_or_else(|_| "iradio=info".to_string())
-    }
+        catalog
+    })
 }
 
-fn default_palette_items() -> Vec<PaletteItem> {
-    vec![
-        PaletteItem {
-            label: "Play selected station".to_string(),
-            action: "play".to_string(),
-        },
-        PaletteItem {
-            label: "Show favorites".to_string(),
-            action: "favorites".to_string(),
-        },
-        PaletteItem {
-            label: "Stop playback".to_string(),
-            action: "stop".to_string(),
-        },
-        PaletteItem {
-            label: "Pause playback".to_string(),
-            action: "pause".to_string(),
-        },
-        PaletteItem {
-            label: "Resume playback".to_string(),
-            action: "resume".to_string(),
-        },
-        PaletteItem {
-            label: "Favorite selected station".to_string(),
-            action: "favorite".to_string(),
-        },
-        PaletteItem {
-            label: "Unfavorite selected station".to_string(),
-            action: "unfavorite".to_string(),
-        },
-        PaletteItem {
-            label: "Clear filters".to_string(),
-            action: "clear-filters".to_string(),
-        },
-        PaletteItem {
-            label: "Sort by name".to_string(),
-            action: "sort-name".to_string(),
-        },
-        PaletteItem {
-            label: "Sort by votes".to_string(),
-            action: "sort-votes".to_string(),
-        },
-        PaletteItem {
-            label: "Sort by clicks".to_string(),
-            action: "sort-clicks".to_string(),
-        },
-        PaletteItem {
-            label: "Sort by bitrate".to_string(),
-            action: "sort-bitrate".to_string(),
-        },
-        PaletteItem {
-            label: "Show help".to_string(),
-            action: "help".to_string(),
-        },
-        PaletteItem {
-            label: "Quit iradio".to_string(),
-            action: "quit".to_string(),
-        },
-    ]
-}
-
-fn sort_label(sort: StationSort) -> &'static str {
-    match sort {
-        StationSort::Name => "name",
-        StationSort::Votes => "votes",
-        StationSort::Clicks => "clicks",
-        StationSort::Bitrate => "bitrate",
+/// `iradio config check`: parse the config file and report problems without starting the TUI.
+pub fn check_config(path: Option<PathBuf>) -> Result<()> {
+    let path = path.unwrap_or_else(RuntimeConfig::default_path);
+    if !path.exists() {
+        println!("{}: not found; using built-in defaults", path.display());
     }
+    let (_, warnings) = RuntimeConfig::load_from_path_with_warnings(&path)?;
+    for warning in &warnings {
+        println!("{}: {warning}", path.display());
+    }
+    if !warnings.is_empty() {
+        return Err(anyhow!("config has {} warning(s)", warnings.len()));
+    }
+    println!("{}: OK", path.display());
+    Ok(())
 }
 
 fn default_stations() -> Vec<Station> {
@@ -829,6 +1849,9 @@ fn default_stations() -> Vec<Station> {
             bitrate: Some(128),
             votes: Some(500),
             click_count: Some(2_000),
+            has_geo_info: false,
+            last_check_ok: None,
+            last_change_time: None,
         },
         Station {
             station_uuid: "npr".to_string(),
@@ -844,6 +1867,9 @@ fn default_stations() -> Vec<Station> {
             bitrate: Some(128),
             votes: Some(700),
             click_count: Some(3_000),
+            has_geo_info: false,
+            last_check_ok: None,
+            last_change_time: None,
         },
         Station {
             station_uuid: "soma-groove".to_string(),
@@ -859,37 +1885,9 @@ fn default_stations() -> Vec<Station> {
             bitrate: Some(128),
             votes: Some(900),
             click_count: Some(4_000),
+            has_geo_info: false,
+            last_check_ok: None,
+            last_change_time: None,
         },
     ]
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::sync::Mutex;
-
-    static ENV_LOCK: Mutex<()> = Mutex::new(());
-
-    #[test]
-    fn debug_flag_forces_debug_filter() {
-        let _guard = ENV_LOCK.lock().expect("lock env");
-        env::set_var("RUST_LOG", "iradio=trace");
-        assert_eq!(resolve_log_filter(true), "iradio=debug");
-        env::remove_var("RUST_LOG");
-    }
-
-    #[test]
-    fn rust_log_is_used_when_debug_is_disabled() {
-        let _guard = ENV_LOCK.lock().expect("lock env");
-        env::set_var("RUST_LOG", "iradio=trace");
-        assert_eq!(resolve_log_filter(false), "iradio=trace");
-        env::remove_var("RUST_LOG");
-    }
-
-    #[test]
-    fn default_log_filter_when_rust_log_absent() {
-        let _guard = ENV_LOCK.lock().expect("lock env");
-        env::remove_var("RUST_LOG");
-        assert_eq!(resolve_log_filter(false), "iradio=info");
-    }
-}
@@ -0,0 +1,347 @@
+use std::io::{IsTerminal, Write};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use tracing::warn;
+
+use crate::app::{favorites_path, radio_browser_catalog, RunOptions};
+use crate::domain::commands::parse_filters;
+use crate::domain::models::{Station, StationFilters, StationSearchQuery, StationSort};
+use crate::integrations::playback::{PlaybackController, PlaybackEvent, PlaybackState};
+use crate::integrations::playback_factory::build_playback_controller;
+use crate::integrations::station_catalog::StationCatalog;
+use crate::logging;
+use crate::storage::config::RuntimeConfig;
+use crate::storage::favorites::FavoritesStore;
+
+const PLAYBACK_POLL: Duration = Duration::from_millis(200);
+/// How to configure a player that outlives `iradio play`, for errors from `play` and `stop`.
+const SHARED_PLAYER_HINT: &str = "set [playback] mode = \"mpv\" with socket = \"<path>\", or attach to a running VLC with [playback] host/port (rc) or mode = \"http\"";
+
+/// One-shot commands for scripts and hotkeys; each runs without the TUI and exits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    Search(Box<SearchArgs>),
+    Play { target: String },
+    Favorites(FavoritesCommand),
+    Stop,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FavoritesCommand {
+    List { json: bool },
+    Add { target: String },
+    Remove { target: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchArgs {
+    pub query: String,
+    pub filters: StationFilters,
+    pub sort: StationSort,
+    pub descending: bool,
+    pub limit: usize,
+    pub json: bool,
+}
+
+impl Default for SearchArgs {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            filters: StationFilters::default(),
+            sort: StationSort::default(),
+            descending: StationSort::default().descending_by_default(),
+            limit: 20,
+            json: false,
+        }
+    }
+}
+
+impl SearchArgs {
+    /// Adds `--filter` text in `/filter` syntax on top of the single-key flags.
+    pub fn with_filter_text(mut self, text: Option<&str>) -> Result<Self> {
+        if let Some(text) = text {
+            let filters = parse_filters(text).context("invalid --filter")?;
+            self.filters.merge(&filters);
+        }
+        Ok(self)
+    }
+}
+
+/// Loads the config and runs `command` against Radio Browser and the configured player.
+pub fn run(command: CliCommand, options: &RunOptions) -> Result<()> {
+    let mut config = RuntimeConfig::load().context("load runtime config")?;
+    if let Some(log_file) = &options.log_file {
+        config.logging.file = Some(log_file.clone());
+    }
+    if let Some(log_format) = options.log_format {
+        config.logging.format = log_format;
+    }
+    if let Err(err) = logging::init(options.debug, &config.logging) {
+        eprintln!("iradio: logging disabled: {err:#}");
+    }
+
+    let catalog = radio_browser_catalog(&config.radio_browser)?;
+    let store = FavoritesStore::new(favorites_path());
+    let mut out = std::io::stdout().lock();
+
+    match command {
+        CliCommand::Search(args) => search(&catalog, &args, &mut out),
+        CliCommand::Play { target } => {
+            // A managed player lives only as long as this process, so stay in the foreground.
+            let wait = !config.playback.has_shared_player();
+            if wait && !std::io::stdin().is_terminal() {
+                return Err(anyhow!(
+                    "iradio play would keep the player in the foreground, and there is no terminal to stop it from; {SHARED_PLAYER_HINT}"
+                ));
+            }
+            let favorites = store.load()?;
+            let station = resolve_station(&catalog, &favorites, &target)?;
+            let mut playback = build_playback_controller(&config.playback)
+                .context("configure playback backend")?;
+            play(playback.as_mut(), &station, wait, &mut out)
+        }
+        CliCommand::Favorites(FavoritesCommand::List { json }) => {
+            list_favorites(&catalog, &store, json, &mut out)
+        }
+        CliCommand::Favorites(FavoritesCommand::Add { target }) => {
+            add_favorite(&catalog, &store, &target, &mut out)
+        }
+        CliCommand::Favorites(FavoritesCommand::Remove { target }) => {
+            remove_favorite(&catalog, &store, &target, &mut out)
+        }
+        CliCommand::Stop => {
+            if !config.playback.has_shared_player() {
+                return Err(anyhow!(
+                    "iradio stop needs a player shared between iradio processes; {SHARED_PLAYER_HINT}"
+                ));
+            }
+            let mut playback = build_playback_controller(&config.playback)
+                .context("configure playback backend")?;
+            stop(playback.as_mut(), &mut out)
+        }
+    }
+}
+
+/// Prints matches as `uuid<TAB>name<TAB>country<TAB>codec<TAB>bitrate`, or a JSON array.
+pub fn search(catalog: &dyn StationCatalog, args: &SearchArgs, out: &mut dyn Write) -> Result<()> {
+    let stations = catalog.search(&StationSearchQuery {
+        query: args.query.clone(),
+        filters: args.filters.clone(),
+        sort: args.sort,
+        descending: args.descending,
+        limit: args.limit,
+        offset: 0,
+    })?;
+    if args.json {
+        return write_json(&stations, out);
+    }
+    for station in &stations {
+        writeln!(
+            out,
+            "{}\t{}\t{}\t{}\t{}",
+            station.station_uuid,
+            station.name,
+            station.country.as_deref().unwrap_or(""),
+            station.codec.as_deref().unwrap_or(""),
+            station
+                .bitrate
+                .map(|kbps| kbps.to_string())
+                .unwrap_or_default()
+        )?;
+    }
+    Ok(())
+}
+
+/// A 1-based favorite index, a station UUID, or a name (exact match preferred).
+pub fn resolve_station(
+    catalog: &dyn StationCatalog,
+    favorites: &[String],
+    target: &str,
+) -> Result<Station> {
+    let target = target.trim();
+    if let Ok(index) = target.parse::<usize>() {
+        let station_uuid = index
+            .checked_sub(1)
+            .and_then(|idx| favorites.get(idx))
+            .ok_or_else(|| {
+                anyhow!(
+                    "favorite index {index} is out of range; there are {} favorites (iradio favorites list)",
+                    favorites.len()
+                )
+            })?;
+        return catalog
+            .lookup(std::slice::from_ref(station_uuid))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                anyhow!("favorite {station_uuid} is no longer listed by Radio Browser")
+            });
+    }
+    find_station(catalog, target)
+}
+
+/// A station UUID or a name (exact match preferred). Digits are a name here, not an index.
+pub fn find_station(catalog: &dyn StationCatalog, target: &str) -> Result<Station> {
+    let target = target.trim();
+    if let Some(station) = catalog.lookup(&[target.to_string()])?.into_iter().next() {
+        return Ok(station);
+    }
+    let matches = catalog.search(&StationSearchQuery {
+        query: target.to_string(),
+        limit: 20,
+        ..StationSearchQuery::default()
+    })?;
+    matches
+        .iter()
+        .find(|station| station.name.eq_ignore_ascii_case(target))
+        .or_else(|| matches.first())
+        .cloned()
+        .ok_or_else(|| anyhow!("no station matches '{target}'; try iradio search {target}"))
+}
+
+/// Starts `station`. With `wait`, keeps polling the player and prints stream titles until
+/// playback stops or fails; otherwise the player is left running.
+pub fn play(
+    playback: &mut dyn PlaybackController,
+    station: &Station,
+    wait: bool,
+    out: &mut dyn Write,
+) -> Result<()> {
+    playback
+        .play(&station.url_resolved)
+        .with_context(|| format!("failed to play {}", station.name))?;
+    writeln!(out, "Playing {} ({})", station.name, station.station_uuid)?;
+    if !wait {
+        playback.keep_running();
+        return Ok(());
+    }
+    out.flush()?;
+
+    loop {
+        for event in playback.poll_events() {
+            match event {
+                PlaybackEvent::StreamTitle(title) => {
+                    writeln!(out, "{title}")?;
+                    out.flush()?;
+                }
+                PlaybackEvent::Error(message) => return Err(anyhow!("playback failed: {message}")),
+                PlaybackEvent::StateChanged(_) => {}
+            }
+        }
+        match playback.state() {
+            PlaybackState::Stopped => return Ok(()),
+            PlaybackState::Error => return Err(anyhow!("playback failed")),
+            _ => thread::sleep(PLAYBACK_POLL),
+        }
+    }
+}
+
+pub fn stop(playback: &mut dyn PlaybackController, out: &mut dyn Write) -> Result<()> {
+    playback.force_stop().context("failed to stop playback")?;
+    writeln!(out, "Stopped")?;
+    Ok(())
+}
+
+/// Prints `index<TAB>uuid<TAB>name`; favorites Radio Browser no longer lists keep an empty name.
+pub fn list_favorites(
+    catalog: &dyn StationCatalog,
+    store: &FavoritesStore,
+    json: bool,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let favorites = store.load()?;
+    let stations = if favorites.is_empty() {
+        Vec::new()
+    } else {
+        catalog
+            .lookup(&favorites)
+            .context("failed to look up favorite stations")?
+    };
+    if json {
+        let ordered: Vec<&Station> = favorites
+            .iter()
+            .filter_map(|id| stations.iter().find(|station| &station.station_uuid == id))
+            .collect();
+        return write_json(&ordered, out);
+    }
+    for (idx, id) in favorites.iter().enumerate() {
+        let name = stations
+            .iter()
+            .find(|station| &station.station_uuid == id)
+            .map(|station| station.name.as_str())
+            .unwrap_or("");
+        writeln!(out, "{}\t{id}\t{name}", idx + 1)?;
+    }
+    Ok(())
+}
+
+pub fn add_favorite(
+    catalog: &dyn StationCatalog,
+    store: &FavoritesStore,
+    target: &str,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let mut favorites = store.load()?;
+    let station = find_station(catalog, target)?;
+    if favorites.contains(&station.station_uuid) {
+        writeln!(out, "{} is already a favorite", station.name)?;
+        return Ok(());
+    }
+    favorites.push(station.station_uuid.clone());
+    store.save(&favorites)?;
+    writeln!(out, "Favorited {} ({})", station.name, station.station_uuid)?;
+    Ok(())
+}
+
+/// Removes by 1-based index, UUID or favorite station name.
+pub fn remove_favorite(
+    catalog: &dyn StationCatalog,
+    store: &FavoritesStore,
+    target: &str,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let mut favorites = store.load()?;
+    let target = target.trim();
+    let position = match target.parse::<usize>() {
+        Ok(index) => Some(index)
+            .filter(|index| (1..=favorites.len()).contains(index))
+            .map(|index| index - 1)
+            .ok_or_else(|| {
+                anyhow!(
+                    "favorite index {index} is out of range; there are {} favorites",
+                    favorites.len()
+                )
+            })?,
+        Err(_) => {
+            let stations = if favorites.iter().any(|id| id == target) {
+                Vec::new()
+            } else {
+                catalog.lookup(&favorites).unwrap_or_else(|err| {
+                    warn!(error = ?err, "favorite lookup failed; matching by UUID only");
+                    Vec::new()
+                })
+            };
+            let named = |id: &String| {
+                stations.iter().any(|station| {
+                    &station.station_uuid == id && station.name.eq_ignore_ascii_case(target)
+                })
+            };
+            favorites
+                .iter()
+                .position(|id| id == target || named(id))
+                .ok_or_else(|| anyhow!("'{target}' is not a favorite; see iradio favorites list"))?
+        }
+    };
+    let removed = favorites.remove(position);
+    store.save(&favorites)?;
+    writeln!(out, "Removed {removed}")?;
+    Ok(())
+}
+
+fn write_json<T: serde::Serialize + ?Sized>(value: &T, out: &mut dyn Write) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *out, value).context("failed to write JSON")?;
+    writeln!(out)?;
+    Ok(())
+}
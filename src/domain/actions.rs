@@ -0,0 +1,414 @@
+use crate::domain::commands::{BROWSE_FACETS, FILTER_KEYS, SORT_FIELDS};
+use crate::domain::palette::PaletteItem;
+
+/// Every user-facing action. Slash commands, palette items and key bindings are all derived
+/// from `Action::spec`, so adding an action here makes it available everywhere it applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Play,
+    Volume,
+    Stop,
+    Pause,
+    Resume,
+    PauseResume,
+    Search,
+    Filter,
+    ClearFilters,
+    Browse,
+    Sort,
+    More,
+    Favorites,
+    History,
+    SaveSearch,
+    Searches,
+    LoadSearch,
+    Favorite,
+    Unfavorite,
+    Vote,
+    ToggleFavorite,
+    CycleSort,
+    Help,
+    Quit,
+    TogglePalette,
+    OpenSlash,
+    SelectNext,
+    SelectPrevious,
+    SelectPageDown,
+    SelectPageUp,
+    SelectFirst,
+    SelectLast,
+    FocusNext,
+    FocusPrevious,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgSchema {
+    None,
+    Optional(&'static str),
+    Required(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionSpec {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub description: &'static str,
+    pub args: ArgSchema,
+    pub default_keys: &'static [&'static str],
+    /// Invocable as `/name`.
+    pub slash: bool,
+    /// Palette entries as `(label, args)`; args are appended to the command (`/sort votes`).
+    pub palette: &'static [(&'static str, &'static str)],
+    pub examples: &'static [&'static str],
+    /// Argument reference for `/help <command>` as `(syntax, meaning)` rows.
+    pub details: &'static [(&'static str, &'static str)],
+}
+
+const fn command(
+    name: &'static str,
+    description: &'static str,
+    args: ArgSchema,
+    palette: &'static [(&'static str, &'static str)],
+) -> ActionSpec {
+    ActionSpec {
+        name,
+        aliases: &[],
+        description,
+        args,
+        default_keys: &[],
+        slash: true,
+        palette,
+        examples: &[],
+        details: &[],
+    }
+}
+
+const fn ui_action(
+    name: &'static str,
+    description: &'static str,
+    default_keys: &'static [&'static str],
+) -> ActionSpec {
+    ActionSpec {
+        name,
+        aliases: &[],
+        description,
+        args: ArgSchema::None,
+        default_keys,
+        slash: false,
+        palette: &[],
+        examples: &[],
+        details: &[],
+    }
+}
+
+impl Action {
+    pub const ALL: [Self; 34] = [
+        Self::Play,
+        Self::Volume,
+        Self::Stop,
+        Self::Pause,
+        Self::Resume,
+        Self::PauseResume,
+        Self::Search,
+        Self::Filter,
+        Self::ClearFilters,
+        Self::Browse,
+        Self::Sort,
+        Self::More,
+        Self::Favorites,
+        Self::History,
+        Self::SaveSearch,
+        Self::Searches,
+        Self::LoadSearch,
+        Self::Favorite,
+        Self::Unfavorite,
+        Self::Vote,
+        Self::ToggleFavorite,
+        Self::CycleSort,
+        Self::Help,
+        Self::Quit,
+        Self::TogglePalette,
+        Self::OpenSlash,
+        Self::SelectNext,
+        Self::SelectPrevious,
+        Self::SelectPageDown,
+        Self::SelectPageUp,
+        Self::SelectFirst,
+        Self::SelectLast,
+        Self::FocusNext,
+        Self::FocusPrevious,
+    ];
+
+    pub fn spec(self) -> ActionSpec {
+        use ArgSchema::{None, Optional, Required};
+
+        match self {
+            Self::Play => ActionSpec {
+                examples: &["/play", "/play 3", "/play jazz radio"],
+                ..command(
+                    "play",
+                    "Play the selected station, a 1-based index or the first name match",
+                    Optional("[selected|<index>|<name>]"),
+                    &[("Play selected station", "")],
+                )
+            },
+            Self::Volume => ActionSpec {
+                default_keys: &["v"],
+                examples: &["/volume 40"],
+                ..command(
+                    "volume",
+                    "Set playback volume",
+                    Required("<0-100>"),
+                    &[("Set volume", "")],
+                )
+            },
+            Self::Stop => ActionSpec {
+                default_keys: &["s"],
+                ..command("stop", "Stop playback", None, &[("Stop playback", "")])
+            },
+            Self::Pause => command("pause", "Pause playback", None, &[("Pause playback", "")]),
+            Self::Resume => command(
+                "resume",
+                "Resume playback",
+                None,
+                &[("Resume playback", "")],
+            ),
+            Self::PauseResume => ui_action("pause-resume", "Toggle pause and resume", &["space"]),
+            Self::Search => ActionSpec {
+                examples: &["/search bbc radio"],
+                ..command(
+                    "search",
+                    "Search stations by name",
+                    Required("<query>"),
+                    &[("Search stations", "")],
+                )
+            },
+            Self::Filter => ActionSpec {
+                examples: &[
+                    "/filter tag=jazz",
+                    "/filter country=Germany min_bitrate=128",
+                    "/filter tag=jazz,blues tag!=talk codec=mp3|aac https_only",
+                ],
+                details: FILTER_KEYS,
+                ..command(
+                    "filter",
+                    "Filter search results",
+                    Required("<key>=<value>[,|<value>] [<key>!=<value>] [flags]"),
+                    &[("Filter stations", "")],
+                )
+            },
+            Self::ClearFilters => command(
+                "clear-filters",
+                "Remove all search filters",
+                None,
+                &[("Clear filters", "")],
+            ),
+            Self::Browse => ActionSpec {
+                default_keys: &["b"],
+                examples: &["/browse country", "/browse tag"],
+                details: BROWSE_FACETS,
+                ..command(
+                    "browse",
+                    "List countries, languages, tags or codecs and filter by one",
+                    Required("<country|language|tag|codec>"),
+                    &[
+                        ("Browse countries", "country"),
+                        ("Browse languages", "language"),
+                        ("Browse tags", "tag"),
+                        ("Browse codecs", "codec"),
+                    ],
+                )
+            },
+            Self::Sort => ActionSpec {
+                examples: &["/sort votes", "/sort bitrate asc", "/sort name desc"],
+                details: SORT_FIELDS,
+                ..command(
+                    "sort",
+                    "Change the result order",
+                    Required("<field> [asc|desc]"),
+                    &[
+                        ("Sort by name", "name"),
+                        ("Sort by votes", "votes"),
+                        ("Sort by clicks", "clicks"),
+                        ("Sort by bitrate", "bitrate"),
+                        ("Sort by lowest bitrate", "bitrate asc"),
+                        ("Sort by country", "country"),
+                        ("Sort by recently changed", "changed"),
+                        ("Sort randomly", "random"),
+                    ],
+                )
+            },
+            Self::More => command(
+                "more",
+                "Load the next page of results",
+                None,
+                &[("Load more results", "")],
+            ),
+            Self::Favorites => command(
+                "favorites",
+                "Show favorite stations",
+                None,
+                &[("Show favorites", "")],
+            ),
+            Self::History => command(
+                "history",
+                "Show track history",
+                None,
+                &[("Show track history", "")],
+            ),
+            Self::SaveSearch => ActionSpec {
+                examples: &["/save-search late jazz"],
+                ..command(
+                    "save-search",
+                    "Save the current query, filters and sort under a name",
+                    Required("<name>"),
+                    &[("Save search", "")],
+                )
+            },
+            Self::Searches => command(
+                "searches",
+                "List saved searches",
+                None,
+                &[("Show saved searches", "")],
+            ),
+            Self::LoadSearch => ActionSpec {
+                examples: &["/load late jazz"],
+                ..command(
+                    "load",
+                    "Restore a saved search and run it",
+                    Required("<name>"),
+                    &[],
+                )
+            },
+            Self::Favorite => ActionSpec {
+                aliases: &["favorite"],
+                ..command(
+                    "fav",
+                    "Add the selected station to favorites",
+                    None,
+                    &[("Favorite selected station", "")],
+                )
+            },
+            Self::Unfavorite => ActionSpec {
+                aliases: &["unfavorite"],
+                ..command(
+                    "unfav",
+                    "Remove the selected station from favorites",
+                    None,
+                    &[("Unfavorite selected station", "")],
+                )
+            },
+            Self::Vote => command(
+                "vote",
+                "Vote for the selected station on Radio Browser",
+                None,
+                &[("Vote for selected station", "")],
+            ),
+            Self::ToggleFavorite => ui_action(
+                "toggle-favorite",
+                "Toggle favorite for the selected station",
+                &["f"],
+            ),
+            Self::CycleSort => ui_action(
+                "cycle-sort",
+                "Sort by the next results column, or reverse the current one",
+                &["o"],
+            ),
+            Self::Help => ActionSpec {
+                examples: &["/help", "/help filter"],
+                ..command(
+                    "help",
+                    "Show help, or details for one command",
+                    Optional("[command]"),
+                    &[("Show help", "")],
+                )
+            },
+            Self::Quit => ActionSpec {
+                aliases: &["q"],
+                default_keys: &["q"],
+                ..command("quit", "Quit iradio", None, &[("Quit iradio", "")])
+            },
+            Self::TogglePalette => {
+                ui_action("palette", "Open or close the command palette", &["ctrl+p"])
+            }
+            Self::OpenSlash => ui_action("slash", "Open the slash command input", &["/"]),
+            Self::SelectNext => ui_action("select-next", "Move the selection down", &["down", "j"]),
+            Self::SelectPrevious => {
+                ui_action("select-previous", "Move the selection up", &["up", "k"])
+            }
+            Self::SelectPageDown => ui_action(
+                "page-down",
+                "Move the selection down one page",
+                &["pagedown"],
+            ),
+            Self::SelectPageUp => {
+                ui_action("page-up", "Move the selection up one page", &["pageup"])
+            }
+            Self::SelectFirst => {
+                ui_action("select-first", "Select the first entry", &["home", "g"])
+            }
+            Self::SelectLast => ui_action("select-last", "Select the last entry", &["end", "G"]),
+            Self::FocusNext => ui_action("focus-next", "Focus the next pane", &["tab"]),
+            Self::FocusPrevious => {
+                ui_action("focus-previous", "Focus the previous pane", &["shift+tab"])
+            }
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        self.spec().name
+    }
+
+    pub fn description(self) -> &'static str {
+        self.spec().description
+    }
+
+    /// Looks up a name or alias; `_` and `-` are interchangeable (`select_next`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase().replace('_', "-");
+        Self::ALL.into_iter().find(|action| {
+            let spec = action.spec();
+            spec.name == name || spec.aliases.contains(&name.as_str())
+        })
+    }
+
+    /// Like `from_name`, restricted to actions available as slash commands.
+    pub fn from_slash_name(name: &str) -> Option<Self> {
+        Self::from_name(name).filter(|action| action.spec().slash)
+    }
+
+    pub fn requires_args(self) -> bool {
+        matches!(self.spec().args, ArgSchema::Required(_))
+    }
+
+    /// `/volume <0-100>` for slash commands, the bare name otherwise.
+    pub fn usage(self) -> String {
+        let spec = self.spec();
+        if !spec.slash {
+            return spec.name.to_string();
+        }
+        match spec.args {
+            ArgSchema::None => format!("/{}", spec.name),
+            ArgSchema::Optional(args) | ArgSchema::Required(args) => {
+                format!("/{} {args}", spec.name)
+            }
+        }
+    }
+}
+
+/// Palette entries for every action; `action` holds the command text without the leading `/`.
+pub fn palette_items() -> Vec<PaletteItem> {
+    Action::ALL
+        .into_iter()
+        .flat_map(|action| {
+            action
+                .spec()
+                .palette
+                .iter()
+                .map(move |(label, args)| PaletteItem {
+                    label: label.to_string(),
+                    action: format!("{} {args}", action.name()).trim_end().to_string(),
+                })
+        })
+        .collect()
+}
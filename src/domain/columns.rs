@@ -0,0 +1,212 @@
+use anyhow::{anyhow, Result};
+
+use crate::domain::models::{Station, StationSort};
+
+/// A column of the results table, configured with `[ui] columns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Name,
+    Codec,
+    Bitrate,
+    Country,
+    Language,
+    Tags,
+    Votes,
+    Clicks,
+}
+
+pub const DEFAULT_COLUMNS: [Column; 5] = [
+    Column::Name,
+    Column::Codec,
+    Column::Bitrate,
+    Column::Country,
+    Column::Votes,
+];
+
+/// Space between two columns in the rendered table.
+pub const COLUMN_SPACING: u16 = 1;
+
+impl Column {
+    pub const ALL: [Self; 8] = [
+        Self::Name,
+        Self::Codec,
+        Self::Bitrate,
+        Self::Country,
+        Self::Language,
+        Self::Tags,
+        Self::Votes,
+        Self::Clicks,
+    ];
+
+    /// The config name, e.g. `bitrate`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Codec => "codec",
+            Self::Bitrate => "bitrate",
+            Self::Country => "country",
+            Self::Language => "language",
+            Self::Tags => "tags",
+            Self::Votes => "votes",
+            Self::Clicks => "clicks",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self> {
+        let value = value.trim();
+        Self::ALL
+            .into_iter()
+            .find(|column| column.name().eq_ignore_ascii_case(value))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|column| column.name()).collect();
+                anyhow!("unknown column '{value}' (expected {})", names.join(", "))
+            })
+    }
+
+    pub fn header(self) -> &'static str {
+        match self {
+            Self::Name => "Name",
+            Self::Codec => "Codec",
+            Self::Bitrate => "kbps",
+            Self::Country => "Country",
+            Self::Language => "Language",
+            Self::Tags => "Tags",
+            Self::Votes => "Votes",
+            Self::Clicks => "Clicks",
+        }
+    }
+
+    /// The sort applied when this column is picked with `cycle-sort`, if any.
+    pub fn sort(self) -> Option<StationSort> {
+        match self {
+            Self::Name => Some(StationSort::Name),
+            Self::Bitrate => Some(StationSort::Bitrate),
+            Self::Votes => Some(StationSort::Votes),
+            Self::Clicks => Some(StationSort::Clicks),
+            Self::Country => Some(StationSort::Country),
+            Self::Language => Some(StationSort::Language),
+            Self::Codec => Some(StationSort::Codec),
+            Self::Tags => None,
+        }
+    }
+
+    /// Narrowest useful width, leaving room for the sort marker after sortable headers;
+    /// text columns grow into the space left over.
+    pub fn min_width(self) -> u16 {
+        match self {
+            Self::Name => 16,
+            Self::Codec => 7,
+            Self::Bitrate => 6,
+            Self::Country => 9,
+            Self::Language => 10,
+            Self::Tags => 10,
+            Self::Votes => 7,
+            Self::Clicks => 8,
+        }
+    }
+
+    /// Whether the column takes a share of the spare width rather than a fixed one.
+    pub fn grows(self) -> bool {
+        matches!(self, Self::Name | Self::Language | Self::Tags)
+    }
+
+    pub fn is_numeric(self) -> bool {
+        matches!(self, Self::Bitrate | Self::Votes | Self::Clicks)
+    }
+
+    pub fn value(self, station: &Station) -> String {
+        let number = |value: Option<u32>| value.map(|n| n.to_string()).unwrap_or_default();
+        match self {
+            Self::Name => station.name.clone(),
+            Self::Codec => station.codec.clone().unwrap_or_default(),
+            Self::Bitrate => number(station.bitrate),
+            Self::Country => station
+                .country_code
+                .clone()
+                .or_else(|| station.country.clone())
+                .unwrap_or_default(),
+            Self::Language => station.language.clone().unwrap_or_default(),
+            Self::Tags => station.tags.join(","),
+            Self::Votes => number(station.votes),
+            Self::Clicks => number(station.click_count),
+        }
+    }
+}
+
+/// The leading columns whose minimum widths fit in `width`; the first column is always kept.
+pub fn fit_columns(columns: &[Column], width: u16) -> Vec<Column> {
+    let mut used = 0u16;
+    let mut fitted = Vec::new();
+    for &column in columns {
+        let needed = column.min_width() + if fitted.is_empty() { 0 } else { COLUMN_SPACING };
+        if !fitted.is_empty() && used.saturating_add(needed) > width {
+            break;
+        }
+        used = used.saturating_add(needed);
+        fitted.push(column);
+    }
+    fitted
+}
+
+/// The sort after `current` when cycling through `columns`: a column first sorts in its
+/// natural direction, then reversed, then the next sortable column takes over.
+pub fn next_sort(
+    columns: &[Column],
+    current: StationSort,
+    descending: bool,
+) -> (StationSort, bool) {
+    let mut sorts: Vec<StationSort> = columns.iter().filter_map(|column| column.sort()).collect();
+    if sorts.is_empty() {
+        sorts = Column::ALL
+            .iter()
+            .filter_map(|column| column.sort())
+            .collect();
+    }
+    match sorts.iter().position(|sort| *sort == current) {
+        Some(_) if descending == current.descending_by_default() => (current, !descending),
+        Some(idx) => {
+            let next = sorts[(idx + 1) % sorts.len()];
+            (next, next.descending_by_default())
+        }
+        None => (sorts[0], sorts[0].descending_by_default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn narrow_terminals_drop_trailing_columns() {
+        assert_eq!(fit_columns(&DEFAULT_COLUMNS, 200), DEFAULT_COLUMNS.to_vec());
+        assert_eq!(
+            fit_columns(&DEFAULT_COLUMNS, 31),
+            vec![Column::Name, Column::Codec, Column::Bitrate]
+        );
+        assert_eq!(fit_columns(&DEFAULT_COLUMNS, 3), vec![Column::Name]);
+    }
+
+    #[test]
+    fn cycling_flips_direction_before_moving_on() {
+        let columns = [Column::Name, Column::Tags, Column::Votes];
+        let mut sort = (StationSort::Votes, true);
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            sort = next_sort(&columns, sort.0, sort.1);
+            seen.push(sort);
+        }
+        assert_eq!(
+            seen,
+            vec![
+                (StationSort::Votes, false),
+                (StationSort::Name, false),
+                (StationSort::Name, true),
+                (StationSort::Votes, true),
+            ]
+        );
+        assert_eq!(
+            next_sort(&columns, StationSort::Bitrate, true),
+            (StationSort::Name, false)
+        );
+    }
+}
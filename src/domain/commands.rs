@@ -1,6 +1,78 @@
 use anyhow::{anyhow, Result};
 
-use crate::domain::models::{StationFilters, StationSort};
+use crate::domain::actions::Action;
+use crate::domain::models::{
+    filter_terms, parse_sort_direction, Facet, StationFilters, StationSort,
+};
+
+/// `/filter` keys as `(syntax, meaning)`; shared by the parser errors and `/help filter`.
+pub const FILTER_KEYS: &[(&str, &str)] = &[
+    (
+        "country=<name>",
+        "Country name or code, e.g. country=Germany or country=DE|AT",
+    ),
+    (
+        "language=<name>",
+        "Broadcast language, e.g. language=english",
+    ),
+    (
+        "tag=<tag>",
+        "Station tags: tag=jazz,blues needs both, tag=jazz|blues either",
+    ),
+    ("codec=<codec>", "Stream codec, e.g. codec=mp3|aac"),
+    (
+        "<key>!=<value>",
+        "Drop stations matching any value, e.g. tag!=talk,news",
+    ),
+    (
+        "min_bitrate=<kbps>",
+        "Minimum bitrate in kbps, e.g. min_bitrate=128",
+    ),
+    ("max_bitrate=<kbps>", "Maximum bitrate in kbps"),
+    (
+        "has_geo[=false]",
+        "Only stations with (or without) map coordinates",
+    ),
+    ("https_only", "Only streams served over HTTPS"),
+    ("is_working", "Only stations that passed their last check"),
+    (
+        "+<key>=<value>",
+        "Change one filter and keep the others, e.g. +tag=jazz",
+    ),
+    ("-<key>", "Remove one filter, e.g. -country"),
+];
+
+/// Filters that may be given bare (`https_only`) or as `key=true|false`.
+const FLAG_KEYS: [&str; 3] = ["has_geo", "https_only", "is_working"];
+
+/// `/sort` fields as `(field, meaning)`.
+pub const SORT_FIELDS: &[(&str, &str)] = &[
+    ("name", "Station name, A to Z"),
+    ("votes", "Most voted first"),
+    ("clicks", "Most clicked first"),
+    ("bitrate", "Highest bitrate first"),
+    ("country", "Country name, A to Z"),
+    ("language", "Language, A to Z"),
+    ("codec", "Codec, A to Z"),
+    ("lastcheck", "Stations that passed the last check first"),
+    ("changed", "Most recently changed first"),
+    ("random", "A new random order on every search"),
+    (
+        "<field> asc|desc",
+        "Pick the direction instead of the field's default",
+    ),
+];
+
+/// Directions accepted after the `/sort` field.
+pub const SORT_DIRECTIONS: [&str; 2] = ["asc", "desc"];
+
+/// `/browse` facets as `(facet, meaning)`.
+pub const BROWSE_FACETS: &[(&str, &str)] = &[
+    ("country", "Countries, most stations first"),
+    ("language", "Broadcast languages"),
+    ("tag", "Tags and genres"),
+    ("codec", "Stream codecs"),
+];
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PlayTarget {
@@ -9,6 +81,39 @@ pub enum PlayTarget {
     Query(String),
 }
 
+/// A parsed `/filter`. Plain `key=value` clauses replace the current filters; once any
+/// clause is written `+key=value` or `-key`, the named keys are edited and the rest kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterUpdate {
+    pub incremental: bool,
+    pub set: StationFilters,
+    /// Keys given as `-key`, e.g. `country` or `https_only`.
+    pub remove: Vec<String>,
+}
+
+impl FilterUpdate {
+    pub fn replace(filters: StationFilters) -> Self {
+        Self {
+            set: filters,
+            ..Self::default()
+        }
+    }
+
+    /// The filters after this update is applied to `current`.
+    pub fn apply(&self, current: &StationFilters) -> Result<StationFilters> {
+        if !self.incremental {
+            return Ok(self.set.clone());
+        }
+        let mut filters = current.clone();
+        for key in &self.remove {
+            filters.remove(key);
+        }
+        filters.merge(&self.set);
+        check_bitrate_range(&filters)?;
+        Ok(filters)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SlashCommand {
     Play(PlayTarget),
@@ -17,14 +122,21 @@ pub enum SlashCommand {
     Pause,
     Resume,
     Search(String),
-    Filter(StationFilters),
+    Filter(Box<FilterUpdate>),
     ClearFilters,
-    Sort(StationSort),
+    Browse(Facet),
+    Sort { sort: StationSort, descending: bool },
+    More,
     Favorites,
+    History,
     Favorite,
     Unfavorite,
+    Vote,
+    SaveSearch(String),
+    Searches,
+    LoadSearch(String),
     Quit,
-    Help,
+    Help(Option<Action>),
 }
 
 impl SlashCommand {
@@ -37,8 +149,12 @@ impl SlashCommand {
         let mut parts = trimmed[1..].split_whitespace();
         let cmd = parts.next().ok_or_else(|| anyhow!("empty command"))?;
 
-        match cmd {
-            "play" => {
+        let action =
+            Action::from_slash_name(cmd).ok_or_else(|| anyhow!("unknown command: {cmd}"))?;
+        let usage = || anyhow!("usage: {}", action.usage());
+
+        match action {
+            Action::Play => {
                 let value = parts.collect::<Vec<_>>().join(" ");
                 if value.is_empty() || value.eq_ignore_ascii_case("selected") {
                     Ok(Self::Play(PlayTarget::Selected))
@@ -52,12 +168,10 @@ impl SlashCommand {
                     Ok(Self::Play(PlayTarget::Query(value)))
                 }
             }
-            "volume" => {
-                let value = parts
-                    .next()
-                    .ok_or_else(|| anyhow!("usage: /volume <0-100>"))?;
+            Action::Volume => {
+                let value = parts.next().ok_or_else(usage)?;
                 if parts.next().is_some() {
-                    return Err(anyhow!("usage: /volume <0-100>"));
+                    return Err(usage());
                 }
                 let value = value
                     .parse::<u8>()
@@ -67,78 +181,256 @@ impl SlashCommand {
                 }
                 Ok(Self::Volume(value))
             }
-            "stop" => Ok(Self::Stop),
-            "pause" => Ok(Self::Pause),
-            "resume" => Ok(Self::Resume),
-            "search" => {
+            Action::Stop => Ok(Self::Stop),
+            Action::Pause => Ok(Self::Pause),
+            Action::Resume => Ok(Self::Resume),
+            Action::Search => {
                 let query = parts.collect::<Vec<_>>().join(" ");
                 if query.is_empty() {
-                    Err(anyhow!("usage: /search <query>"))
+                    Err(usage())
                 } else {
                     Ok(Self::Search(query))
                 }
             }
-            "filter" => {
+            Action::Filter => {
                 let args = parts.collect::<Vec<_>>();
                 if args.is_empty() {
-                    return Err(anyhow!(
-                        "usage: /filter country=<x> language=<y> tag=<z> codec=<c> min_bitrate=<n>"
-                    ));
+                    return Err(usage());
+                }
+                Ok(Self::Filter(Box::new(parse_filter_args(&args)?)))
+            }
+            Action::ClearFilters => Ok(Self::ClearFilters),
+            Action::Browse => {
+                let value = parts.next().ok_or_else(usage)?;
+                if parts.next().is_some() {
+                    return Err(usage());
                 }
-                Ok(Self::Filter(parse_filter_args(&args)?))
+                let facet = Facet::ALL
+                    .into_iter()
+                    .find(|facet| {
+                        value.eq_ignore_ascii_case(facet.filter_key())
+                            || value.eq_ignore_ascii_case(facet.plural())
+                    })
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "invalid browse facet: {value} (expected {})",
+                            field_names(BROWSE_FACETS)
+                        )
+                    })?;
+                Ok(Self::Browse(facet))
             }
-            "clear-filters" => Ok(Self::ClearFilters),
-            "sort" => {
-                let value = parts
-                    .next()
-                    .ok_or_else(|| anyhow!("usage: /sort <name|votes|clicks|bitrate>"))?;
+            Action::Sort => {
+                let value = parts.next().ok_or_else(usage)?;
+                let direction = parts.next();
                 if parts.next().is_some() {
-                    return Err(anyhow!("usage: /sort <name|votes|clicks|bitrate>"));
+                    return Err(usage());
                 }
-                let sort = match value.to_ascii_lowercase().as_str() {
-                    "name" => StationSort::Name,
-                    "votes" => StationSort::Votes,
-                    "clicks" => StationSort::Clicks,
-                    "bitrate" => StationSort::Bitrate,
-                    _ => return Err(anyhow!("invalid sort field: {value}")),
+                let sort = StationSort::parse(value)?;
+                let descending = match direction {
+                    Some(direction) => parse_sort_direction(direction)?,
+                    None => sort.descending_by_default(),
                 };
-                Ok(Self::Sort(sort))
+                Ok(Self::Sort { sort, descending })
+            }
+            Action::More => Ok(Self::More),
+            Action::Favorites => Ok(Self::Favorites),
+            Action::History => Ok(Self::History),
+            Action::Favorite => Ok(Self::Favorite),
+            Action::Unfavorite => Ok(Self::Unfavorite),
+            Action::Vote => Ok(Self::Vote),
+            Action::SaveSearch | Action::LoadSearch => {
+                let name = parts.collect::<Vec<_>>().join(" ");
+                if name.is_empty() {
+                    Err(usage())
+                } else if action == Action::SaveSearch {
+                    Ok(Self::SaveSearch(name))
+                } else {
+                    Ok(Self::LoadSearch(name))
+                }
             }
-            "favorites" => Ok(Self::Favorites),
-            "fav" | "favorite" => Ok(Self::Favorite),
-            "unfav" | "unfavorite" => Ok(Self::Unfavorite),
-            "quit" | "q" => Ok(Self::Quit),
-            "help" => Ok(Self::Help),
+            Action::Searches => Ok(Self::Searches),
+            Action::Quit => Ok(Self::Quit),
+            Action::Help => match parts.next() {
+                None => Ok(Self::Help(None)),
+                Some(name) => {
+                    let name = name.trim_start_matches('/');
+                    let topic = Action::from_name(name)
+                        .ok_or_else(|| anyhow!("no help for unknown command: {name}"))?;
+                    if parts.next().is_some() {
+                        return Err(usage());
+                    }
+                    Ok(Self::Help(Some(topic)))
+                }
+            },
             _ => Err(anyhow!("unknown command: {cmd}")),
         }
     }
 }
 
-fn parse_filter_args(args: &[&str]) -> Result<StationFilters> {
-    let mut filters = StationFilters::default();
+/// Parses a complete filter set in `/filter` syntax, as stored by saved searches; empty
+/// text means no filters.
+pub fn parse_filters(text: &str) -> Result<StationFilters> {
+    let args: Vec<&str> = text.split_whitespace().collect();
+    if args.is_empty() {
+        return Ok(StationFilters::default());
+    }
+    let update = parse_filter_args(&args)?;
+    if update.incremental {
+        return Err(anyhow!(
+            "+key and -key edits are not allowed here; list the filters as key=value"
+        ));
+    }
+    Ok(update.set)
+}
+
+fn parse_filter_args(args: &[&str]) -> Result<FilterUpdate> {
+    let mut update = FilterUpdate::default();
+    let filters = &mut update.set;
 
+    // Words without `=` continue the previous value, so `country=United States` works;
+    // flags such as `https_only` stand alone.
+    let mut clauses: Vec<(&str, bool, String)> = Vec::new();
     for arg in args {
-        let (key, value) = arg
-            .split_once('=')
-            .ok_or_else(|| anyhow!("invalid filter syntax: {arg} (expected key=value)"))?;
+        if let Some(key) = arg.strip_prefix('-').filter(|key| is_filter_key(key)) {
+            update.incremental = true;
+            update.remove.push(key.to_ascii_lowercase());
+            continue;
+        }
+        if let Some((key, _)) = arg.strip_prefix('-').and_then(|arg| arg.split_once('=')) {
+            let key = key.trim_end_matches('!');
+            if is_filter_key(key) {
+                return Err(anyhow!(
+                    "-{key} takes no value; write -{key} to remove the filter or +{key}=<value> to change it"
+                ));
+            }
+        }
+        let arg = match arg.strip_prefix('+') {
+            Some(rest) => {
+                update.incremental = true;
+                rest
+            }
+            None => arg,
+        };
+        if let Some((key, value)) = arg.split_once('=') {
+            let (key, negated) = match key.strip_suffix('!') {
+                Some(key) => (key, true),
+                None => (key, false),
+            };
+            clauses.push((key, negated, value.to_string()));
+        } else if FLAG_KEYS.iter().any(|flag| flag.eq_ignore_ascii_case(arg)) {
+            clauses.push((arg, false, "true".to_string()));
+        } else {
+            match clauses.last_mut() {
+                Some((_, _, value)) if !value.is_empty() => {
+                    value.push(' ');
+                    value.push_str(arg);
+                }
+                _ => return Err(anyhow!("invalid filter syntax: {arg} (expected key=value)")),
+            }
+        }
+    }
+
+    for (key, negated, value) in clauses {
         if value.trim().is_empty() {
             return Err(anyhow!("filter value cannot be empty for key: {key}"));
         }
+        let key = key.to_ascii_lowercase();
 
-        match key.to_ascii_lowercase().as_str() {
-            "country" => filters.country = Some(value.to_string()),
-            "language" => filters.language = Some(value.to_string()),
-            "tag" => filters.tag = Some(value.to_string()),
-            "codec" => filters.codec = Some(value.to_string()),
-            "min_bitrate" => {
-                let bitrate = value
-                    .parse::<u32>()
-                    .map_err(|_| anyhow!("min_bitrate must be an integer"))?;
-                filters.min_bitrate = Some(bitrate);
+        if let Some(facet) = Facet::from_filter_key(&key) {
+            if filter_terms(&value)
+                .iter()
+                .flatten()
+                .any(|term| term.is_empty())
+            {
+                return Err(anyhow!(
+                    "empty value in {key}={value}; remove the extra ',' or '|'"
+                ));
+            }
+            let slot = if negated {
+                filters.excluded_mut(facet)
+            } else {
+                filters.value_mut(facet)
+            };
+            *slot = Some(value);
+            continue;
+        }
+        if negated {
+            return Err(anyhow!(
+                "{key} cannot be negated; != applies to country, language, tag and codec"
+            ));
+        }
+
+        match key.as_str() {
+            "min_bitrate" => filters.min_bitrate = Some(parse_kbps(&key, &value)?),
+            "max_bitrate" => filters.max_bitrate = Some(parse_kbps(&key, &value)?),
+            "has_geo" => filters.has_geo = Some(parse_flag(&key, &value)?),
+            "https_only" | "is_working" if !parse_flag(&key, &value)? => {
+                // Turning a flag off only means something when editing the current filters;
+                // a plain `/filter` leaves it off already, so the clause is almost surely a slip.
+                if !update.incremental {
+                    return Err(anyhow!(
+                        "{key}={value} only applies to edits; use +{key}={value} or -{key} to turn it off, or leave it out"
+                    ));
+                }
+                update.remove.push(key);
+            }
+            "https_only" => filters.https_only = true,
+            "is_working" => filters.is_working = true,
+            _ => {
+                return Err(anyhow!(
+                    "unknown filter key: {key} (expected {}; see /help filter)",
+                    field_names(FILTER_KEYS)
+                ))
             }
-            _ => return Err(anyhow!("unknown filter key: {key}")),
         }
     }
 
-    Ok(filters)
+    check_bitrate_range(&update.set)?;
+    Ok(update)
+}
+
+fn check_bitrate_range(filters: &StationFilters) -> Result<()> {
+    match (filters.min_bitrate, filters.max_bitrate) {
+        (Some(min), Some(max)) if min > max => Err(anyhow!(
+            "min_bitrate={min} is above max_bitrate={max}; no station can match"
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn is_filter_key(name: &str) -> bool {
+    FILTER_KEYS
+        .iter()
+        .any(|(syntax, _)| field_name(syntax).eq_ignore_ascii_case(name) && !name.is_empty())
+}
+
+fn parse_kbps(key: &str, value: &str) -> Result<u32> {
+    value
+        .parse::<u32>()
+        .map_err(|_| anyhow!("{key} must be an integer"))
+}
+
+fn parse_flag(key: &str, value: &str) -> Result<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "1" => Ok(true),
+        "false" | "no" | "0" => Ok(false),
+        _ => Err(anyhow!("{key} must be true or false, got: {value}")),
+    }
+}
+
+/// Leading names of a `(syntax, meaning)` table, skipping placeholder rows like `<key>!=`.
+fn field_names(table: &[(&str, &str)]) -> String {
+    table
+        .iter()
+        .map(|(syntax, _)| field_name(syntax))
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn field_name(syntax: &str) -> &str {
+    let end = syntax
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(syntax.len());
+    &syntax[..end]
 }
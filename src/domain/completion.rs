@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+
+use crate::domain::actions::{Action, ArgSchema};
+use crate::domain::commands::{
+    field_name, BROWSE_FACETS, FILTER_KEYS, SORT_DIRECTIONS, SORT_FIELDS,
+};
+use crate::domain::models::{Facet, FacetValue};
+
+/// Argument values that are not fixed tables: favorite names for `/play`, saved search
+/// names for `/load` and the facet values listed by the station catalog.
+pub struct CompletionSources<'a> {
+    pub station_names: Vec<&'a str>,
+    pub search_names: Vec<&'a str>,
+    pub facets: &'a HashMap<Facet, Vec<FacetValue>>,
+}
+
+/// Tab completion for the token at the end of the slash input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// Byte offset where the token being completed starts.
+    pub start: usize,
+    pub candidates: Vec<String>,
+}
+
+impl Completion {
+    fn none(input: &str) -> Self {
+        Self {
+            start: input.len(),
+            candidates: Vec::new(),
+        }
+    }
+
+    /// `input` with the token replaced by the only candidate, or extended to the prefix all
+    /// candidates share. `None` when Tab would not change anything.
+    pub fn apply(&self, input: &str) -> Option<String> {
+        let typed = input.get(self.start..)?;
+        let replacement = match self.candidates.as_slice() {
+            [] => return None,
+            [only] if only.ends_with('=') => only.clone(),
+            [only] => format!("{only} "),
+            [first, rest @ ..] => {
+                let prefix = common_prefix(first, rest);
+                if prefix.chars().count() <= typed.chars().count() {
+                    return None;
+                }
+                prefix
+            }
+        };
+        let completed = format!("{}{replacement}", &input[..self.start]);
+        (completed != input).then_some(completed)
+    }
+}
+
+pub fn complete(input: &str, sources: &CompletionSources<'_>) -> Completion {
+    let Some(body) = input.strip_prefix('/') else {
+        return Completion::none(input);
+    };
+    let Some((name, _)) = body.split_once(char::is_whitespace) else {
+        return Completion {
+            start: 1,
+            candidates: matching(slash_names(), body),
+        };
+    };
+    let Some(action) = Action::from_slash_name(name) else {
+        return Completion::none(input);
+    };
+    let args_start = input.len() - input[1 + name.len()..].trim_start().len();
+    let args = &input[args_start..];
+
+    match action {
+        Action::Sort => match args.split_once(char::is_whitespace) {
+            None => Completion {
+                start: args_start,
+                candidates: matching(
+                    SORT_FIELDS
+                        .iter()
+                        .map(|(field, _)| field_name(field))
+                        .filter(|field| !field.is_empty()),
+                    args,
+                ),
+            },
+            Some((_, direction)) if !direction.trim_start().contains(char::is_whitespace) => {
+                let direction = direction.trim_start();
+                Completion {
+                    start: input.len() - direction.len(),
+                    candidates: matching(SORT_DIRECTIONS, direction),
+                }
+            }
+            Some(_) => Completion::none(input),
+        },
+        Action::Browse | Action::Help if args.contains(char::is_whitespace) => {
+            Completion::none(input)
+        }
+        Action::Browse => Completion {
+            start: args_start,
+            candidates: matching(BROWSE_FACETS.iter().map(|(facet, _)| *facet), args),
+        },
+        Action::Help => {
+            // `/help /fil` completes the name after the slash.
+            let start = args_start + usize::from(args.starts_with('/'));
+            Completion {
+                start,
+                candidates: matching(slash_names(), &input[start..]),
+            }
+        }
+        Action::Play => Completion {
+            start: args_start,
+            candidates: matching(
+                std::iter::once("selected").chain(sources.station_names.iter().copied()),
+                args,
+            ),
+        },
+        Action::LoadSearch => Completion {
+            start: args_start,
+            candidates: matching(sources.search_names.iter().copied(), args),
+        },
+        Action::Filter => complete_filter(input, args_start, sources),
+        _ => Completion::none(input),
+    }
+}
+
+fn complete_filter(input: &str, args_start: usize, sources: &CompletionSources<'_>) -> Completion {
+    let mut token_start = last_token_start(input, args_start);
+    // `+tag=` completes like the plain key; `-country` takes no value.
+    let removing = input[token_start..].starts_with('-');
+    if removing || input[token_start..].starts_with('+') {
+        token_start += 1;
+    }
+    let token = &input[token_start..];
+    if !token.contains('=') {
+        let keys = if removing {
+            matching(filter_keys().map(|key| key.trim_end_matches('=')), token)
+        } else {
+            matching(filter_keys(), token)
+        };
+        if !keys.is_empty() {
+            return Completion {
+                start: token_start,
+                candidates: keys,
+            };
+        }
+    }
+
+    let Some((facet, value_start)) = filter_value(input, args_start) else {
+        return Completion::none(input);
+    };
+    let values: Vec<&str> = sources
+        .facets
+        .get(&facet)
+        .map(|values| values.iter().map(|value| value.name.as_str()).collect())
+        .unwrap_or_default();
+    Completion {
+        start: value_start,
+        candidates: matching(values, &input[value_start..]),
+    }
+}
+
+/// The facet whose values `/filter` is waiting for, so they can be fetched ahead of Tab.
+pub fn wanted_facet(input: &str) -> Option<Facet> {
+    let rest = input.strip_prefix('/')?;
+    let (name, _) = rest.split_once(char::is_whitespace)?;
+    if Action::from_slash_name(name)? != Action::Filter {
+        return None;
+    }
+    let args_start = input.len() - input[1 + name.len()..].trim_start().len();
+    filter_value(input, args_start).map(|(facet, _)| facet)
+}
+
+/// Ghost text shown after the cursor: the rest of the first completion, or the usage of
+/// the arguments still to be typed.
+pub fn hint(input: &str, sources: &CompletionSources<'_>) -> Option<String> {
+    let body = input.strip_prefix('/')?;
+    let completion = complete(input, sources);
+    let typed = &input[completion.start..];
+    let rest = completion
+        .candidates
+        .first()
+        .filter(|candidate| candidate.to_lowercase().starts_with(&typed.to_lowercase()))
+        .and_then(|candidate| candidate.get(typed.len()..))
+        .unwrap_or_default();
+
+    match body.split_once(char::is_whitespace) {
+        None => {
+            let name = completion.candidates.first()?;
+            let action = Action::from_slash_name(name)?;
+            match action.spec().args {
+                ArgSchema::None => Some(rest.to_string()),
+                ArgSchema::Optional(args) | ArgSchema::Required(args) => {
+                    Some(format!("{rest} {args}"))
+                }
+            }
+        }
+        Some((name, args)) => {
+            let action = Action::from_slash_name(name)?;
+            match action.spec().args {
+                ArgSchema::Optional(usage) | ArgSchema::Required(usage)
+                    if args.trim().is_empty() =>
+                {
+                    Some(usage.to_string())
+                }
+                _ if rest.is_empty() => None,
+                _ => Some(rest.to_string()),
+            }
+        }
+    }
+    .filter(|hint| !hint.is_empty())
+}
+
+/// Facet of the last `key=` or `key!=` in the `/filter` arguments, and the offset of the
+/// value being typed after it (past any `,` or `|`).
+fn filter_value(input: &str, args_start: usize) -> Option<(Facet, usize)> {
+    let eq = args_start + input[args_start..].rfind('=')?;
+    let key_start = last_token_start(&input[..eq], args_start);
+    let key = input[key_start..eq].trim_start_matches('+');
+    let facet = Facet::from_filter_key(key.strip_suffix('!').unwrap_or(key))?;
+    let value_start = input[eq + 1..]
+        .rfind([',', '|'])
+        .map_or(eq + 1, |idx| eq + 1 + idx + 1);
+    Some((facet, value_start))
+}
+
+fn last_token_start(input: &str, from: usize) -> usize {
+    input[from..]
+        .rfind(char::is_whitespace)
+        .map(|idx| from + idx + 1)
+        .unwrap_or(from)
+}
+
+fn slash_names() -> impl Iterator<Item = &'static str> {
+    Action::ALL
+        .into_iter()
+        .filter(|action| action.spec().slash)
+        .map(Action::name)
+}
+
+/// `country=`-style keys that take a value, and bare flags like `https_only`.
+fn filter_keys() -> impl Iterator<Item = &'static str> {
+    FILTER_KEYS.iter().filter_map(|(syntax, _)| {
+        let key = field_name(syntax);
+        if key.is_empty() {
+            None
+        } else if syntax[key.len()..].starts_with("=<") {
+            Some(&syntax[..=key.len()])
+        } else {
+            Some(key)
+        }
+    })
+}
+
+/// Options starting with `typed`, ignoring case, in their original order.
+fn matching<'a>(options: impl IntoIterator<Item = &'a str>, typed: &str) -> Vec<String> {
+    let typed = typed.to_lowercase();
+    let mut matches: Vec<String> = Vec::new();
+    for option in options {
+        if option.to_lowercase().starts_with(&typed) && !matches.iter().any(|m| m == option) {
+            matches.push(option.to_string());
+        }
+    }
+    matches
+}
+
+fn common_prefix(first: &str, rest: &[String]) -> String {
+    let mut len = first.len();
+    for other in rest {
+        let shared = first
+            .char_indices()
+            .zip(other.chars())
+            .take_while(|((_, a), b)| a.to_lowercase().eq(b.to_lowercase()))
+            .last()
+            .map_or(0, |((idx, a), _)| idx + a.len_utf8());
+        len = len.min(shared);
+    }
+    first[..len].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facets() -> HashMap<Facet, Vec<FacetValue>> {
+        let values = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| FacetValue {
+                    name: name.to_string(),
+                    station_count: 1,
+                })
+                .collect()
+        };
+        HashMap::from([
+            (
+                Facet::Country,
+                values(&["Germany", "United Kingdom", "United States"]),
+            ),
+            (Facet::Language, values(&["english", "german"])),
+        ])
+    }
+
+    fn tab(input: &str) -> Option<String> {
+        let facets = facets();
+        let sources = CompletionSources {
+            station_names: vec!["Jazz FM", "Jazz Radio"],
+            search_names: vec!["late jazz", "news"],
+            facets: &facets,
+        };
+        complete(input, &sources).apply(input)
+    }
+
+    #[test]
+    fn completes_command_names_and_arguments() {
+        assert_eq!(tab("/vol").as_deref(), Some("/volume "));
+        assert_eq!(tab("/sort vo").as_deref(), Some("/sort votes "));
+        assert_eq!(tab("/browse t").as_deref(), Some("/browse tag "));
+        assert_eq!(tab("/help /fil").as_deref(), Some("/help /filter "));
+        assert_eq!(tab("/play ja").as_deref(), Some("/play Jazz "));
+        assert_eq!(tab("/play Jazz R").as_deref(), Some("/play Jazz Radio "));
+        assert_eq!(tab("/load la").as_deref(), Some("/load late jazz "));
+        assert_eq!(tab("/sort votes "), None);
+        assert_eq!(tab("/sort votes a").as_deref(), Some("/sort votes asc "));
+        assert_eq!(tab("/sort votes asc "), None);
+    }
+
+    #[test]
+    fn completes_filter_keys_then_facet_values() {
+        assert_eq!(tab("/filter co"), None);
+        assert_eq!(tab("/filter cou").as_deref(), Some("/filter country="));
+        assert_eq!(
+            tab("/filter country=uni").as_deref(),
+            Some("/filter country=United ")
+        );
+        assert_eq!(
+            tab("/filter country=United St").as_deref(),
+            Some("/filter country=United States ")
+        );
+        assert_eq!(
+            tab("/filter country=Germany la").as_deref(),
+            Some("/filter country=Germany language=")
+        );
+        assert_eq!(
+            tab("/filter language=EN").as_deref(),
+            Some("/filter language=english ")
+        );
+        assert_eq!(tab("/filter min_bitrate=1"), None);
+        assert_eq!(
+            tab("/filter language!=ge").as_deref(),
+            Some("/filter language!=german ")
+        );
+        assert_eq!(
+            tab("/filter country=Germany|uni").as_deref(),
+            Some("/filter country=Germany|United ")
+        );
+        assert_eq!(tab("/filter http").as_deref(), Some("/filter https_only "));
+        assert_eq!(tab("/filter +lang").as_deref(), Some("/filter +language="));
+        assert_eq!(
+            tab("/filter +language=ger").as_deref(),
+            Some("/filter +language=german ")
+        );
+        assert_eq!(tab("/filter -cou").as_deref(), Some("/filter -country "));
+    }
+
+    #[test]
+    fn hints_show_the_rest_of_the_command_and_its_usage() {
+        let facets = facets();
+        let sources = CompletionSources {
+            station_names: Vec::new(),
+            search_names: Vec::new(),
+            facets: &facets,
+        };
+        assert_eq!(hint("/vol", &sources).as_deref(), Some("ume <0-100>"));
+        assert_eq!(hint("/volume ", &sources).as_deref(), Some("<0-100>"));
+        assert_eq!(hint("/sort cl", &sources).as_deref(), Some("icks"));
+        assert_eq!(hint("/stop", &sources), None);
+        assert_eq!(hint("/volume 40", &sources), None);
+    }
+
+    #[test]
+    fn filter_values_are_wanted_after_a_facet_key() {
+        assert_eq!(wanted_facet("/filter tag="), Some(Facet::Tag));
+        assert_eq!(
+            wanted_facet("/filter tag=jazz country=Uni"),
+            Some(Facet::Country)
+        );
+        assert_eq!(wanted_facet("/filter min_bitrate="), None);
+        assert_eq!(wanted_facet("/sort tag="), None);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::domain::actions::{self, Action, ArgSchema};
+use crate::domain::keymap::Keymap;
+use crate::storage::searches::SavedSearch;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpLine {
+    pub text: String,
+    pub heading: bool,
+}
+
+impl HelpLine {
+    fn heading(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            heading: true,
+        }
+    }
+
+    fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            heading: false,
+        }
+    }
+}
+
+/// Keys handled directly by the input widgets rather than through the keymap.
+const FIXED_KEYS: [(&str, &str); 4] = [
+    ("Enter", "Run the input, or play the selected station"),
+    ("Esc", "Close the palette or this help"),
+    ("Backspace", "Delete the last character"),
+    ("Ctrl+C", "Quit (cannot be rebound)"),
+];
+
+/// Every slash command, key binding and palette action, built from the action registry.
+pub fn overview(keymap: &Keymap) -> Vec<HelpLine> {
+    let mut lines = Vec::new();
+
+    let commands: Vec<(String, String)> = Action::ALL
+        .into_iter()
+        .filter(|action| action.spec().slash)
+        .flat_map(|action| {
+            let spec = action.spec();
+            std::iter::once((action.usage(), spec.description.to_string())).chain(
+                spec.examples
+                    .iter()
+                    .map(|example| (String::new(), format!("e.g. {example}"))),
+            )
+        })
+        .collect();
+    push_section(&mut lines, "Slash commands", &commands);
+
+    let mut keys: Vec<(String, String)> = Action::ALL
+        .into_iter()
+        .filter(|action| !keymap.keys_for(*action).is_empty())
+        .map(|action| (keymap.label(action), action.description().to_string()))
+        .collect();
+    keys.extend(
+        FIXED_KEYS
+            .iter()
+            .map(|(key, description)| (key.to_string(), description.to_string())),
+    );
+    push_section(&mut lines, "Keys", &keys);
+
+    let palette: Vec<(String, String)> = actions::palette_items()
+        .into_iter()
+        .map(|item| (item.label, format!("/{}", item.action)))
+        .collect();
+    push_section(&mut lines, "Palette actions", &palette);
+
+    lines.push(HelpLine::text(
+        "Type /help <command> for argument details, e.g. /help filter.",
+    ));
+    lines
+}
+
+/// Usage, arguments, examples and bindings for a single action.
+pub fn command_help(action: Action, keymap: &Keymap) -> Vec<HelpLine> {
+    let spec = action.spec();
+    let mut lines = Vec::new();
+
+    push_section(
+        &mut lines,
+        "Usage",
+        &[(action.usage(), spec.description.to_string())],
+    );
+    if let ArgSchema::Optional(args) | ArgSchema::Required(args) = spec.args {
+        if spec.details.is_empty() {
+            push_section(
+                &mut lines,
+                "Arguments",
+                &[(args.to_string(), String::new())],
+            );
+        }
+    }
+    if !spec.details.is_empty() {
+        let rows: Vec<(String, String)> = spec
+            .details
+            .iter()
+            .map(|(syntax, meaning)| (syntax.to_string(), meaning.to_string()))
+            .collect();
+        push_section(&mut lines, "Arguments", &rows);
+    }
+    if !spec.examples.is_empty() {
+        let rows: Vec<(String, String)> = spec
+            .examples
+            .iter()
+            .map(|example| (example.to_string(), String::new()))
+            .collect();
+        push_section(&mut lines, "Examples", &rows);
+    }
+    if !spec.aliases.is_empty() {
+        let aliases = spec
+            .aliases
+            .iter()
+            .map(|alias| {
+                if spec.slash {
+                    format!("/{alias}")
+                } else {
+                    alias.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        push_section(&mut lines, "Aliases", &[(aliases, String::new())]);
+    }
+    let keys = if keymap.keys_for(action).is_empty() {
+        format!("unbound (set `{}` in [keys])", spec.name)
+    } else {
+        keymap.label(action)
+    };
+    push_section(&mut lines, "Keys", &[(keys, String::new())]);
+    lines
+}
+
+/// Saved searches and config presets for `/searches`, each with what it restores.
+pub fn saved_searches(saved: &[SavedSearch], presets: &[SavedSearch]) -> Vec<HelpLine> {
+    let mut lines = Vec::new();
+    let rows = |searches: &[SavedSearch]| -> Vec<(String, String)> {
+        searches
+            .iter()
+            .map(|search| (search.name.clone(), search.summary()))
+            .collect()
+    };
+    if !saved.is_empty() {
+        push_section(&mut lines, "Saved searches", &rows(saved));
+    }
+    if !presets.is_empty() {
+        push_section(&mut lines, "Config presets", &rows(presets));
+    }
+    lines.push(HelpLine::text(if saved.is_empty() && presets.is_empty() {
+        "No saved searches yet. Use /save-search <name> to save the current one."
+    } else {
+        "Type /load <name> to run one."
+    }));
+    lines
+}
+
+fn push_section(lines: &mut Vec<HelpLine>, title: &str, rows: &[(String, String)]) {
+    let width = rows
+        .iter()
+        .map(|(left, _)| left.chars().count())
+        .max()
+        .unwrap_or(0);
+    lines.push(HelpLine::heading(title));
+    for (left, right) in rows {
+        let row = if right.is_empty() {
+            format!("  {left}")
+        } else {
+            format!("  {left:<width$}  {right}")
+        };
+        lines.push(HelpLine::text(row));
+    }
+    lines.push(HelpLine::text(""));
+}
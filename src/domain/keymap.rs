@@ -0,0 +1,300 @@
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+
+use crate::domain::actions::Action;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Esc,
+    Tab,
+    BackTab,
+    Backspace,
+    Delete,
+    Insert,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    F(u8),
+}
+
+/// A key plus modifiers. Shift is folded into the character for `Char` keys (`Shift+q` is `Q`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub key: Key,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl KeyChord {
+    pub fn new(key: Key) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub fn ctrl(key: Key) -> Self {
+        Self {
+            ctrl: true,
+            ..Self::new(key)
+        }
+    }
+
+    /// Parses chords like `q`, `Q`, `ctrl+p`, `shift+tab`, `space`, `pageup` or `f5`.
+    pub fn parse(value: &str) -> Result<Self> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return Err(anyhow!("empty key chord"));
+        }
+
+        let mut parts: Vec<&str> = trimmed.split('+').collect();
+        // `+` itself and chords like `ctrl++` end with an empty segment.
+        if trimmed.ends_with('+') {
+            parts.truncate(parts.len().saturating_sub(2));
+            parts.push("+");
+        }
+        let key_name = parts.pop().unwrap_or_default();
+
+        let mut chord = Self::new(Key::Char(' '));
+        for modifier in parts {
+            match modifier.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => chord.ctrl = true,
+                "alt" | "meta" => chord.alt = true,
+                "shift" => chord.shift = true,
+                other => {
+                    return Err(anyhow!(
+                        "invalid key chord '{value}': unknown modifier '{other}' (expected ctrl, alt or shift)"
+                    ))
+                }
+            }
+        }
+
+        chord.key = parse_key(key_name)
+            .ok_or_else(|| anyhow!("invalid key chord '{value}': unknown key '{key_name}'"))?;
+        Ok(chord.normalized())
+    }
+
+    /// Plain characters are typed into focused inputs instead of triggering bindings.
+    pub fn is_text(&self) -> bool {
+        matches!(self.key, Key::Char(_)) && !self.ctrl && !self.alt
+    }
+
+    fn normalized(mut self) -> Self {
+        match self.key {
+            Key::Char(c) if self.shift => {
+                self.key = Key::Char(c.to_ascii_uppercase());
+                self.shift = false;
+            }
+            Key::Tab if self.shift => {
+                self.key = Key::BackTab;
+                self.shift = false;
+            }
+            Key::BackTab => self.shift = false,
+            _ => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        match self.key {
+            Key::Char(' ') => f.write_str("Space"),
+            Key::Char(c) if self.ctrl || self.alt => write!(f, "{}", c.to_ascii_uppercase()),
+            Key::Char(c) => write!(f, "{c}"),
+            Key::Enter => f.write_str("Enter"),
+            Key::Esc => f.write_str("Esc"),
+            Key::Tab => f.write_str("Tab"),
+            Key::BackTab => f.write_str("Shift+Tab"),
+            Key::Backspace => f.write_str("Backspace"),
+            Key::Delete => f.write_str("Delete"),
+            Key::Insert => f.write_str("Insert"),
+            Key::Up => f.write_str("Up"),
+            Key::Down => f.write_str("Down"),
+            Key::Left => f.write_str("Left"),
+            Key::Right => f.write_str("Right"),
+            Key::PageUp => f.write_str("PageUp"),
+            Key::PageDown => f.write_str("PageDown"),
+            Key::Home => f.write_str("Home"),
+            Key::End => f.write_str("End"),
+            Key::F(n) => write!(f, "F{n}"),
+        }
+    }
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(Key::Char(c));
+    }
+
+    let key = match name.trim().to_ascii_lowercase().as_str() {
+        "space" => Key::Char(' '),
+        "enter" | "return" => Key::Enter,
+        "esc" | "escape" => Key::Esc,
+        "tab" => Key::Tab,
+        "backtab" => Key::BackTab,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "insert" | "ins" => Key::Insert,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "pageup" | "pgup" => Key::PageUp,
+        "pagedown" | "pgdn" => Key::PageDown,
+        "home" => Key::Home,
+        "end" => Key::End,
+        other => {
+            let n = other.strip_prefix('f')?.parse::<u8>().ok()?;
+            if (1..=12).contains(&n) {
+                Key::F(n)
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(key)
+}
+
+/// Editing keys and the emergency quit that cannot be rebound.
+pub const RESERVED_CHORDS: [KeyChord; 4] = [
+    KeyChord {
+        key: Key::Enter,
+        ctrl: false,
+        alt: false,
+        shift: false,
+    },
+    KeyChord {
+        key: Key::Esc,
+        ctrl: false,
+        alt: false,
+        shift: false,
+    },
+    KeyChord {
+        key: Key::Backspace,
+        ctrl: false,
+        alt: false,
+        shift: false,
+    },
+    KeyChord {
+        key: Key::Char('c'),
+        ctrl: true,
+        alt: false,
+        shift: false,
+    },
+];
+
+/// Effective key bindings: built-in defaults with per-action overrides from `[keys]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: Vec<(Action, Vec<KeyChord>)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: Action::ALL
+                .into_iter()
+                .map(|action| {
+                    let keys = action
+                        .spec()
+                        .default_keys
+                        .iter()
+                        .map(|chord| KeyChord::parse(chord).expect("built-in key chord is valid"))
+                        .collect();
+                    (action, keys)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Replaces the keys of each overridden action, then rejects reserved or doubly bound chords.
+    pub fn with_overrides(
+        overrides: impl IntoIterator<Item = (Action, Vec<KeyChord>)>,
+    ) -> Result<Self> {
+        let mut keymap = Self::default();
+        for (action, keys) in overrides {
+            if let Some((_, bound)) = keymap.bindings.iter_mut().find(|(a, _)| *a == action) {
+                *bound = keys;
+            }
+        }
+        keymap.validate()?;
+        Ok(keymap)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let mut seen: Vec<(KeyChord, Action)> = Vec::new();
+        for (action, keys) in &self.bindings {
+            for chord in keys {
+                if RESERVED_CHORDS.contains(chord) {
+                    return Err(anyhow!(
+                        "{chord} is reserved and cannot be bound to {}",
+                        action.name()
+                    ));
+                }
+                match seen.iter().find(|(other, _)| other == chord) {
+                    Some((_, other_action)) if other_action != action => {
+                        return Err(anyhow!(
+                            "{chord} is bound to both {} and {}; rebind or unbind one of them",
+                            other_action.name(),
+                            action.name()
+                        ));
+                    }
+                    Some(_) => {}
+                    None => seen.push((*chord, *action)),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn action_for(&self, chord: &KeyChord) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(_, keys)| keys.contains(chord))
+            .map(|(action, _)| *action)
+    }
+
+    pub fn keys_for(&self, action: Action) -> &[KeyChord] {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, keys)| keys.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// `j/Down`-style label, or `unbound`.
+    pub fn label(&self, action: Action) -> String {
+        let keys = self.keys_for(action);
+        if keys.is_empty() {
+            return "unbound".to_string();
+        }
+        keys.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
@@ -1,3 +1,8 @@
+pub mod actions;
+pub mod columns;
 pub mod commands;
+pub mod completion;
+pub mod help;
+pub mod keymap;
 pub mod models;
 pub mod palette;
@@ -1,3 +1,6 @@
+use std::fmt;
+
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -18,6 +21,14 @@ pub struct Station {
     pub votes: Option<u32>,
     #[serde(alias = "clicks")]
     pub click_count: Option<u32>,
+    #[serde(default)]
+    pub has_geo_info: bool,
+    /// Radio Browser's `lastcheckok`; `None` when the catalog does not check stations.
+    #[serde(default)]
+    pub last_check_ok: Option<bool>,
+    /// When the station entry last changed, as an ISO 8601 timestamp.
+    #[serde(default)]
+    pub last_change_time: Option<String>,
 }
 
 impl Station {
@@ -32,55 +43,359 @@ impl Station {
     }
 }
 
+/// Search filters. Facet values are expressions: `,` requires every value and `|` any of
+/// them (`tag=jazz,blues`, `codec=mp3|aac`); excluded values drop a station if any matches.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct StationFilters {
     pub country: Option<String>,
     pub language: Option<String>,
     pub tag: Option<String>,
     pub codec: Option<String>,
+    pub exclude_country: Option<String>,
+    pub exclude_language: Option<String>,
+    pub exclude_tag: Option<String>,
+    pub exclude_codec: Option<String>,
     pub min_bitrate: Option<u32>,
+    pub max_bitrate: Option<u32>,
+    pub has_geo: Option<bool>,
+    pub https_only: bool,
+    pub is_working: bool,
 }
 
 impl StationFilters {
+    /// Sets the filter that matches `facet` values, e.g. a country picked in `/browse`.
+    pub fn set(&mut self, facet: Facet, value: String) {
+        *self.value_mut(facet) = Some(value);
+    }
+
+    pub fn value(&self, facet: Facet) -> Option<&str> {
+        match facet {
+            Facet::Country => self.country.as_deref(),
+            Facet::Language => self.language.as_deref(),
+            Facet::Tag => self.tag.as_deref(),
+            Facet::Codec => self.codec.as_deref(),
+        }
+    }
+
+    pub fn value_mut(&mut self, facet: Facet) -> &mut Option<String> {
+        match facet {
+            Facet::Country => &mut self.country,
+            Facet::Language => &mut self.language,
+            Facet::Tag => &mut self.tag,
+            Facet::Codec => &mut self.codec,
+        }
+    }
+
+    pub fn excluded(&self, facet: Facet) -> Option<&str> {
+        match facet {
+            Facet::Country => self.exclude_country.as_deref(),
+            Facet::Language => self.exclude_language.as_deref(),
+            Facet::Tag => self.exclude_tag.as_deref(),
+            Facet::Codec => self.exclude_codec.as_deref(),
+        }
+    }
+
+    pub fn excluded_mut(&mut self, facet: Facet) -> &mut Option<String> {
+        match facet {
+            Facet::Country => &mut self.exclude_country,
+            Facet::Language => &mut self.exclude_language,
+            Facet::Tag => &mut self.exclude_tag,
+            Facet::Codec => &mut self.exclude_codec,
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.country.is_none()
-            && self.language.is_none()
-            && self.tag.is_none()
-            && self.codec.is_none()
-            && self.min_bitrate.is_none()
+        *self == Self::default()
+    }
+
+    /// Overrides every filter that `other` sets and keeps the rest.
+    pub fn merge(&mut self, other: &StationFilters) {
+        for facet in Facet::ALL {
+            if let Some(value) = other.value(facet) {
+                *self.value_mut(facet) = Some(value.to_string());
+            }
+            if let Some(value) = other.excluded(facet) {
+                *self.excluded_mut(facet) = Some(value.to_string());
+            }
+        }
+        self.min_bitrate = other.min_bitrate.or(self.min_bitrate);
+        self.max_bitrate = other.max_bitrate.or(self.max_bitrate);
+        self.has_geo = other.has_geo.or(self.has_geo);
+        self.https_only |= other.https_only;
+        self.is_working |= other.is_working;
     }
+
+    /// Drops the filter named by a `/filter` key; a facet key drops its exclusions too.
+    /// Returns false for unknown keys.
+    pub fn remove(&mut self, key: &str) -> bool {
+        if let Some(facet) = Facet::from_filter_key(key) {
+            *self.value_mut(facet) = None;
+            *self.excluded_mut(facet) = None;
+            return true;
+        }
+        match key {
+            "min_bitrate" => self.min_bitrate = None,
+            "max_bitrate" => self.max_bitrate = None,
+            "has_geo" => self.has_geo = None,
+            "https_only" => self.https_only = false,
+            "is_working" => self.is_working = false,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Applies every filter locally; catalogs use this for whatever their server cannot.
+    pub fn matches(&self, station: &Station) -> bool {
+        let facets_match = Facet::ALL.into_iter().all(|facet| {
+            let included = self.value(facet).is_none_or(|expr| {
+                filter_terms(expr).iter().all(|any_of| {
+                    any_of
+                        .iter()
+                        .any(|term| station.has_facet_value(facet, term))
+                })
+            });
+            let excluded = self.excluded(facet).is_some_and(|expr| {
+                filter_terms(expr)
+                    .iter()
+                    .flatten()
+                    .any(|term| station.has_facet_value(facet, term))
+            });
+            included && !excluded
+        });
+        let bitrate = station.bitrate.unwrap_or(0);
+        facets_match
+            && self.min_bitrate.is_none_or(|min| bitrate >= min)
+            && self
+                .max_bitrate
+                .is_none_or(|max| station.bitrate.is_some_and(|bitrate| bitrate <= max))
+            && self
+                .has_geo
+                .is_none_or(|has_geo| station.has_geo_info == has_geo)
+            && (!self.https_only || station.url_resolved.starts_with("https://"))
+            && (!self.is_working || station.last_check_ok == Some(true))
+    }
+}
+
+/// The filters in `/filter` syntax, e.g. `tag=jazz,blues tag!=talk https_only`.
+impl fmt::Display for StationFilters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut clauses = Vec::new();
+        for facet in Facet::ALL {
+            if let Some(value) = self.value(facet) {
+                clauses.push(format!("{}={value}", facet.filter_key()));
+            }
+            if let Some(value) = self.excluded(facet) {
+                clauses.push(format!("{}!={value}", facet.filter_key()));
+            }
+        }
+        if let Some(min) = self.min_bitrate {
+            clauses.push(format!("min_bitrate={min}"));
+        }
+        if let Some(max) = self.max_bitrate {
+            clauses.push(format!("max_bitrate={max}"));
+        }
+        match self.has_geo {
+            Some(true) => clauses.push("has_geo".to_string()),
+            Some(false) => clauses.push("has_geo=false".to_string()),
+            None => {}
+        }
+        if self.https_only {
+            clauses.push("https_only".to_string());
+        }
+        if self.is_working {
+            clauses.push("is_working".to_string());
+        }
+        f.write_str(&clauses.join(" "))
+    }
+}
+
+/// Splits a filter expression into groups that must all match, each satisfied by any of
+/// its terms: `jazz|blues,lounge` is `[[jazz, blues], [lounge]]`.
+pub fn filter_terms(expr: &str) -> Vec<Vec<&str>> {
+    expr.split(',')
+        .map(|group| group.split('|').map(str::trim).collect())
+        .collect()
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+impl Station {
+    /// Case-insensitive facet match; countries also match their ISO code (`US`).
+    fn has_facet_value(&self, facet: Facet, term: &str) -> bool {
+        let eq = |value: &str| value.trim().eq_ignore_ascii_case(term);
+        match facet {
+            Facet::Country => {
+                self.country.as_deref().is_some_and(eq)
+                    || self.country_code.as_deref().is_some_and(eq)
+            }
+            Facet::Language => self
+                .language
+                .as_deref()
+                .is_some_and(|languages| languages.split(',').any(eq)),
+            Facet::Tag => self.tags.iter().any(|tag| eq(tag)),
+            Facet::Codec => self.codec.as_deref().is_some_and(eq),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum StationSort {
     Name,
     #[default]
     Votes,
     Clicks,
     Bitrate,
+    Country,
+    Language,
+    Codec,
+    LastCheck,
+    Changed,
+    Random,
 }
 
 impl StationSort {
+    pub const ALL: [Self; 10] = [
+        Self::Name,
+        Self::Votes,
+        Self::Clicks,
+        Self::Bitrate,
+        Self::Country,
+        Self::Language,
+        Self::Codec,
+        Self::LastCheck,
+        Self::Changed,
+        Self::Random,
+    ];
+
+    /// The `/sort` field name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Votes => "votes",
+            Self::Clicks => "clicks",
+            Self::Bitrate => "bitrate",
+            Self::Country => "country",
+            Self::Language => "language",
+            Self::Codec => "codec",
+            Self::LastCheck => "lastcheck",
+            Self::Changed => "changed",
+            Self::Random => "random",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self> {
+        let value = value.trim();
+        Self::ALL
+            .into_iter()
+            .find(|sort| sort.name().eq_ignore_ascii_case(value))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|sort| sort.name()).collect();
+                anyhow!(
+                    "invalid sort field '{value}' (expected {})",
+                    names.join(", ")
+                )
+            })
+    }
+
     pub fn as_api_order(self) -> &'static str {
         match self {
             Self::Name => "name",
             Self::Votes => "votes",
             Self::Clicks => "clickcount",
             Self::Bitrate => "bitrate",
+            Self::Country => "country",
+            Self::Language => "language",
+            Self::Codec => "codec",
+            Self::LastCheck => "lastcheckok",
+            Self::Changed => "changetimestamp",
+            Self::Random => "random",
+        }
+    }
+
+    /// Direction used when none is picked: text A→Z, counts highest first, working
+    /// stations first and the most recently changed first.
+    pub fn descending_by_default(self) -> bool {
+        matches!(
+            self,
+            Self::Votes | Self::Clicks | Self::Bitrate | Self::LastCheck | Self::Changed
+        )
+    }
+
+    /// `votes desc`, as shown in status messages.
+    pub fn label(self, descending: bool) -> String {
+        if self == Self::Random {
+            return self.name().to_string();
         }
+        format!(
+            "{} {}",
+            self.name(),
+            if descending { "desc" } else { "asc" }
+        )
     }
+}
 
-    pub fn is_descending(self) -> bool {
-        !matches!(self, Self::Name)
+/// Parses the `asc`/`desc` argument of `/sort`; `true` means descending.
+pub fn parse_sort_direction(value: &str) -> Result<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "asc" => Ok(false),
+        "desc" => Ok(true),
+        _ => Err(anyhow!(
+            "invalid sort direction '{value}' (expected asc or desc)"
+        )),
+    }
+}
+
+/// Station attributes the catalog can list with station counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Facet {
+    Country,
+    Language,
+    Tag,
+    Codec,
+}
+
+impl Facet {
+    pub const ALL: [Self; 4] = [Self::Country, Self::Language, Self::Tag, Self::Codec];
+
+    /// The `/filter` key that takes this facet's values.
+    pub fn filter_key(self) -> &'static str {
+        match self {
+            Self::Country => "country",
+            Self::Language => "language",
+            Self::Tag => "tag",
+            Self::Codec => "codec",
+        }
+    }
+
+    pub fn from_filter_key(key: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|facet| facet.filter_key().eq_ignore_ascii_case(key))
+    }
+
+    pub fn plural(self) -> &'static str {
+        match self {
+            Self::Country => "countries",
+            Self::Language => "languages",
+            Self::Tag => "tags",
+            Self::Codec => "codecs",
+        }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FacetValue {
+    pub name: String,
+    pub station_count: u32,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StationSearchQuery {
     pub query: String,
     pub filters: StationFilters,
     pub sort: StationSort,
+    pub descending: bool,
     pub limit: usize,
+    pub offset: usize,
 }
 
 impl Default for StationSearchQuery {
@@ -89,7 +404,9 @@ impl Default for StationSearchQuery {
             query: String::new(),
             filters: StationFilters::default(),
             sort: StationSort::default(),
+            descending: StationSort::default().descending_by_default(),
             limit: 50,
+            offset: 0,
         }
     }
 }
@@ -98,6 +415,86 @@ impl Default for StationSearchQuery {
 mod tests {
     use super::*;
 
+    fn station(tags: &[&str], codec: &str, url: &str) -> Station {
+        Station {
+            station_uuid: "id".to_string(),
+            name: "Station".to_string(),
+            url_resolved: url.to_string(),
+            homepage: None,
+            favicon: None,
+            tags: tags.iter().map(ToString::to_string).collect(),
+            country: Some("United States".to_string()),
+            country_code: Some("US".to_string()),
+            language: Some("english,spanish".to_string()),
+            codec: Some(codec.to_string()),
+            bitrate: Some(128),
+            votes: None,
+            click_count: None,
+            has_geo_info: false,
+            last_check_ok: Some(true),
+            last_change_time: None,
+        }
+    }
+
+    #[test]
+    fn filters_combine_all_any_and_excluded_values() {
+        let jazz_blues = station(&["Jazz", "blues"], "MP3", "https://example.com/a");
+        let jazz_talk = station(&["jazz", "talk"], "AAC", "http://example.com/b");
+        let filters = |apply: fn(&mut StationFilters)| {
+            let mut filters = StationFilters::default();
+            apply(&mut filters);
+            filters
+        };
+
+        let both = filters(|f| f.tag = Some("jazz,blues".to_string()));
+        assert!(both.matches(&jazz_blues));
+        assert!(!both.matches(&jazz_talk));
+
+        let either = filters(|f| f.tag = Some("blues|talk".to_string()));
+        assert!(either.matches(&jazz_blues) && either.matches(&jazz_talk));
+
+        let no_talk = filters(|f| f.exclude_tag = Some("talk,news".to_string()));
+        assert!(no_talk.matches(&jazz_blues));
+        assert!(!no_talk.matches(&jazz_talk));
+
+        let codecs = filters(|f| f.codec = Some("mp3|opus".to_string()));
+        assert!(codecs.matches(&jazz_blues));
+        assert!(!codecs.matches(&jazz_talk));
+
+        let by_code = filters(|f| f.country = Some("us".to_string()));
+        let by_language = filters(|f| f.language = Some("Spanish".to_string()));
+        assert!(by_code.matches(&jazz_blues) && by_language.matches(&jazz_blues));
+    }
+
+    #[test]
+    fn filters_check_bitrate_flags_and_health() {
+        let mut station = station(&[], "MP3", "http://example.com/a");
+        let mut filters = StationFilters {
+            min_bitrate: Some(96),
+            max_bitrate: Some(128),
+            ..StationFilters::default()
+        };
+        assert!(filters.matches(&station));
+
+        filters.https_only = true;
+        assert!(!filters.matches(&station));
+        station.url_resolved = "https://example.com/a".to_string();
+        assert!(filters.matches(&station));
+
+        filters.has_geo = Some(true);
+        assert!(!filters.matches(&station));
+        station.has_geo_info = true;
+        assert!(filters.matches(&station));
+
+        filters.is_working = true;
+        station.last_check_ok = None;
+        assert!(!filters.matches(&station));
+
+        station.last_check_ok = Some(true);
+        station.bitrate = Some(192);
+        assert!(!filters.matches(&station));
+    }
+
     #[test]
     fn deserialize_legacy_station_fields() {
         let json = r#"{
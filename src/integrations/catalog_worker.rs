@@ -0,0 +1,246 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use crate::domain::models::{Facet, FacetValue, Station, StationSearchQuery};
+
+use super::station_catalog::StationCatalog;
+
+pub struct CatalogResponse {
+    pub id: u64,
+    pub result: CatalogResult,
+}
+
+pub enum CatalogResult {
+    Stations(Result<Vec<Station>>),
+    Facets(Facet, Result<Vec<FacetValue>>),
+    Click(Result<()>),
+    Vote(Result<String>),
+}
+
+enum CatalogJob {
+    Lookup(Vec<String>),
+    Facets(Facet),
+    Click(String),
+    Vote(String),
+}
+
+struct CatalogRequest {
+    id: u64,
+    job: CatalogJob,
+}
+
+/// Runs catalog queries on a background thread so the UI never blocks on the network.
+///
+/// Searches run on a thread of their own each, so a new search never queues behind an old one
+/// that is still waiting for a slow server. Lookups, facet lists, clicks and votes are never
+/// superseded and run one after another on the worker thread.
+pub struct CatalogWorker {
+    catalog: Arc<dyn StationCatalog>,
+    requests: Sender<CatalogRequest>,
+    responses: Receiver<CatalogResponse>,
+    response_tx: Sender<CatalogResponse>,
+    /// Id of the only search whose result is still wanted; 0 after `cancel_search`.
+    current_search: Arc<AtomicU64>,
+}
+
+impl CatalogWorker {
+    pub fn spawn(catalog: Arc<dyn StationCatalog>) -> Result<Self> {
+        let (request_tx, request_rx) = mpsc::channel::<CatalogRequest>();
+        let (response_tx, response_rx) = mpsc::channel();
+
+        let worker_catalog = catalog.clone();
+        let worker_responses = response_tx.clone();
+        thread::Builder::new()
+            .name("catalog-worker".to_string())
+            .spawn(move || {
+                let catalog = worker_catalog;
+                while let Ok(request) = request_rx.recv() {
+                    let result = match &request.job {
+                        CatalogJob::Lookup(station_uuids) => {
+                            CatalogResult::Stations(catalog.lookup(station_uuids))
+                        }
+                        CatalogJob::Facets(facet) => {
+                            CatalogResult::Facets(*facet, catalog.facet_values(*facet))
+                        }
+                        CatalogJob::Click(station_uuid) => {
+                            CatalogResult::Click(catalog.register_click(station_uuid))
+                        }
+                        CatalogJob::Vote(station_uuid) => {
+                            CatalogResult::Vote(catalog.vote(station_uuid))
+                        }
+                    };
+                    let response = CatalogResponse {
+                        id: request.id,
+                        result,
+                    };
+                    if worker_responses.send(response).is_err() {
+                        return;
+                    }
+                }
+            })
+            .context("failed to start station catalog worker")?;
+
+        Ok(Self {
+            catalog,
+            requests: request_tx,
+            responses: response_rx,
+            response_tx,
+            current_search: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Starts a search that supersedes any earlier one. A superseded search that has not reached
+    /// the network yet is skipped; one already waiting on a server is left to finish on its own
+    /// thread and its result is dropped.
+    pub fn submit_search(&self, id: u64, query: StationSearchQuery) -> Result<()> {
+        self.current_search.store(id, Ordering::SeqCst);
+        let catalog = self.catalog.clone();
+        let current_search = self.current_search.clone();
+        let responses = self.response_tx.clone();
+        thread::Builder::new()
+            .name("catalog-search".to_string())
+            .spawn(move || {
+                if current_search.load(Ordering::SeqCst) != id {
+                    debug!(id, "skipping superseded station search");
+                    return;
+                }
+                let result = catalog.search(&query);
+                if current_search.load(Ordering::SeqCst) != id {
+                    debug!(id, "dropping superseded station search result");
+                    return;
+                }
+                let _ = responses.send(CatalogResponse {
+                    id,
+                    result: CatalogResult::Stations(result),
+                });
+            })
+            .context("failed to start station search")?;
+        Ok(())
+    }
+
+    /// Drops the result of the running search, if any.
+    pub fn cancel_search(&self) {
+        self.current_search.store(0, Ordering::SeqCst);
+    }
+
+    pub fn submit_lookup(&self, id: u64, station_uuids: Vec<String>) -> Result<()> {
+        self.submit(CatalogRequest {
+            id,
+            job: CatalogJob::Lookup(station_uuids),
+        })
+    }
+
+    pub fn submit_facets(&self, id: u64, facet: Facet) -> Result<()> {
+        self.submit(CatalogRequest {
+            id,
+            job: CatalogJob::Facets(facet),
+        })
+    }
+
+    pub fn submit_click(&self, id: u64, station_uuid: String) -> Result<()> {
+        self.submit(CatalogRequest {
+            id,
+            job: CatalogJob::Click(station_uuid),
+        })
+    }
+
+    pub fn submit_vote(&self, id: u64, station_uuid: String) -> Result<()> {
+        self.submit(CatalogRequest {
+            id,
+            job: CatalogJob::Vote(station_uuid),
+        })
+    }
+
+    fn submit(&self, request: CatalogRequest) -> Result<()> {
+        self.requests
+            .send(request)
+            .context("station catalog worker stopped; restart iradio")
+    }
+
+    pub fn try_recv(&self) -> Option<CatalogResponse> {
+        self.responses.try_recv().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    struct GatedCatalog {
+        gate: Mutex<Receiver<()>>,
+        seen: Mutex<Vec<String>>,
+    }
+
+    impl StationCatalog for GatedCatalog {
+        fn search(&self, query: &StationSearchQuery) -> Result<Vec<Station>> {
+            self.seen
+                .lock()
+                .expect("lock seen")
+                .push(query.query.clone());
+            let _ = self.gate.lock().expect("lock gate").recv();
+            Ok(Vec::new())
+        }
+
+        fn lookup(&self, station_uuids: &[String]) -> Result<Vec<Station>> {
+            self.seen
+                .lock()
+                .expect("lock seen")
+                .push(format!("lookup:{}", station_uuids.join(",")));
+            Ok(Vec::new())
+        }
+    }
+
+    fn wait_for_response(worker: &CatalogWorker) -> CatalogResponse {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            if let Some(response) = worker.try_recv() {
+                return response;
+            }
+            assert!(Instant::now() < deadline, "worker did not respond");
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn a_slow_search_does_not_hold_up_the_next_one() {
+        let (gate_tx, gate_rx) = mpsc::channel();
+        let catalog = Arc::new(GatedCatalog {
+            gate: Mutex::new(gate_rx),
+            seen: Mutex::new(Vec::new()),
+        });
+        let worker = CatalogWorker::spawn(catalog.clone()).expect("spawn worker");
+
+        let query = |text: &str| StationSearchQuery {
+            query: text.to_string(),
+            ..StationSearchQuery::default()
+        };
+        worker.submit_search(1, query("slow")).expect("submit slow");
+        while catalog.seen.lock().expect("lock seen").is_empty() {
+            thread::sleep(Duration::from_millis(5));
+        }
+        worker
+            .submit_lookup(2, vec!["fav-1".to_string()])
+            .expect("submit lookup");
+        assert_eq!(wait_for_response(&worker).id, 2);
+
+        // The slow search still holds the gate, so the next one has to run beside it.
+        worker.submit_search(3, query("fast")).expect("submit fast");
+        gate_tx.send(()).expect("release a search");
+        gate_tx.send(()).expect("release a search");
+        assert_eq!(wait_for_response(&worker).id, 3);
+
+        worker.submit_search(4, query("cancelled")).expect("submit");
+        worker.cancel_search();
+        gate_tx.send(()).expect("release a search");
+        thread::sleep(Duration::from_millis(50));
+        assert!(worker.try_recv().is_none());
+    }
+}
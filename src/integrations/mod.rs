@@ -1,5 +1,9 @@
+pub mod catalog_worker;
+pub mod mpv_ipc;
 pub mod playback;
+pub mod playback_factory;
 pub mod station_catalog;
 pub mod vlc_http;
 pub mod vlc_process;
 pub mod vlc_rc;
+pub mod vlc_rc_status;
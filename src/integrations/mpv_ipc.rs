@@ -0,0 +1,774 @@
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::{json, Value};
+
+use super::playback::{PlaybackController, PlaybackEvent, PlaybackState};
+
+const CONNECT_WAIT: Duration = Duration::from_secs(3);
+const CONNECT_POLL: Duration = Duration::from_millis(50);
+const REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+const SHUTDOWN_WAIT: Duration = Duration::from_millis(500);
+const SHUTDOWN_POLL: Duration = Duration::from_millis(50);
+const ICY_TITLE_OBSERVER: u64 = 1;
+const ICY_TITLE_PROPERTY: &str = "metadata/by-key/icy-title";
+const CORE_IDLE_OBSERVER: u64 = 2;
+const CORE_IDLE_PROPERTY: &str = "core-idle";
+
+struct MpvConnection {
+    stream: UnixStream,
+    messages: Receiver<Value>,
+}
+
+pub struct MpvIpcController {
+    program: Option<String>,
+    socket_path: PathBuf,
+    /// Reuse an mpv already listening on `socket_path` before starting one.
+    shared: bool,
+    child: Option<Child>,
+    connection: Option<MpvConnection>,
+    next_request_id: u64,
+    pending_events: Vec<Value>,
+    stream_title: Option<String>,
+    state: PlaybackState,
+}
+
+impl Default for MpvIpcController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MpvIpcController {
+    pub fn new() -> Self {
+        let socket_path =
+            std::env::temp_dir().join(format!("iradio-mpv-{}.sock", std::process::id()));
+        Self::new_with_program("mpv", socket_path)
+    }
+
+    pub fn new_with_program(program: impl Into<String>, socket_path: impl AsRef<Path>) -> Self {
+        Self {
+            program: Some(program.into()),
+            socket_path: socket_path.as_ref().to_path_buf(),
+            shared: false,
+            child: None,
+            connection: None,
+            next_request_id: 1,
+            pending_events: Vec::new(),
+            stream_title: None,
+            state: PlaybackState::Stopped,
+        }
+    }
+
+    /// Uses the mpv listening on a fixed `socket_path`, starting one there when none is, so
+    /// separate `iradio` processes drive the same player.
+    pub fn shared(socket_path: impl AsRef<Path>) -> Self {
+        let mut controller = Self::new_with_program("mpv", socket_path);
+        controller.shared = true;
+        controller
+    }
+
+    /// Drives an mpv instance that is already listening on `socket_path`.
+    pub fn attach(socket_path: impl AsRef<Path>) -> Self {
+        Self {
+            program: None,
+            socket_path: socket_path.as_ref().to_path_buf(),
+            shared: false,
+            child: None,
+            connection: None,
+            next_request_id: 1,
+            pending_events: Vec::new(),
+            stream_title: None,
+            state: PlaybackState::Stopped,
+        }
+    }
+
+    fn connect_if_needed(&mut self) -> Result<()> {
+        if self.connection.is_some() && (self.child.is_none() || self.child_is_running()?) {
+            return Ok(());
+        }
+
+        self.connection = None;
+        if self.shared && !self.child_is_running()? {
+            if let Ok(stream) = UnixStream::connect(&self.socket_path) {
+                return self.open_connection(stream);
+            }
+        }
+        if let Some(program) = self.program.clone() {
+            self.spawn(&program)?;
+        }
+
+        let deadline = Instant::now() + CONNECT_WAIT;
+        let stream = loop {
+            match UnixStream::connect(&self.socket_path) {
+                Ok(stream) => break stream,
+                Err(err) if Instant::now() >= deadline => {
+                    return Err(anyhow!(
+                        "failed to connect mpv IPC socket {}: {err}",
+                        self.socket_path.display()
+                    ));
+                }
+                Err(_) => thread::sleep(CONNECT_POLL),
+            }
+        };
+        self.open_connection(stream)
+    }
+
+    fn open_connection(&mut self, stream: UnixStream) -> Result<()> {
+        let reader = stream
+            .try_clone()
+            .context("failed cloning mpv IPC socket for reading")?;
+
+        // Replies and asynchronous events share the socket, so a reader thread
+        // forwards every message and `send_command` picks out its reply.
+        let (tx, rx) = mpsc::channel();
+        thread::Builder::new()
+            .name("mpv-ipc-reader".to_string())
+            .spawn(move || {
+                for line in BufReader::new(reader).lines() {
+                    let Ok(line) = line else {
+                        break;
+                    };
+                    let Ok(message) = serde_json::from_str::<Value>(&line) else {
+                        continue;
+                    };
+                    if tx.send(message).is_err() {
+                        break;
+                    }
+                }
+            })
+            .context("failed to start mpv IPC reader")?;
+
+        self.connection = Some(MpvConnection {
+            stream,
+            messages: rx,
+        });
+        self.send_command(json!([
+            "observe_property",
+            ICY_TITLE_OBSERVER,
+            ICY_TITLE_PROPERTY
+        ]))?;
+        self.send_command(json!([
+            "observe_property",
+            CORE_IDLE_OBSERVER,
+            CORE_IDLE_PROPERTY
+        ]))?;
+        Ok(())
+    }
+
+    fn spawn(&mut self, program: &str) -> Result<()> {
+        if self.child_is_running()? {
+            return Ok(());
+        }
+
+        let _ = std::fs::remove_file(&self.socket_path);
+        let ipc_arg = format!("--input-ipc-server={}", self.socket_path.display());
+        let child = Command::new(program)
+            .args(["--idle=yes", "--no-video", "--no-terminal", &ipc_arg])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|err| {
+                if err.kind() == std::io::ErrorKind::NotFound {
+                    anyhow!(
+                        "failed to start mpv: '{program}' not found on PATH; install mpv (e.g. apt install mpv)"
+                    )
+                } else {
+                    anyhow!("failed to start mpv process '{program} --idle {ipc_arg}': {err}")
+                }
+            })?;
+        self.child = Some(child);
+        Ok(())
+    }
+
+    fn child_is_running(&mut self) -> Result<bool> {
+        if let Some(child) = self.child.as_mut() {
+            if child
+                .try_wait()
+                .context("failed checking mpv process status")?
+                .is_none()
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn send_command(&mut self, command: Value) -> Result<Value> {
+        let connection = self.connection.as_mut().ok_or_else(|| {
+            anyhow!("mpv IPC connection unavailable; restart playback with /play")
+        })?;
+
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+        let payload = json!({ "command": command, "request_id": request_id });
+
+        connection
+            .stream
+            .write_all(format!("{payload}\n").as_bytes())
+            .with_context(|| {
+                format!(
+                    "failed writing command to mpv ({command}); mpv may have exited unexpectedly"
+                )
+            })?;
+        connection
+            .stream
+            .flush()
+            .context("failed flushing mpv IPC socket; mpv may have exited unexpectedly")?;
+
+        let deadline = Instant::now() + REPLY_TIMEOUT;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let message = match connection.messages.recv_timeout(remaining) {
+                Ok(message) => message,
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(anyhow!("no reply from mpv for command {command}"));
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    self.connection = None;
+                    return Err(anyhow!("mpv closed the IPC connection"));
+                }
+            };
+
+            if message.get("event").is_some() {
+                self.pending_events.push(message);
+                continue;
+            }
+            if message.get("request_id").and_then(Value::as_u64) != Some(request_id) {
+                continue;
+            }
+
+            return match message.get("error").and_then(Value::as_str) {
+                Some("success") => Ok(message.get("data").cloned().unwrap_or(Value::Null)),
+                Some(error) => Err(anyhow!("mpv rejected command {command}: {error}")),
+                None => Err(anyhow!("malformed mpv reply: {message}")),
+            };
+        }
+    }
+
+    fn drain_messages(&mut self) {
+        let Some(connection) = self.connection.as_ref() else {
+            return;
+        };
+        loop {
+            match connection.messages.try_recv() {
+                Ok(message) if message.get("event").is_some() => self.pending_events.push(message),
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.connection = None;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Moves `Loading` to `Playing` once audio flows, and to `Error` when the file ends
+    /// before or while playing. Returns the events to report.
+    fn apply_state_event(&mut self, event: &Value) -> Vec<PlaybackEvent> {
+        let name = event.get("event").and_then(Value::as_str);
+        let started = match name {
+            Some("playback-restart") => true,
+            Some("property-change") => {
+                event.get("name").and_then(Value::as_str) == Some(CORE_IDLE_PROPERTY)
+                    && event.get("data").and_then(Value::as_bool) == Some(false)
+            }
+            _ => false,
+        };
+        if started && self.state == PlaybackState::Loading {
+            self.state = PlaybackState::Playing;
+            return vec![PlaybackEvent::StateChanged(PlaybackState::Playing)];
+        }
+
+        if name != Some("end-file")
+            || !matches!(self.state, PlaybackState::Loading | PlaybackState::Playing)
+        {
+            return Vec::new();
+        }
+        let message = match event.get("reason").and_then(Value::as_str) {
+            Some("error") if self.state == PlaybackState::Loading => {
+                "stream failed to start; the station may be offline"
+            }
+            Some("error") | Some("eof") => {
+                "stream stopped unexpectedly; the station may have gone offline"
+            }
+            _ => return Vec::new(),
+        };
+        self.state = PlaybackState::Error;
+        vec![
+            PlaybackEvent::StateChanged(PlaybackState::Error),
+            PlaybackEvent::Error(message.to_string()),
+        ]
+    }
+
+    fn icy_title_from_event(event: &Value) -> Option<Option<String>> {
+        if event.get("event").and_then(Value::as_str) != Some("property-change")
+            || event.get("name").and_then(Value::as_str) != Some(ICY_TITLE_PROPERTY)
+        {
+            return None;
+        }
+        Some(
+            event
+                .get("data")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|title| !title.is_empty())
+                .map(ToString::to_string),
+        )
+    }
+}
+
+impl PlaybackController for MpvIpcController {
+    fn play(&mut self, stream_url: &str) -> Result<()> {
+        self.connect_if_needed()?;
+        self.send_command(json!(["loadfile", stream_url, "replace"]))?;
+        // State events that arrived before the reply belong to the previous file.
+        self.pending_events
+            .retain(|event| Self::icy_title_from_event(event).is_some());
+        self.stream_title = None;
+        self.state = PlaybackState::Loading;
+        Ok(())
+    }
+
+    fn set_volume(&mut self, value: u8) -> Result<()> {
+        self.connect_if_needed()?;
+        self.send_command(json!(["set_property", "volume", value]))?;
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        if self.state == PlaybackState::Stopped {
+            return Err(anyhow!(
+                "cannot stop because playback is already stopped; start a stream first with /play"
+            ));
+        }
+        self.send_command(json!(["stop"]))?;
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        if self.state != PlaybackState::Playing {
+            return Err(anyhow!(
+                "cannot pause because no stream is currently playing; start playback first"
+            ));
+        }
+        self.send_command(json!(["cycle", "pause"]))?;
+        self.state = PlaybackState::Paused;
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        if self.state != PlaybackState::Paused {
+            return Err(anyhow!(
+                "cannot resume because playback is not paused; pause first or use /play"
+            ));
+        }
+        self.send_command(json!(["cycle", "pause"]))?;
+        self.state = PlaybackState::Playing;
+        Ok(())
+    }
+
+    fn shutdown(&mut self) -> Result<()> {
+        if self.child.is_none() {
+            self.connection = None;
+            self.state = PlaybackState::Stopped;
+            return Ok(());
+        }
+
+        let _ = self.send_command(json!(["quit"]));
+        let deadline = Instant::now() + SHUTDOWN_WAIT;
+        if let Some(child) = self.child.as_mut() {
+            loop {
+                if child
+                    .try_wait()
+                    .context("failed waiting for mpv process exit")?
+                    .is_some()
+                {
+                    break;
+                }
+                if Instant::now() >= deadline {
+                    child.kill().context("failed to force-kill mpv process")?;
+                    let _ = child.wait();
+                    break;
+                }
+                thread::sleep(SHUTDOWN_POLL);
+            }
+        }
+
+        let _ = std::fs::remove_file(&self.socket_path);
+        self.connection = None;
+        self.child = None;
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn state(&self) -> PlaybackState {
+        self.state
+    }
+
+    /// Stops whatever the mpv on the socket is playing, without starting one to stop.
+    fn force_stop(&mut self) -> Result<()> {
+        if self.connection.is_none() {
+            let stream = UnixStream::connect(&self.socket_path).with_context(|| {
+                format!(
+                    "nothing to stop: no mpv is listening on {}",
+                    self.socket_path.display()
+                )
+            })?;
+            self.open_connection(stream)?;
+        }
+        self.send_command(json!(["stop"]))?;
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn keep_running(&mut self) {
+        // Dropping the `Child` handle does not kill mpv; `shutdown` then has nothing to quit.
+        self.child = None;
+        self.connection = None;
+    }
+
+    fn poll_events(&mut self) -> Vec<PlaybackEvent> {
+        self.drain_messages();
+
+        let mut events = Vec::new();
+        for event in std::mem::take(&mut self.pending_events) {
+            let Some(title) = Self::icy_title_from_event(&event) else {
+                events.extend(self.apply_state_event(&event));
+                continue;
+            };
+            if title == self.stream_title || self.state == PlaybackState::Stopped {
+                continue;
+            }
+            self.stream_title = title.clone();
+            if let Some(title) = title {
+                events.push(PlaybackEvent::StreamTitle(title));
+            }
+        }
+        events
+    }
+}
+
+impl Drop for MpvIpcController {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixListener;
+    use std::thread::{self, JoinHandle};
+
+    use super::*;
+
+    const SUCCESS: &str = "{\"data\":null,\"request_id\":$ID,\"error\":\"success\"}\n";
+    const RESTART_THEN_SUCCESS: &str = "{\"event\":\"playback-restart\"}\n{\"data\":null,\"request_id\":$ID,\"error\":\"success\"}\n";
+
+    fn fake_mpv(socket_path: &Path, replies: &[&str]) -> Option<JoinHandle<Vec<Value>>> {
+        let listener = match UnixListener::bind(socket_path) {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => return None,
+            Err(err) => panic!("bind listener: {err}"),
+        };
+
+        let replies: Vec<String> = replies.iter().map(ToString::to_string).collect();
+        Some(thread::spawn(move || {
+            let (socket, _) = listener.accept().expect("accept socket");
+            let mut writer = socket.try_clone().expect("clone socket");
+            let mut reader = BufReader::new(socket);
+            let mut received = Vec::new();
+            for reply in replies {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("read command");
+                let command: Value = serde_json::from_str(&line).expect("command is json");
+                let request_id = command["request_id"].clone();
+                received.push(command);
+                let reply = reply.replace("$ID", &request_id.to_string());
+                writer.write_all(reply.as_bytes()).expect("write reply");
+            }
+            received
+        }))
+    }
+
+    #[test]
+    fn play_sends_loadfile_and_waits_for_reply() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let socket_path = dir.path().join("mpv.sock");
+        let Some(handle) = fake_mpv(
+            &socket_path,
+            &[
+                SUCCESS,
+                SUCCESS,
+                "{\"event\":\"start-file\"}\n{\"data\":null,\"request_id\":$ID,\"error\":\"success\"}\n",
+                SUCCESS,
+            ],
+        ) else {
+            return;
+        };
+
+        let mut controller = MpvIpcController::attach(&socket_path);
+        controller
+            .play("http://example.com/radio.mp3")
+            .expect("send loadfile");
+        assert_eq!(controller.state(), PlaybackState::Loading);
+        controller.set_volume(40).expect("send volume");
+
+        let received = handle.join().expect("join thread");
+        assert_eq!(
+            received[0]["command"],
+            json!(["observe_property", 1, "metadata/by-key/icy-title"])
+        );
+        assert_eq!(
+            received[1]["command"],
+            json!(["observe_property", 2, "core-idle"])
+        );
+        assert_eq!(
+            received[2]["command"],
+            json!(["loadfile", "http://example.com/radio.mp3", "replace"])
+        );
+        assert_eq!(
+            received[3]["command"],
+            json!(["set_property", "volume", 40])
+        );
+    }
+
+    #[test]
+    fn loading_becomes_playing_once_audio_starts() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let socket_path = dir.path().join("mpv.sock");
+        let idle_false = "{\"event\":\"property-change\",\"id\":2,\"name\":\"core-idle\",\"data\":false}\n{\"data\":null,\"request_id\":$ID,\"error\":\"success\"}\n";
+        let Some(handle) = fake_mpv(
+            &socket_path,
+            &[SUCCESS, SUCCESS, idle_false, SUCCESS, idle_false],
+        ) else {
+            return;
+        };
+
+        let mut controller = MpvIpcController::attach(&socket_path);
+        // `core-idle` changes before the loadfile reply belong to the previous stream.
+        controller
+            .play("http://example.com/radio.mp3")
+            .expect("send loadfile");
+        assert!(controller.poll_events().is_empty());
+        assert_eq!(controller.state(), PlaybackState::Loading);
+
+        controller.set_volume(40).expect("send volume");
+        assert!(controller.poll_events().is_empty());
+        assert_eq!(controller.state(), PlaybackState::Loading);
+
+        controller.set_volume(50).expect("send volume");
+        assert_eq!(
+            controller.poll_events(),
+            vec![PlaybackEvent::StateChanged(PlaybackState::Playing)]
+        );
+        assert_eq!(controller.state(), PlaybackState::Playing);
+        handle.join().expect("join thread");
+    }
+
+    #[test]
+    fn dead_stream_reports_an_error_instead_of_playing() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let socket_path = dir.path().join("mpv.sock");
+        let end_file = "{\"event\":\"end-file\",\"reason\":\"error\",\"file_error\":\"loading failed\"}\n{\"data\":null,\"request_id\":$ID,\"error\":\"success\"}\n";
+        let Some(handle) = fake_mpv(&socket_path, &[SUCCESS, SUCCESS, SUCCESS, end_file]) else {
+            return;
+        };
+
+        let mut controller = MpvIpcController::attach(&socket_path);
+        controller
+            .play("http://example.com/dead.mp3")
+            .expect("send loadfile");
+        controller.set_volume(40).expect("send volume");
+        assert_eq!(
+            controller.poll_events(),
+            vec![
+                PlaybackEvent::StateChanged(PlaybackState::Error),
+                PlaybackEvent::Error(
+                    "stream failed to start; the station may be offline".to_string()
+                ),
+            ]
+        );
+        assert_eq!(controller.state(), PlaybackState::Error);
+        handle.join().expect("join thread");
+    }
+
+    #[test]
+    fn pause_and_resume_cycle_pause_then_stop() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let socket_path = dir.path().join("mpv.sock");
+        let Some(handle) = fake_mpv(
+            &socket_path,
+            &[
+                SUCCESS,
+                SUCCESS,
+                SUCCESS,
+                RESTART_THEN_SUCCESS,
+                SUCCESS,
+                SUCCESS,
+                SUCCESS,
+            ],
+        ) else {
+            return;
+        };
+
+        let mut controller = MpvIpcController::attach(&socket_path);
+        controller
+            .play("http://example.com/radio.mp3")
+            .expect("play");
+        let err = controller.pause().expect_err("nothing is playing yet");
+        assert!(err.to_string().contains("cannot pause"));
+        controller.set_volume(40).expect("send volume");
+        controller.poll_events();
+        assert_eq!(controller.state(), PlaybackState::Playing);
+        controller.pause().expect("pause");
+        assert_eq!(controller.state(), PlaybackState::Paused);
+        controller.resume().expect("resume");
+        assert_eq!(controller.state(), PlaybackState::Playing);
+        controller.stop().expect("stop");
+        assert_eq!(controller.state(), PlaybackState::Stopped);
+
+        let commands: Vec<Value> = handle
+            .join()
+            .expect("join thread")
+            .into_iter()
+            .map(|message| message["command"].clone())
+            .collect();
+        assert_eq!(
+            commands[4..],
+            [
+                json!(["cycle", "pause"]),
+                json!(["cycle", "pause"]),
+                json!(["stop"])
+            ]
+        );
+    }
+
+    #[test]
+    fn error_reply_is_reported_and_state_is_unchanged() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let socket_path = dir.path().join("mpv.sock");
+        let Some(handle) = fake_mpv(
+            &socket_path,
+            &[
+                SUCCESS,
+                SUCCESS,
+                "{\"request_id\":$ID,\"error\":\"loading failed\"}\n",
+            ],
+        ) else {
+            return;
+        };
+
+        let mut controller = MpvIpcController::attach(&socket_path);
+        let err = controller
+            .play("http://example.com/broken.mp3")
+            .expect_err("error reply should fail play");
+        assert!(err.to_string().contains("loading failed"));
+        assert_eq!(controller.state(), PlaybackState::Stopped);
+        handle.join().expect("join thread");
+    }
+
+    #[test]
+    fn icy_title_property_changes_become_stream_title_events() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let socket_path = dir.path().join("mpv.sock");
+        let title_event = "{\"event\":\"property-change\",\"id\":1,\"name\":\"metadata/by-key/icy-title\",\"data\":\"Artist - Song\"}\n";
+        let reply_with_title = format!("{title_event}{title_event}{SUCCESS}");
+        let Some(handle) = fake_mpv(&socket_path, &[SUCCESS, SUCCESS, &reply_with_title]) else {
+            return;
+        };
+
+        let mut controller = MpvIpcController::attach(&socket_path);
+        controller
+            .play("http://example.com/radio.mp3")
+            .expect("send loadfile");
+        handle.join().expect("join thread");
+
+        assert_eq!(
+            controller.poll_events(),
+            vec![PlaybackEvent::StreamTitle("Artist - Song".to_string())]
+        );
+    }
+
+    #[test]
+    fn invalid_transitions_are_rejected_before_ipc() {
+        let mut controller = MpvIpcController::attach("/nonexistent/mpv.sock");
+
+        let err = controller
+            .pause()
+            .expect_err("pause from stopped should fail");
+        assert!(err.to_string().contains("cannot pause"));
+
+        let err = controller
+            .resume()
+            .expect_err("resume from stopped should fail");
+        assert!(err.to_string().contains("cannot resume"));
+
+        let err = controller
+            .stop()
+            .expect_err("stop from stopped should fail");
+        assert!(err.to_string().contains("already stopped"));
+        assert_eq!(controller.state(), PlaybackState::Stopped);
+    }
+
+    #[test]
+    fn shared_controller_reuses_a_running_mpv_and_force_stop_reaches_it() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let socket_path = dir.path().join("mpv.sock");
+        let Some(handle) = fake_mpv(&socket_path, &[SUCCESS; 3]) else {
+            return;
+        };
+
+        // `definitely-not-mpv` would fail to start, so this only passes by reusing the socket.
+        let mut controller = MpvIpcController::shared(&socket_path);
+        controller.program = Some("definitely-not-mpv".to_string());
+        controller
+            .play("http://example.com/radio.mp3")
+            .expect("play on the running mpv");
+        controller.keep_running();
+        drop(controller);
+        assert_eq!(
+            handle.join().expect("join thread")[2]["command"],
+            json!(["loadfile", "http://example.com/radio.mp3", "replace"])
+        );
+
+        let _ = std::fs::remove_file(&socket_path);
+        let Some(handle) = fake_mpv(&socket_path, &[SUCCESS; 3]) else {
+            return;
+        };
+        let mut controller = MpvIpcController::attach(&socket_path);
+        controller.force_stop().expect("stop from another process");
+        assert_eq!(
+            handle.join().expect("join thread")[2]["command"],
+            json!(["stop"])
+        );
+    }
+
+    #[test]
+    fn force_stop_without_a_running_mpv_says_so() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let mut controller = MpvIpcController::shared(dir.path().join("mpv.sock"));
+        let err = controller.force_stop().expect_err("nothing is listening");
+        assert!(err.to_string().contains("no mpv is listening"), "{err}");
+    }
+
+    #[test]
+    fn missing_mpv_binary_returns_actionable_error() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let mut controller =
+            MpvIpcController::new_with_program("definitely-not-mpv", dir.path().join("mpv.sock"));
+        let err = controller
+            .play("https://example.com/radio.mp3")
+            .expect_err("play should fail when mpv binary is missing");
+        assert!(err.to_string().contains("not found on PATH"));
+    }
+}
@@ -3,8 +3,31 @@ use anyhow::Result;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlaybackState {
     Stopped,
+    Loading,
     Playing,
+    Buffering,
     Paused,
+    Error,
+}
+
+impl PlaybackState {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Stopped => "Stopped",
+            Self::Loading => "Loading",
+            Self::Playing => "Playing",
+            Self::Buffering => "Buffering",
+            Self::Paused => "Paused",
+            Self::Error => "Error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlaybackEvent {
+    StateChanged(PlaybackState),
+    StreamTitle(String),
+    Error(String),
 }
 
 pub trait PlaybackController: Send {
@@ -15,6 +38,21 @@ pub trait PlaybackController: Send {
     fn resume(&mut self) -> Result<()>;
     fn shutdown(&mut self) -> Result<()>;
     fn state(&self) -> PlaybackState;
+
+    /// Stops the player even when this controller did not start the stream, as
+    /// `iradio stop` does for a VLC instance driven by another process.
+    fn force_stop(&mut self) -> Result<()> {
+        self.stop()
+    }
+
+    /// Lets a player this controller started keep playing after the controller is dropped,
+    /// as `iradio play` does when `iradio stop` can reach the player later.
+    fn keep_running(&mut self) {}
+
+    // Called on every UI tick; backends that observe the player report changes here.
+    fn poll_events(&mut self) -> Vec<PlaybackEvent> {
+        Vec::new()
+    }
 }
 
 pub fn volume_percent_to_vlc_scale(value: u8) -> u16 {
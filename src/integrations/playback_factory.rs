@@ -0,0 +1,101 @@
+use anyhow::{anyhow, Result};
+use tracing::debug;
+
+use super::mpv_ipc::MpvIpcController;
+use super::playback::PlaybackController;
+use super::vlc_http::VlcHttpController;
+use super::vlc_process::VlcProcessController;
+use super::vlc_rc::VlcRcController;
+use crate::storage::config::{PlaybackConfig, PlaybackMode};
+
+pub fn build_playback_controller(config: &PlaybackConfig) -> Result<Box<dyn PlaybackController>> {
+    match config.mode {
+        PlaybackMode::Rc if config.attaches_to_external_vlc() => {
+            debug!(
+                host = config.host(),
+                port = config.port(),
+                "attaching to VLC RC interface"
+            );
+            Ok(Box::new(VlcRcController::new(config.host(), config.port())))
+        }
+        PlaybackMode::Rc => {
+            debug!("using managed cvlc subprocess");
+            Ok(Box::new(VlcProcessController::new()))
+        }
+        PlaybackMode::Http => {
+            let password = config.password.clone().ok_or_else(|| {
+                anyhow!(
+                    "playback mode 'http' requires a VLC web interface password; set [playback] password or IRADIO_VLC_HTTP_PASSWORD"
+                )
+            })?;
+            let base_url = format!("http://{}:{}", config.host(), config.port());
+            debug!(base_url = %base_url, "attaching to VLC HTTP interface");
+            Ok(Box::new(VlcHttpController::new(base_url, password)))
+        }
+        PlaybackMode::Mpv => match config.mpv_socket() {
+            Some(socket) => {
+                debug!(socket = %socket.display(), "using shared mpv IPC socket");
+                Ok(Box::new(MpvIpcController::shared(socket)))
+            }
+            None => {
+                debug!("using managed mpv subprocess");
+                Ok(Box::new(MpvIpcController::new()))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    fn playback_config(mode: PlaybackMode) -> PlaybackConfig {
+        PlaybackConfig {
+            mode,
+            host: None,
+            port: None,
+            password: None,
+            socket: None,
+        }
+    }
+
+    #[test]
+    fn http_mode_without_password_is_rejected() {
+        let err = build_playback_controller(&playback_config(PlaybackMode::Http))
+            .err()
+            .expect("http mode without password should fail");
+        assert!(err.to_string().contains("IRADIO_VLC_HTTP_PASSWORD"));
+    }
+
+    #[test]
+    fn rc_mode_with_host_attaches_to_external_vlc() {
+        let listener = match TcpListener::bind(("127.0.0.1", 0)) {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => return,
+            Err(err) => panic!("bind listener: {err}"),
+        };
+        let port = listener.local_addr().expect("read local addr").port();
+
+        let handle = thread::spawn(move || {
+            let (mut socket, _) = listener.accept().expect("accept socket");
+            let mut buf = [0_u8; 128];
+            let n = socket.read(&mut buf).expect("read command");
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+
+        let mut config = playback_config(PlaybackMode::Rc);
+        config.host = Some("127.0.0.1".to_string());
+        config.port = Some(port);
+        let mut controller = build_playback_controller(&config).expect("build controller");
+        controller
+            .play("http://example.com/radio.mp3")
+            .expect("send play command");
+
+        let payload = handle.join().expect("join thread");
+        assert_eq!(payload, "add http://example.com/radio.mp3\n");
+    }
+}
@@ -1,22 +1,82 @@
+use std::hash::{BuildHasher, RandomState};
+use std::sync::{Mutex, MutexGuard, PoisonError};
 use std::thread;
 use std::time::Duration;
 
 use anyhow::{anyhow, Context, Result};
 use reqwest::blocking::Client;
+use serde::de::DeserializeOwned;
+use tracing::{debug, warn};
 
-use crate::domain::models::{Station, StationSearchQuery};
+use crate::domain::models::{
+    filter_terms, Facet, FacetValue, Station, StationFilters, StationSearchQuery, StationSort,
+};
 
-pub trait StationCatalog: Send {
+pub trait StationCatalog: Send + Sync {
     fn search(&self, query: &StationSearchQuery) -> Result<Vec<Station>>;
+
+    /// Resolve stations by UUID. Unknown UUIDs are omitted from the result.
+    fn lookup(&self, _station_uuids: &[String]) -> Result<Vec<Station>> {
+        Ok(Vec::new())
+    }
+
+    /// Known values of `facet`, most stations first.
+    fn facet_values(&self, _facet: Facet) -> Result<Vec<FacetValue>> {
+        Ok(Vec::new())
+    }
+
+    /// Tell the catalog a station was played, so its click count reflects real listening.
+    fn register_click(&self, _station_uuid: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Vote for a station; returns the server's confirmation message.
+    fn vote(&self, _station_uuid: &str) -> Result<String> {
+        Err(anyhow!("this station catalog does not take votes"))
+    }
 }
 
+const LOOKUP_BATCH_SIZE: usize = 100;
+/// Server pages scanned per search when some filters are applied locally.
+const FILTERED_PAGE_SIZE: usize = 100;
+const MAX_FILTERED_PAGES: usize = 10;
+/// Radio Browser lists tens of thousands of tags; the most used ones are enough to complete.
+const FACET_LIMIT: usize = 1000;
+
 pub struct RadioBrowserCatalog {
     client: Client,
-    base_url: String,
+    mirrors: Mutex<Mirrors>,
+    /// `/json/servers` listing asked for more mirrors once every known one has failed.
+    servers_url: Option<String>,
     timeout: Duration,
     max_retries: usize,
 }
 
+/// Radio Browser servers in the order they were configured, then discovered.
+struct Mirrors {
+    urls: Vec<String>,
+    /// The mirror that answered last; requests start there.
+    current: usize,
+    discovered: bool,
+}
+
+impl Mirrors {
+    /// The `tried`-th mirror to ask: the current one first, then the rest in list order, so
+    /// mirrors appended by discovery are still reached exactly once.
+    fn nth(&self, tried: usize) -> Option<usize> {
+        let idx = match tried {
+            0 => self.current,
+            n if n - 1 < self.current => n - 1,
+            n => n,
+        };
+        (idx < self.urls.len()).then_some(idx)
+    }
+}
+
+/// How one mirror handled a request: `Err` means try the next mirror, `Ok` is final, even
+/// when it holds an error such as a 4xx status or an unparsable body.
+type MirrorResult<T> = Result<Result<T>>;
+
 impl RadioBrowserCatalog {
     pub fn new(base_url: impl Into<String>) -> Result<Self> {
         Self::new_with_config(base_url, Duration::from_secs(3), 2)
@@ -33,20 +93,66 @@ impl RadioBrowserCatalog {
             .context("failed to build radio browser client")?;
         Ok(Self {
             client,
-            base_url: base_url.into(),
+            mirrors: Mutex::new(Mirrors {
+                urls: vec![normalize_base(&base_url.into())],
+                current: 0,
+                discovered: false,
+            }),
+            servers_url: None,
             timeout,
             max_retries,
         })
     }
 
-    fn build_params(&self, query: &StationSearchQuery) -> Vec<(String, String)> {
+    /// Adds fallback servers, tried in order after the base URL fails.
+    pub fn with_mirrors<I, S>(self, mirrors: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        {
+            let mut state = self.lock_mirrors();
+            for mirror in mirrors {
+                let mirror = normalize_base(mirror.as_ref());
+                if !state.urls.contains(&mirror) {
+                    state.urls.push(mirror);
+                }
+            }
+        }
+        self
+    }
+
+    /// Fetches more mirrors from `servers_url` (a `/json/servers` listing) the first time
+    /// every known mirror fails.
+    pub fn with_discovery(mut self, servers_url: impl Into<String>) -> Self {
+        self.servers_url = Some(servers_url.into());
+        self
+    }
+
+    /// Known mirrors, configured ones first.
+    pub fn mirrors(&self) -> Vec<String> {
+        self.lock_mirrors().urls.clone()
+    }
+
+    /// The mirror that answered last, or the base URL before any request.
+    pub fn current_mirror(&self) -> String {
+        let state = self.lock_mirrors();
+        state.urls[state.current].clone()
+    }
+
+    fn lock_mirrors(&self) -> MutexGuard<'_, Mirrors> {
+        self.mirrors.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Query parameters for `query`, plus the filters Radio Browser cannot express, which are
+    /// applied to the results locally. Paging parameters are added by the caller.
+    fn build_params(&self, query: &StationSearchQuery) -> (Vec<(String, String)>, StationFilters) {
         let mut params = vec![
             ("hidebroken".to_string(), "true".to_string()),
-            ("limit".to_string(), query.limit.to_string()),
             ("order".to_string(), query.sort.as_api_order().to_string()),
             (
                 "reverse".to_string(),
-                if query.sort.is_descending() {
+                if query.descending {
                     "true".to_string()
                 } else {
                     "false".to_string()
@@ -58,108 +164,71 @@ impl RadioBrowserCatalog {
             params.push(("name".to_string(), query.query.trim().to_string()));
         }
 
-        if let Some(country) = &query.filters.country {
-            params.push(("country".to_string(), country.clone()));
+        let filters = &query.filters;
+        let mut client_side = StationFilters::default();
+        for facet in Facet::ALL {
+            *client_side.excluded_mut(facet) = filters.excluded(facet).map(ToString::to_string);
+            let Some(expr) = filters.value(facet) else {
+                continue;
+            };
+            let groups = filter_terms(expr);
+            if groups.len() == 1 && groups[0].len() == 1 {
+                params.push((facet.filter_key().to_string(), groups[0][0].to_string()));
+            } else if facet == Facet::Tag && groups.iter().all(|group| group.len() == 1) {
+                // `tagList` requires every listed tag.
+                let tags: Vec<&str> = groups.iter().map(|group| group[0]).collect();
+                params.push(("tagList".to_string(), tags.join(",")));
+            } else {
+                client_side.set(facet, expr.to_string());
+            }
         }
-        if let Some(language) = &query.filters.language {
-            params.push(("language".to_string(), language.clone()));
+        if let Some(min_bitrate) = filters.min_bitrate {
+            params.push(("bitrateMin".to_string(), min_bitrate.to_string()));
         }
-        if let Some(tag) = &query.filters.tag {
-            params.push(("tag".to_string(), tag.clone()));
+        if let Some(max_bitrate) = filters.max_bitrate {
+            params.push(("bitrateMax".to_string(), max_bitrate.to_string()));
         }
-        if let Some(codec) = &query.filters.codec {
-            params.push(("codec".to_string(), codec.clone()));
+        if let Some(has_geo) = filters.has_geo {
+            params.push(("has_geo_info".to_string(), has_geo.to_string()));
         }
-        if let Some(min_bitrate) = query.filters.min_bitrate {
-            params.push(("bitrateMin".to_string(), min_bitrate.to_string()));
+        if filters.https_only {
+            params.push(("is_https".to_string(), "true".to_string()));
         }
+        // `is_working` needs nothing extra: `hidebroken` already drops failed stations.
 
-        params
+        (params, client_side)
     }
-}
 
-impl StationCatalog for RadioBrowserCatalog {
-    fn search(&self, query: &StationSearchQuery) -> Result<Vec<Station>> {
-        let url = format!("{}/json/stations/search", self.base_url);
-        let params = self.build_params(query);
-
-        #[derive(serde::Deserialize)]
-        struct ApiStation {
-            stationuuid: String,
-            name: Option<String>,
-            url: Option<String>,
-            url_resolved: Option<String>,
-            homepage: Option<String>,
-            favicon: Option<String>,
-            tags: Option<String>,
-            country: Option<String>,
-            countrycode: Option<String>,
-            language: Option<String>,
-            codec: Option<String>,
-            bitrate: Option<u32>,
-            votes: Option<u32>,
-            clickcount: Option<u32>,
-        }
+    fn fetch_page(
+        &self,
+        params: &[(String, String)],
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<ApiStation>> {
+        let mut params = params.to_vec();
+        params.push(("limit".to_string(), limit.to_string()));
+        params.push(("offset".to_string(), offset.to_string()));
+        self.get_json("/json/stations/search", &params)
+    }
 
+    /// GET `path` with `params`. Transport failures and 5xx responses move on to the next
+    /// mirror; once every mirror has failed the round is retried with backoff.
+    fn get_json<T: DeserializeOwned>(&self, path: &str, params: &[(String, String)]) -> Result<T> {
         let mut last_error = None;
 
         for attempt in 0..=self.max_retries {
-            let response = self.client.get(&url).query(&params).send();
-            match response {
-                Ok(resp) => {
-                    let status = resp.status();
-                    if status.is_server_error() {
-                        last_error = Some(anyhow!("station catalog server error: HTTP {status}"));
-                    } else {
-                        let api_stations: Vec<ApiStation> = resp
-                            .error_for_status()
-                            .context("station catalog returned error status")?
-                            .json()
-                            .context("failed to deserialize station catalog response")?;
-
-                        let stations = api_stations
-                            .into_iter()
-                            .map(|s| {
-                                let url_resolved =
-                                    s.url_resolved.or(s.url).unwrap_or_else(|| "".to_string());
-
-                                Station {
-                                    station_uuid: s.stationuuid,
-                                    name: s
-                                        .name
-                                        .filter(|n| !n.trim().is_empty())
-                                        .unwrap_or_else(|| "(unnamed station)".to_string()),
-                                    url_resolved,
-                                    homepage: s.homepage,
-                                    favicon: s.favicon.filter(|v| !v.trim().is_empty()),
-                                    tags: s
-                                        .tags
-                                        .unwrap_or_default()
-                                        .split(',')
-                                        .map(str::trim)
-                                        .filter(|t| !t.is_empty())
-                                        .map(ToString::to_string)
-                                        .collect(),
-                                    country: s.country.filter(|v| !v.trim().is_empty()),
-                                    country_code: s.countrycode.filter(|v| !v.trim().is_empty()),
-                                    language: s.language.filter(|v| !v.trim().is_empty()),
-                                    codec: s.codec.filter(|v| !v.trim().is_empty()),
-                                    bitrate: s.bitrate,
-                                    votes: s.votes,
-                                    click_count: s.clickcount,
-                                }
-                            })
-                            .filter(|s| !s.url_resolved.trim().is_empty())
-                            .collect();
-
-                        return Ok(stations);
+            let mut tried = 0;
+            while let Some((idx, base)) = self.next_mirror(tried) {
+                tried += 1;
+                match self.get_from(&base, path, params) {
+                    Ok(result) => {
+                        self.mark_healthy(idx, &base, path);
+                        return result;
+                    }
+                    Err(err) => {
+                        warn!(mirror = %base, path, error = %err, "radio browser mirror failed");
+                        last_error = Some(err);
                     }
-                }
-                Err(err) => {
-                    last_error = Some(anyhow!(
-                        "station catalog request failed (timeout={}ms): {err}",
-                        self.timeout.as_millis()
-                    ));
                 }
             }
 
@@ -171,6 +240,284 @@ impl StationCatalog for RadioBrowserCatalog {
 
         Err(last_error.unwrap_or_else(|| anyhow!("station catalog request failed")))
     }
+
+    /// One request to the current mirror, for calls that must not be repeated: a vote or click
+    /// that timed out may still have been counted, and re-sending it would count it twice.
+    fn get_json_once<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let (idx, base) = self
+            .next_mirror(0)
+            .ok_or_else(|| anyhow!("no station catalog mirror configured"))?;
+        let result = self.get_from(&base, path, &[])?;
+        self.mark_healthy(idx, &base, path);
+        result
+    }
+
+    fn next_mirror(&self, tried: usize) -> Option<(usize, String)> {
+        if tried >= self.lock_mirrors().urls.len() && !self.discover_mirrors() {
+            return None;
+        }
+        let state = self.lock_mirrors();
+        let idx = state.nth(tried)?;
+        Some((idx, state.urls[idx].clone()))
+    }
+
+    fn get_from<T: DeserializeOwned>(
+        &self,
+        base: &str,
+        path: &str,
+        params: &[(String, String)],
+    ) -> MirrorResult<T> {
+        let resp = self
+            .client
+            .get(format!("{base}{path}"))
+            .query(params)
+            .send()
+            .map_err(|err| {
+                anyhow!(
+                    "station catalog request to {base} failed (timeout={}ms): {err}",
+                    self.timeout.as_millis()
+                )
+            })?;
+        let status = resp.status();
+        if status.is_server_error() {
+            return Err(anyhow!(
+                "station catalog server error from {base}: HTTP {status}"
+            ));
+        }
+        Ok(resp
+            .error_for_status()
+            .context("station catalog returned error status")
+            .and_then(|resp| {
+                resp.json()
+                    .context("failed to deserialize station catalog response")
+            }))
+    }
+
+    fn mark_healthy(&self, idx: usize, base: &str, path: &str) {
+        let mut state = self.lock_mirrors();
+        if state.current != idx {
+            warn!(mirror = %base, previous = %state.urls[state.current], "switched radio browser mirror");
+            state.current = idx;
+        }
+        debug!(mirror = %base, path, "radio browser answered");
+    }
+
+    /// Appends the servers from the `/json/servers` listing, once per session. Returns whether
+    /// any new mirror was added.
+    fn discover_mirrors(&self) -> bool {
+        let Some(servers_url) = &self.servers_url else {
+            return false;
+        };
+        {
+            let mut state = self.lock_mirrors();
+            if state.discovered {
+                return false;
+            }
+            state.discovered = true;
+        }
+
+        let servers: Vec<ApiServer> = match self
+            .client
+            .get(servers_url)
+            .send()
+            .and_then(|resp| resp.error_for_status())
+            .and_then(|resp| resp.json())
+        {
+            Ok(servers) => servers,
+            Err(err) => {
+                warn!(url = %servers_url, error = %err, "radio browser mirror discovery failed");
+                return false;
+            }
+        };
+        let scheme = servers_url
+            .split_once("://")
+            .map_or("https", |(scheme, _)| scheme);
+
+        let mut state = self.lock_mirrors();
+        let known = state.urls.len();
+        for server in servers {
+            let name = server.name.trim();
+            if name.is_empty() {
+                continue;
+            }
+            let mirror = normalize_base(&format!("{scheme}://{name}"));
+            if !state.urls.contains(&mirror) {
+                state.urls.push(mirror);
+            }
+        }
+        debug!(
+            url = %servers_url,
+            added = state.urls.len() - known,
+            "discovered radio browser mirrors"
+        );
+        state.urls.len() > known
+    }
+}
+
+fn normalize_base(url: &str) -> String {
+    url.trim().trim_end_matches('/').to_string()
+}
+
+impl StationCatalog for RadioBrowserCatalog {
+    fn search(&self, query: &StationSearchQuery) -> Result<Vec<Station>> {
+        let (params, client_side) = self.build_params(query);
+        if client_side.is_empty() {
+            let api_stations = self.fetch_page(&params, query.limit, query.offset)?;
+            return Ok(into_stations(api_stations));
+        }
+
+        // `offset` counts filtered stations, so scan server pages from the start until this
+        // page is full. The scan is bounded; a short page ends paging in the UI.
+        let wanted = query.offset + query.limit;
+        let mut matched = Vec::new();
+        let mut server_offset = 0;
+        for _ in 0..MAX_FILTERED_PAGES {
+            let api_stations = self.fetch_page(&params, FILTERED_PAGE_SIZE, server_offset)?;
+            let fetched = api_stations.len();
+            server_offset += fetched;
+            matched.extend(
+                into_stations(api_stations)
+                    .into_iter()
+                    .filter(|station| client_side.matches(station)),
+            );
+            if fetched < FILTERED_PAGE_SIZE || matched.len() >= wanted {
+                break;
+            }
+        }
+        Ok(matched
+            .into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .collect())
+    }
+
+    fn lookup(&self, station_uuids: &[String]) -> Result<Vec<Station>> {
+        let mut stations = Vec::new();
+        for chunk in station_uuids.chunks(LOOKUP_BATCH_SIZE) {
+            let params = [("uuids".to_string(), chunk.join(","))];
+            let api_stations: Vec<ApiStation> = self.get_json("/json/stations/byuuid", &params)?;
+            stations.extend(into_stations(api_stations));
+        }
+        Ok(stations)
+    }
+
+    fn facet_values(&self, facet: Facet) -> Result<Vec<FacetValue>> {
+        let params = [
+            ("hidebroken".to_string(), "true".to_string()),
+            ("order".to_string(), "stationcount".to_string()),
+            ("reverse".to_string(), "true".to_string()),
+            ("limit".to_string(), FACET_LIMIT.to_string()),
+        ];
+        let api_facets: Vec<ApiFacet> =
+            self.get_json(&format!("/json/{}", facet.plural()), &params)?;
+        Ok(api_facets
+            .into_iter()
+            .filter(|f| !f.name.trim().is_empty())
+            .map(|f| FacetValue {
+                name: f.name,
+                station_count: f.stationcount,
+            })
+            .collect())
+    }
+
+    fn register_click(&self, station_uuid: &str) -> Result<()> {
+        let result: ApiActionResult = self.get_json_once(&format!("/json/url/{station_uuid}"))?;
+        if !result.ok {
+            return Err(anyhow!("click not counted: {}", result.message));
+        }
+        debug!(station_uuid, "registered station click");
+        Ok(())
+    }
+
+    fn vote(&self, station_uuid: &str) -> Result<String> {
+        let result: ApiActionResult = self.get_json_once(&format!("/json/vote/{station_uuid}"))?;
+        if result.ok {
+            Ok(result.message)
+        } else {
+            Err(anyhow!("{}", result.message))
+        }
+    }
+}
+
+/// Reply of the `/json/url` and `/json/vote` endpoints.
+#[derive(serde::Deserialize)]
+struct ApiActionResult {
+    ok: bool,
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(serde::Deserialize)]
+struct ApiServer {
+    name: String,
+}
+
+#[derive(serde::Deserialize)]
+struct ApiFacet {
+    name: String,
+    #[serde(default)]
+    stationcount: u32,
+}
+
+#[derive(serde::Deserialize)]
+struct ApiStation {
+    stationuuid: String,
+    name: Option<String>,
+    url: Option<String>,
+    url_resolved: Option<String>,
+    homepage: Option<String>,
+    favicon: Option<String>,
+    tags: Option<String>,
+    country: Option<String>,
+    countrycode: Option<String>,
+    language: Option<String>,
+    codec: Option<String>,
+    bitrate: Option<u32>,
+    votes: Option<u32>,
+    clickcount: Option<u32>,
+    geo_lat: Option<f64>,
+    geo_long: Option<f64>,
+    lastcheckok: Option<u8>,
+    lastchangetime_iso8601: Option<String>,
+}
+
+fn into_stations(api_stations: Vec<ApiStation>) -> Vec<Station> {
+    api_stations
+        .into_iter()
+        .map(|s| {
+            let url_resolved = s.url_resolved.or(s.url).unwrap_or_else(|| "".to_string());
+
+            Station {
+                station_uuid: s.stationuuid,
+                name: s
+                    .name
+                    .filter(|n| !n.trim().is_empty())
+                    .unwrap_or_else(|| "(unnamed station)".to_string()),
+                url_resolved,
+                homepage: s.homepage,
+                favicon: s.favicon.filter(|v| !v.trim().is_empty()),
+                tags: s
+                    .tags
+                    .unwrap_or_default()
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(ToString::to_string)
+                    .collect(),
+                country: s.country.filter(|v| !v.trim().is_empty()),
+                country_code: s.countrycode.filter(|v| !v.trim().is_empty()),
+                language: s.language.filter(|v| !v.trim().is_empty()),
+                codec: s.codec.filter(|v| !v.trim().is_empty()),
+                bitrate: s.bitrate,
+                votes: s.votes,
+                click_count: s.clickcount,
+                has_geo_info: s.geo_lat.is_some() && s.geo_long.is_some(),
+                last_check_ok: s.lastcheckok.map(|ok| ok == 1),
+                last_change_time: s.lastchangetime_iso8601.filter(|v| !v.trim().is_empty()),
+            }
+        })
+        .filter(|s| !s.url_resolved.trim().is_empty())
+        .collect()
 }
 
 pub struct StaticCatalog {
@@ -189,35 +536,78 @@ impl StationCatalog for StaticCatalog {
         let mut stations: Vec<Station> = self
             .stations
             .iter()
-            .filter(|station| text.is_empty() || station.matches_query(&text))
+            .filter(|station| {
+                (text.is_empty() || station.matches_query(&text)) && query.filters.matches(station)
+            })
             .cloned()
             .collect();
 
-        match query.sort {
-            crate::domain::models::StationSort::Name => {
-                stations.sort_by(|a, b| a.name.cmp(&b.name));
-            }
-            crate::domain::models::StationSort::Votes => {
-                stations.sort_by(|a, b| b.votes.cmp(&a.votes).then_with(|| a.name.cmp(&b.name)));
-            }
-            crate::domain::models::StationSort::Clicks => {
-                stations.sort_by(|a, b| {
-                    b.click_count
-                        .cmp(&a.click_count)
-                        .then_with(|| a.name.cmp(&b.name))
-                });
-            }
-            crate::domain::models::StationSort::Bitrate => {
-                stations
-                    .sort_by(|a, b| b.bitrate.cmp(&a.bitrate).then_with(|| a.name.cmp(&b.name)));
-            }
-        }
+        // A fresh hasher per search gives a new order each time, like Radio Browser's.
+        let shuffle = RandomState::new();
+        stations.sort_by(|a, b| {
+            let order = match query.sort {
+                StationSort::Name => a.name.cmp(&b.name),
+                StationSort::Votes => a.votes.cmp(&b.votes),
+                StationSort::Clicks => a.click_count.cmp(&b.click_count),
+                StationSort::Bitrate => a.bitrate.cmp(&b.bitrate),
+                StationSort::Country => a.country.cmp(&b.country),
+                StationSort::Language => a.language.cmp(&b.language),
+                StationSort::Codec => a.codec.cmp(&b.codec),
+                StationSort::LastCheck => a.last_check_ok.cmp(&b.last_check_ok),
+                StationSort::Changed => a.last_change_time.cmp(&b.last_change_time),
+                StationSort::Random => shuffle
+                    .hash_one(&a.station_uuid)
+                    .cmp(&shuffle.hash_one(&b.station_uuid)),
+            };
+            let order = if query.descending {
+                order.reverse()
+            } else {
+                order
+            };
+            order.then_with(|| a.name.cmp(&b.name))
+        });
 
-        if stations.len() > query.limit {
-            stations.truncate(query.limit);
-        }
+        Ok(stations
+            .into_iter()
+            .skip(query.offset)
+            .take(query.limit)
+            .collect())
+    }
 
-        Ok(stations)
+    fn lookup(&self, station_uuids: &[String]) -> Result<Vec<Station>> {
+        Ok(self
+            .stations
+            .iter()
+            .filter(|station| station_uuids.contains(&station.station_uuid))
+            .cloned()
+            .collect())
+    }
+
+    fn facet_values(&self, facet: Facet) -> Result<Vec<FacetValue>> {
+        let mut values: Vec<FacetValue> = Vec::new();
+        for station in &self.stations {
+            let names: Vec<&str> = match facet {
+                Facet::Country => station.country.as_deref().into_iter().collect(),
+                Facet::Language => station.language.as_deref().into_iter().collect(),
+                Facet::Tag => station.tags.iter().map(String::as_str).collect(),
+                Facet::Codec => station.codec.as_deref().into_iter().collect(),
+            };
+            for name in names {
+                match values.iter_mut().find(|value| value.name == name) {
+                    Some(value) => value.station_count += 1,
+                    None => values.push(FacetValue {
+                        name: name.to_string(),
+                        station_count: 1,
+                    }),
+                }
+            }
+        }
+        values.sort_by(|a, b| {
+            b.station_count
+                .cmp(&a.station_count)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        Ok(values)
     }
 }
 
@@ -225,6 +615,8 @@ impl StationCatalog for StaticCatalog {
 mod tests {
     use std::io::{Read, Write};
     use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
 
     use super::*;
     use crate::domain::models::{StationFilters, StationSort};
@@ -251,6 +643,7 @@ mod tests {
             assert!(req.contains("bitrateMin=128"));
             assert!(req.contains("order=clickcount"));
             assert!(req.contains("reverse=true"));
+            assert!(req.contains("offset=50"));
 
             let body = r#"[{"stationuuid":"id1","name":"Jazz FM","url_resolved":"https://example.com/stream","tags":"jazz,smooth","country":"US","language":"english","codec":"mp3","bitrate":128,"votes":10,"clickcount":20}]"#;
             let response = format!(
@@ -278,9 +671,12 @@ mod tests {
                     tag: Some("smooth".to_string()),
                     codec: Some("mp3".to_string()),
                     min_bitrate: Some(128),
+                    ..StationFilters::default()
                 },
                 sort: StationSort::Clicks,
+                descending: true,
                 limit: 25,
+                offset: 50,
             })
             .expect("search stations");
 
@@ -340,4 +736,435 @@ mod tests {
         assert_eq!(stations.len(), 1);
         assert_eq!(stations[0].name, "Retry FM");
     }
+
+    /// Answers every request with `status` and `body` until the test exits; returns the base
+    /// URL and a request counter, or `None` when the sandbox forbids binding.
+    fn spawn_server(status: &'static str, body: String) -> Option<(String, Arc<AtomicUsize>)> {
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => return None,
+            Err(err) => panic!("bind listener: {err}"),
+        };
+        let addr = listener.local_addr().expect("local addr");
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0_u8; 2048];
+                let _ = stream.read(&mut buf);
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = format!(
+                    "HTTP/1.1 {status}\r\ncontent-type: application/json\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        Some((format!("http://{addr}"), requests))
+    }
+
+    fn closed_port_url() -> Option<String> {
+        let listener = TcpListener::bind("127.0.0.1:0").ok()?;
+        let addr = listener.local_addr().ok()?;
+        drop(listener);
+        Some(format!("http://{addr}"))
+    }
+
+    const HEALTHY_BODY: &str = r#"[{"stationuuid":"id3","name":"Mirror FM","url_resolved":"https://example.com/mirror","tags":""}]"#;
+
+    #[test]
+    fn failing_mirror_hands_over_to_the_next_and_stays_switched() {
+        let Some((broken, broken_hits)) = spawn_server("503 Service Unavailable", String::new())
+        else {
+            return;
+        };
+        let Some((healthy, healthy_hits)) = spawn_server("200 OK", HEALTHY_BODY.to_string()) else {
+            return;
+        };
+
+        let catalog = RadioBrowserCatalog::new_with_config(&broken, Duration::from_secs(1), 0)
+            .expect("create catalog")
+            .with_mirrors([format!("{healthy}/")]);
+        assert_eq!(catalog.mirrors(), vec![broken.clone(), healthy.clone()]);
+
+        for _ in 0..2 {
+            let stations = catalog
+                .search(&StationSearchQuery::default())
+                .expect("search through the healthy mirror");
+            assert_eq!(stations[0].name, "Mirror FM");
+        }
+
+        assert_eq!(catalog.current_mirror(), healthy);
+        assert_eq!(broken_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(healthy_hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn exhausted_mirrors_discover_more_from_the_server_list() {
+        let Some(dead) = closed_port_url() else {
+            return;
+        };
+        let Some((healthy, _)) = spawn_server("200 OK", HEALTHY_BODY.to_string()) else {
+            return;
+        };
+        let listing = format!(
+            r#"[{{"name":"{}"}},{{"name":""}}]"#,
+            healthy.trim_start_matches("http://")
+        );
+        let Some((servers, servers_hits)) = spawn_server("200 OK", listing) else {
+            return;
+        };
+
+        let catalog = RadioBrowserCatalog::new_with_config(&dead, Duration::from_secs(1), 0)
+            .expect("create catalog")
+            .with_discovery(format!("{servers}/json/servers"));
+        let stations = catalog
+            .search(&StationSearchQuery::default())
+            .expect("search through a discovered mirror");
+        assert_eq!(stations[0].name, "Mirror FM");
+        assert_eq!(catalog.mirrors(), vec![dead, healthy.clone()]);
+        assert_eq!(catalog.current_mirror(), healthy);
+
+        catalog
+            .search(&StationSearchQuery::default())
+            .expect("search again");
+        assert_eq!(servers_hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn client_errors_do_not_fail_over() {
+        let Some((rejecting, _)) = spawn_server("400 Bad Request", String::new()) else {
+            return;
+        };
+        let Some((healthy, healthy_hits)) = spawn_server("200 OK", HEALTHY_BODY.to_string()) else {
+            return;
+        };
+
+        let catalog = RadioBrowserCatalog::new_with_config(&rejecting, Duration::from_secs(1), 0)
+            .expect("create catalog")
+            .with_mirrors([healthy]);
+        let err = catalog
+            .search(&StationSearchQuery::default())
+            .expect_err("4xx is final");
+        assert!(format!("{err:#}").contains("error status"));
+        assert_eq!(healthy_hits.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn votes_report_the_server_message() {
+        let Some((accepting, hits)) = spawn_server(
+            "200 OK",
+            r#"{"ok":true,"message":"voted for station successfully"}"#.to_string(),
+        ) else {
+            return;
+        };
+        let Some((refusing, _)) = spawn_server(
+            "200 OK",
+            r#"{"ok":false,"message":"VoteError 'you are voting for the same station too often'"}"#
+                .to_string(),
+        ) else {
+            return;
+        };
+
+        let catalog = RadioBrowserCatalog::new_with_config(&accepting, Duration::from_secs(1), 0)
+            .expect("create catalog");
+        assert_eq!(
+            catalog.vote("id1").expect("vote"),
+            "voted for station successfully"
+        );
+        catalog.register_click("id1").expect("register click");
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        let catalog = RadioBrowserCatalog::new_with_config(&refusing, Duration::from_secs(1), 0)
+            .expect("create catalog");
+        let err = catalog.vote("id1").expect_err("vote refused");
+        assert!(err.to_string().contains("too often"));
+    }
+
+    #[test]
+    fn votes_and_clicks_are_sent_once_without_retry_or_failover() {
+        let Some((broken, broken_hits)) = spawn_server("503 Service Unavailable", String::new())
+        else {
+            return;
+        };
+        let Some((healthy, healthy_hits)) = spawn_server(
+            "200 OK",
+            r#"{"ok":true,"message":"voted for station successfully"}"#.to_string(),
+        ) else {
+            return;
+        };
+
+        let catalog = RadioBrowserCatalog::new_with_config(&broken, Duration::from_secs(1), 2)
+            .expect("create catalog")
+            .with_mirrors([healthy]);
+        catalog.vote("id1").expect_err("the current mirror failed");
+        catalog
+            .register_click("id1")
+            .expect_err("the current mirror failed");
+        assert_eq!(broken_hits.load(Ordering::SeqCst), 2);
+        assert_eq!(healthy_hits.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn static_catalog_applies_offset_after_sorting() {
+        let stations = ["Charlie", "Alpha", "Bravo"]
+            .into_iter()
+            .map(|name| Station {
+                station_uuid: name.to_ascii_lowercase(),
+                name: name.to_string(),
+                url_resolved: format!("https://example.com/{name}"),
+                homepage: None,
+                favicon: None,
+                tags: Vec::new(),
+                country: None,
+                country_code: None,
+                language: None,
+                codec: None,
+                bitrate: None,
+                votes: None,
+                click_count: None,
+                has_geo_info: false,
+                last_check_ok: None,
+                last_change_time: None,
+            })
+            .collect();
+        let catalog = StaticCatalog::new(stations);
+
+        let page = catalog
+            .search(&StationSearchQuery {
+                sort: StationSort::Name,
+                descending: false,
+                limit: 1,
+                offset: 1,
+                ..StationSearchQuery::default()
+            })
+            .expect("search static catalog");
+
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].name, "Bravo");
+    }
+
+    #[test]
+    fn static_catalog_sorts_new_fields_in_either_direction() {
+        let station = |id: &str, country: &str, changed: &str, ok: bool| Station {
+            station_uuid: id.to_string(),
+            name: id.to_string(),
+            url_resolved: format!("https://example.com/{id}"),
+            homepage: None,
+            favicon: None,
+            tags: Vec::new(),
+            country: Some(country.to_string()),
+            country_code: None,
+            language: None,
+            codec: None,
+            bitrate: None,
+            votes: None,
+            click_count: None,
+            has_geo_info: false,
+            last_check_ok: Some(ok),
+            last_change_time: Some(changed.to_string()),
+        };
+        let catalog = StaticCatalog::new(vec![
+            station("a", "Germany", "2024-03-01T10:00:00Z", false),
+            station("b", "Austria", "2025-01-01T10:00:00Z", true),
+            station("c", "Peru", "2023-06-01T10:00:00Z", true),
+        ]);
+        let ids = |sort: StationSort, descending: bool| -> Vec<String> {
+            catalog
+                .search(&StationSearchQuery {
+                    sort,
+                    descending,
+                    ..StationSearchQuery::default()
+                })
+                .expect("search static catalog")
+                .into_iter()
+                .map(|station| station.station_uuid)
+                .collect()
+        };
+
+        assert_eq!(ids(StationSort::Country, false), ["b", "a", "c"]);
+        assert_eq!(ids(StationSort::Country, true), ["c", "a", "b"]);
+        assert_eq!(ids(StationSort::Changed, true), ["b", "a", "c"]);
+        assert_eq!(ids(StationSort::LastCheck, true), ["b", "c", "a"]);
+        let mut shuffled = ids(StationSort::Random, false);
+        shuffled.sort();
+        assert_eq!(shuffled, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn sort_direction_maps_to_the_reverse_param() {
+        let catalog =
+            RadioBrowserCatalog::new_with_config("http://127.0.0.1:9", Duration::from_secs(1), 0)
+                .expect("create catalog");
+        let (params, _) = catalog.build_params(&StationSearchQuery {
+            sort: StationSort::Changed,
+            descending: false,
+            ..StationSearchQuery::default()
+        });
+        assert!(params.contains(&("order".to_string(), "changetimestamp".to_string())));
+        assert!(params.contains(&("reverse".to_string(), "false".to_string())));
+    }
+
+    #[test]
+    fn lookup_requests_stations_by_uuid() {
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => return,
+            Err(err) => panic!("bind listener: {err}"),
+        };
+        let addr = listener.local_addr().expect("local addr");
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept request");
+            let mut buf = [0_u8; 4096];
+            let n = stream.read(&mut buf).expect("read request");
+            let req = String::from_utf8_lossy(&buf[..n]).to_string();
+            assert!(req.starts_with("GET /json/stations/byuuid?"));
+            assert!(req.contains("uuids=fav-1%2Cfav-2"));
+
+            let body = r#"[{"stationuuid":"fav-2","name":"Fav Two","url_resolved":"https://example.com/two"}]"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream
+                .write_all(response.as_bytes())
+                .expect("write response");
+        });
+
+        let catalog = RadioBrowserCatalog::new_with_config(
+            format!("http://{addr}"),
+            Duration::from_secs(1),
+            0,
+        )
+        .expect("create catalog");
+        let stations = catalog
+            .lookup(&["fav-1".to_string(), "fav-2".to_string()])
+            .expect("lookup stations");
+
+        handle.join().expect("join server");
+        assert_eq!(stations.len(), 1);
+        assert_eq!(stations[0].station_uuid, "fav-2");
+        assert_eq!(stations[0].name, "Fav Two");
+    }
+
+    #[test]
+    fn facet_values_request_the_list_endpoint_by_station_count() {
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => return,
+            Err(err) => panic!("bind listener: {err}"),
+        };
+        let addr = listener.local_addr().expect("local addr");
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept request");
+            let mut buf = [0_u8; 4096];
+            let n = stream.read(&mut buf).expect("read request");
+            let req = String::from_utf8_lossy(&buf[..n]).to_string();
+            assert!(req.starts_with("GET /json/countries?"));
+            assert!(req.contains("order=stationcount"));
+            assert!(req.contains("reverse=true"));
+
+            let body = r#"[{"name":"Germany","iso_3166_1":"DE","stationcount":3000},{"name":"","stationcount":5},{"name":"United States","stationcount":2500}]"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream
+                .write_all(response.as_bytes())
+                .expect("write response");
+        });
+
+        let catalog = RadioBrowserCatalog::new_with_config(
+            format!("http://{addr}"),
+            Duration::from_secs(1),
+            0,
+        )
+        .expect("create catalog");
+        let countries = catalog
+            .facet_values(Facet::Country)
+            .expect("list countries");
+
+        handle.join().expect("join server");
+        assert_eq!(
+            countries,
+            [
+                FacetValue {
+                    name: "Germany".to_string(),
+                    station_count: 3000,
+                },
+                FacetValue {
+                    name: "United States".to_string(),
+                    station_count: 2500,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn search_filters_what_the_server_cannot_express() {
+        let listener = match TcpListener::bind("127.0.0.1:0") {
+            Ok(listener) => listener,
+            Err(err) if err.kind() == std::io::ErrorKind::PermissionDenied => return,
+            Err(err) => panic!("bind listener: {err}"),
+        };
+        let addr = listener.local_addr().expect("local addr");
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept request");
+            let mut buf = [0_u8; 8192];
+            let n = stream.read(&mut buf).expect("read request");
+            let req = String::from_utf8_lossy(&buf[..n]).to_string();
+            assert!(req.contains("tagList=jazz%2Cblues"));
+            assert!(req.contains("bitrateMax=256"));
+            assert!(req.contains("is_https=true"));
+            assert!(req.contains("has_geo_info=true"));
+            assert!(!req.contains("codec="));
+            assert!(req.contains("limit=100"));
+            assert!(req.contains("offset=0"));
+
+            let body = r#"[
+                {"stationuuid":"a","name":"A","url_resolved":"https://example.com/a","tags":"jazz,blues","codec":"MP3","geo_lat":1.0,"geo_long":2.0},
+                {"stationuuid":"b","name":"B","url_resolved":"https://example.com/b","tags":"jazz,blues,talk","codec":"AAC"},
+                {"stationuuid":"c","name":"C","url_resolved":"https://example.com/c","tags":"jazz,blues","codec":"OGG"}
+            ]"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream
+                .write_all(response.as_bytes())
+                .expect("write response");
+        });
+
+        let catalog = RadioBrowserCatalog::new_with_config(
+            format!("http://{addr}"),
+            Duration::from_secs(1),
+            0,
+        )
+        .expect("create catalog");
+        let stations = catalog
+            .search(&StationSearchQuery {
+                filters: StationFilters {
+                    tag: Some("jazz,blues".to_string()),
+                    exclude_tag: Some("talk".to_string()),
+                    codec: Some("mp3|aac".to_string()),
+                    max_bitrate: Some(256),
+                    has_geo: Some(true),
+                    https_only: true,
+                    ..StationFilters::default()
+                },
+                ..StationSearchQuery::default()
+            })
+            .expect("search stations");
+
+        handle.join().expect("join server");
+        let ids: Vec<&str> = stations.iter().map(|s| s.station_uuid.as_str()).collect();
+        assert_eq!(ids, ["a"]);
+        assert!(stations[0].has_geo_info);
+    }
 }
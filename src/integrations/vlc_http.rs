@@ -77,6 +77,12 @@ impl PlaybackController for VlcHttpController {
         Ok(())
     }
 
+    fn force_stop(&mut self) -> Result<()> {
+        self.send_command("pl_stop", None)?;
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
     fn pause(&mut self) -> Result<()> {
         if self.state != PlaybackState::Playing {
             return Err(anyhow!(
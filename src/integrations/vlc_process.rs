@@ -1,20 +1,27 @@
-use std::io::Write;
+use std::io::{BufRead, BufReader, Write};
 use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
 use std::thread;
 use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context, Result};
 
-use super::playback::{volume_percent_to_vlc_scale, PlaybackController, PlaybackState};
+use super::playback::{
+    volume_percent_to_vlc_scale, PlaybackController, PlaybackEvent, PlaybackState,
+};
+use super::vlc_rc_status::{parse_rc_line, RcLine, RcStatusTracker};
 
 const SHUTDOWN_WAIT: Duration = Duration::from_millis(500);
 const SHUTDOWN_POLL: Duration = Duration::from_millis(50);
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 pub struct VlcProcessController {
     program: String,
     child: Option<Child>,
     stdin: Option<ChildStdin>,
-    state: PlaybackState,
+    output: Option<Receiver<RcLine>>,
+    tracker: RcStatusTracker,
+    last_status_poll: Option<Instant>,
 }
 
 impl Default for VlcProcessController {
@@ -33,7 +40,9 @@ impl VlcProcessController {
             program: program.into(),
             child: None,
             stdin: None,
-            state: PlaybackState::Stopped,
+            output: None,
+            tracker: RcStatusTracker::new(),
+            last_status_poll: None,
         }
     }
 
@@ -44,11 +53,12 @@ impl VlcProcessController {
 
         self.child = None;
         self.stdin = None;
+        self.output = None;
 
         let mut child = Command::new(&self.program)
             .args(["--intf", "rc", "--rc-fake-tty", "--no-video", "--quiet"])
             .stdin(Stdio::piped())
-            .stdout(Stdio::null())
+            .stdout(Stdio::piped())
             .stderr(Stdio::null())
             .spawn()
             .map_err(|err| {
@@ -69,11 +79,66 @@ impl VlcProcessController {
             .stdin
             .take()
             .ok_or_else(|| anyhow!("failed to capture VLC stdin for RC commands"))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| anyhow!("failed to capture VLC stdout for RC responses"))?;
+
+        let (tx, rx) = mpsc::channel();
+        thread::Builder::new()
+            .name("vlc-rc-reader".to_string())
+            .spawn(move || {
+                for line in BufReader::new(stdout).lines() {
+                    let Ok(line) = line else {
+                        break;
+                    };
+                    if let Some(parsed) = parse_rc_line(&line) {
+                        if tx.send(parsed).is_err() {
+                            break;
+                        }
+                    }
+                }
+            })
+            .context("failed to start VLC output reader")?;
+
         self.stdin = Some(stdin);
+        self.output = Some(rx);
         self.child = Some(child);
         Ok(())
     }
 
+    fn drain_output(&mut self) {
+        let Some(output) = self.output.as_ref() else {
+            return;
+        };
+        loop {
+            match output.try_recv() {
+                Ok(line) => self.tracker.apply_line(line),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.output = None;
+                    self.tracker.output_closed();
+                    break;
+                }
+            }
+        }
+    }
+
+    fn poll_status(&mut self) {
+        let due = self
+            .last_status_poll
+            .is_none_or(|last| last.elapsed() >= STATUS_POLL_INTERVAL);
+        if !due || self.output.is_none() {
+            return;
+        }
+        self.last_status_poll = Some(Instant::now());
+
+        if self.send_command("status").is_ok() && self.send_command("is_playing").is_ok() {
+            self.tracker.is_playing_query_sent();
+        }
+        let _ = self.send_command("info");
+    }
+
     fn child_is_running(&mut self) -> Result<bool> {
         if let Some(child) = self.child.as_mut() {
             if child
@@ -125,11 +190,14 @@ impl PlaybackController for VlcProcessController {
     fn play(&mut self, stream_url: &str) -> Result<()> {
         let validated = Self::validate_stream_url(stream_url)?;
         self.spawn_if_needed()?;
-        if matches!(self.state, PlaybackState::Playing | PlaybackState::Paused) {
+        if self.state() != PlaybackState::Stopped {
             self.send_command("clear")?;
         }
         self.send_command(&format!("add {validated}"))?;
-        self.state = PlaybackState::Playing;
+        let now = Instant::now();
+        self.tracker.begin_loading(now);
+        // Give the stream a full poll interval to open before asking `is_playing`.
+        self.last_status_poll = Some(now);
         Ok(())
     }
 
@@ -141,41 +209,44 @@ impl PlaybackController for VlcProcessController {
     }
 
     fn stop(&mut self) -> Result<()> {
-        if self.state == PlaybackState::Stopped {
+        if self.state() == PlaybackState::Stopped {
             return Err(anyhow!(
                 "cannot stop because playback is already stopped; start a stream first with /play"
             ));
         }
         self.send_command("stop")?;
-        self.state = PlaybackState::Stopped;
+        self.tracker.user_transition(PlaybackState::Stopped);
         Ok(())
     }
 
     fn pause(&mut self) -> Result<()> {
-        if self.state != PlaybackState::Playing {
+        if !matches!(
+            self.state(),
+            PlaybackState::Playing | PlaybackState::Buffering
+        ) {
             return Err(anyhow!(
                 "cannot pause because no stream is currently playing; start playback first"
             ));
         }
         self.send_command("pause")?;
-        self.state = PlaybackState::Paused;
+        self.tracker.user_transition(PlaybackState::Paused);
         Ok(())
     }
 
     fn resume(&mut self) -> Result<()> {
-        if self.state != PlaybackState::Paused {
+        if self.state() != PlaybackState::Paused {
             return Err(anyhow!(
                 "cannot resume because playback is not paused; pause first or use /play"
             ));
         }
         self.send_command("pause")?;
-        self.state = PlaybackState::Playing;
+        self.tracker.user_transition(PlaybackState::Playing);
         Ok(())
     }
 
     fn shutdown(&mut self) -> Result<()> {
         if self.child.is_none() {
-            self.state = PlaybackState::Stopped;
+            self.tracker.user_transition(PlaybackState::Stopped);
             return Ok(());
         }
 
@@ -200,13 +271,23 @@ impl PlaybackController for VlcProcessController {
         }
 
         self.stdin = None;
+        self.output = None;
         self.child = None;
-        self.state = PlaybackState::Stopped;
+        self.tracker.user_transition(PlaybackState::Stopped);
         Ok(())
     }
 
     fn state(&self) -> PlaybackState {
-        self.state
+        self.tracker.state()
+    }
+
+    fn poll_events(&mut self) -> Vec<PlaybackEvent> {
+        self.drain_output();
+        if !matches!(self.state(), PlaybackState::Stopped | PlaybackState::Error) {
+            self.poll_status();
+            self.tracker.check_timeouts(Instant::now());
+        }
+        self.tracker.drain_events()
     }
 }
 
@@ -58,6 +58,12 @@ impl PlaybackController for VlcRcController {
         Ok(())
     }
 
+    fn force_stop(&mut self) -> Result<()> {
+        self.send("stop")?;
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
     fn pause(&mut self) -> Result<()> {
         if self.state != PlaybackState::Playing {
             return Err(anyhow!(
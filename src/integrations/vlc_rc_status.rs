@@ -0,0 +1,384 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use super::playback::{PlaybackEvent, PlaybackState};
+
+const LOADING_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RcLine {
+    State(PlaybackState),
+    NewInput(String),
+    NowPlaying(String),
+    Reply(String),
+}
+
+pub fn parse_rc_line(raw: &str) -> Option<RcLine> {
+    let mut line = raw.trim();
+    while let Some(rest) = line.strip_prefix('>') {
+        line = rest.trim_start();
+    }
+    if line.is_empty() {
+        return None;
+    }
+
+    if let Some(rest) = line.strip_prefix("status change:") {
+        return parse_status_change(rest.trim());
+    }
+
+    if let Some(inner) = line.strip_prefix('(').and_then(|l| l.strip_suffix(')')) {
+        let inner = inner.trim();
+        if let Some(state) = inner.strip_prefix("state ") {
+            return state_from_word(state.trim()).map(RcLine::State);
+        }
+        if let Some(input) = inner.strip_prefix("new input:") {
+            return Some(RcLine::NewInput(input.trim().to_string()));
+        }
+        return None;
+    }
+
+    // `info` prints a `+----[ Meta data ]` block of `| key: value` lines.
+    if let Some(meta) = line.strip_prefix('|') {
+        let (key, value) = meta.split_once(':')?;
+        let value = value.trim();
+        if key.trim() == "now_playing" && !value.is_empty() {
+            return Some(RcLine::NowPlaying(value.to_string()));
+        }
+        return None;
+    }
+    if line.starts_with('+') {
+        return None;
+    }
+
+    Some(RcLine::Reply(line.to_string()))
+}
+
+// VLC prints e.g. `( play state: 3 ): Play` or `( new input: http://... )`.
+fn parse_status_change(rest: &str) -> Option<RcLine> {
+    let (group, trailer) = match rest.split_once("):") {
+        Some((group, trailer)) => (group, Some(trailer.trim())),
+        None => (rest.trim_end_matches(')'), None),
+    };
+    let group = group.trim_start_matches('(').trim();
+
+    if let Some(input) = group.strip_prefix("new input:") {
+        return Some(RcLine::NewInput(input.trim().to_string()));
+    }
+
+    let (_, code) = group.split_once("state:")?;
+    if let Some(state) = trailer.and_then(state_from_word) {
+        return Some(RcLine::State(state));
+    }
+    let state = match code.trim().parse::<u8>().ok()? {
+        0 | 1 => PlaybackState::Loading,
+        2 => PlaybackState::Playing,
+        3 => PlaybackState::Paused,
+        4 => PlaybackState::Stopped,
+        _ => PlaybackState::Error,
+    };
+    Some(RcLine::State(state))
+}
+
+fn state_from_word(word: &str) -> Option<PlaybackState> {
+    match word.to_ascii_lowercase().as_str() {
+        "opening" | "initializing" => Some(PlaybackState::Loading),
+        "buffering" => Some(PlaybackState::Buffering),
+        "play" | "playing" => Some(PlaybackState::Playing),
+        "pause" | "paused" => Some(PlaybackState::Paused),
+        "stop" | "stopped" | "end" | "ended" => Some(PlaybackState::Stopped),
+        "error" => Some(PlaybackState::Error),
+        _ => None,
+    }
+}
+
+/// Playback state machine fed by commands we send and lines VLC prints back.
+#[derive(Debug)]
+pub struct RcStatusTracker {
+    state: PlaybackState,
+    epoch: u64,
+    loading_since: Option<Instant>,
+    pending_is_playing: VecDeque<u64>,
+    now_playing: Option<String>,
+    events: Vec<PlaybackEvent>,
+}
+
+impl Default for RcStatusTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RcStatusTracker {
+    pub fn new() -> Self {
+        Self {
+            state: PlaybackState::Stopped,
+            epoch: 0,
+            loading_since: None,
+            pending_is_playing: VecDeque::new(),
+            now_playing: None,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn state(&self) -> PlaybackState {
+        self.state
+    }
+
+    pub fn begin_loading(&mut self, now: Instant) {
+        self.user_transition(PlaybackState::Loading);
+        self.loading_since = Some(now);
+        self.now_playing = None;
+    }
+
+    // User commands start a new epoch so replies to earlier queries are ignored.
+    pub fn user_transition(&mut self, state: PlaybackState) {
+        self.epoch += 1;
+        self.loading_since = None;
+        self.state = state;
+    }
+
+    pub fn is_playing_query_sent(&mut self) {
+        self.pending_is_playing.push_back(self.epoch);
+    }
+
+    pub fn apply_line(&mut self, line: RcLine) {
+        match line {
+            RcLine::State(PlaybackState::Error) => {
+                self.fail("VLC reported a playback error for this stream");
+            }
+            RcLine::State(PlaybackState::Stopped) => match self.state {
+                PlaybackState::Playing | PlaybackState::Buffering | PlaybackState::Paused => {
+                    self.fail("stream stopped unexpectedly; the station may have gone offline");
+                }
+                // A stop while loading is usually the `clear` of the previous
+                // station; the loading timeout catches streams that never start.
+                PlaybackState::Loading | PlaybackState::Stopped | PlaybackState::Error => {}
+            },
+            RcLine::State(next) => {
+                if matches!(self.state, PlaybackState::Stopped | PlaybackState::Error) {
+                    return;
+                }
+                if next == PlaybackState::Playing {
+                    self.loading_since = None;
+                }
+                self.transition(next);
+            }
+            RcLine::Reply(reply) => {
+                let playing = match reply.as_str() {
+                    "1" => true,
+                    "0" => false,
+                    _ => return,
+                };
+                let Some(epoch) = self.pending_is_playing.pop_front() else {
+                    return;
+                };
+                if epoch != self.epoch || playing {
+                    return;
+                }
+                // VLC also answers `0` while an HTTP stream is still opening, so a stream
+                // that never starts is left to the loading timeout.
+                if matches!(
+                    self.state,
+                    PlaybackState::Playing | PlaybackState::Buffering
+                ) {
+                    self.fail("stream stopped unexpectedly; the station may have gone offline");
+                }
+            }
+            RcLine::NowPlaying(title) => {
+                if self.state == PlaybackState::Stopped
+                    || self.now_playing.as_deref() == Some(title.as_str())
+                {
+                    return;
+                }
+                self.now_playing = Some(title.clone());
+                self.events.push(PlaybackEvent::StreamTitle(title));
+            }
+            RcLine::NewInput(_) => {}
+        }
+    }
+
+    pub fn output_closed(&mut self) {
+        if self.state != PlaybackState::Stopped {
+            self.fail("VLC exited unexpectedly; use /play to restart playback");
+        }
+    }
+
+    pub fn check_timeouts(&mut self, now: Instant) {
+        if let Some(since) = self.loading_since {
+            if self.state == PlaybackState::Loading && now.duration_since(since) >= LOADING_TIMEOUT
+            {
+                self.fail(&format!(
+                    "stream did not start within {}s; the station may be offline",
+                    LOADING_TIMEOUT.as_secs()
+                ));
+            }
+        }
+    }
+
+    pub fn drain_events(&mut self) -> Vec<PlaybackEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn transition(&mut self, next: PlaybackState) {
+        if self.state != next {
+            self.state = next;
+            self.events.push(PlaybackEvent::StateChanged(next));
+        }
+    }
+
+    fn fail(&mut self, message: &str) {
+        self.loading_since = None;
+        self.pending_is_playing.clear();
+        self.transition(PlaybackState::Error);
+        self.events.push(PlaybackEvent::Error(message.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_status_output_and_state_changes() {
+        assert_eq!(
+            parse_rc_line("> ( state playing )"),
+            Some(RcLine::State(PlaybackState::Playing))
+        );
+        assert_eq!(
+            parse_rc_line("status change: ( play state: 3 ): Pause"),
+            Some(RcLine::State(PlaybackState::Paused))
+        );
+        assert_eq!(
+            parse_rc_line("status change: ( play state: 1 )"),
+            Some(RcLine::State(PlaybackState::Loading))
+        );
+        assert_eq!(
+            parse_rc_line("status change: ( new input: http://example.com/a.mp3 )"),
+            Some(RcLine::NewInput("http://example.com/a.mp3".to_string()))
+        );
+        assert_eq!(parse_rc_line("( audio volume: 256 )"), None);
+        assert_eq!(parse_rc_line("> 1"), Some(RcLine::Reply("1".to_string())));
+        assert_eq!(parse_rc_line("> "), None);
+    }
+
+    #[test]
+    fn parses_now_playing_from_info_block() {
+        assert_eq!(parse_rc_line("+----[ Meta data ]"), None);
+        assert_eq!(parse_rc_line("| title: Groove Salad"), None);
+        assert_eq!(
+            parse_rc_line("| now_playing: Boards of Canada - Dayvan Cowboy"),
+            Some(RcLine::NowPlaying(
+                "Boards of Canada - Dayvan Cowboy".to_string()
+            ))
+        );
+        assert_eq!(parse_rc_line("| now_playing: "), None);
+    }
+
+    #[test]
+    fn stream_title_is_reported_once_per_change() {
+        let mut tracker = RcStatusTracker::new();
+        tracker.begin_loading(Instant::now());
+        tracker.apply_line(RcLine::NowPlaying("Artist - One".to_string()));
+        tracker.apply_line(RcLine::NowPlaying("Artist - One".to_string()));
+        tracker.apply_line(RcLine::NowPlaying("Artist - Two".to_string()));
+
+        assert_eq!(
+            tracker.drain_events(),
+            vec![
+                PlaybackEvent::StreamTitle("Artist - One".to_string()),
+                PlaybackEvent::StreamTitle("Artist - Two".to_string()),
+            ]
+        );
+
+        tracker.begin_loading(Instant::now());
+        tracker.apply_line(RcLine::NowPlaying("Artist - Two".to_string()));
+        assert_eq!(
+            tracker.drain_events(),
+            vec![PlaybackEvent::StreamTitle("Artist - Two".to_string())]
+        );
+    }
+
+    #[test]
+    fn loading_becomes_playing_when_vlc_reports_it() {
+        let mut tracker = RcStatusTracker::new();
+        tracker.begin_loading(Instant::now());
+        tracker.apply_line(RcLine::State(PlaybackState::Stopped));
+        assert_eq!(tracker.state(), PlaybackState::Loading);
+
+        tracker.apply_line(RcLine::State(PlaybackState::Playing));
+        assert_eq!(tracker.state(), PlaybackState::Playing);
+        assert_eq!(
+            tracker.drain_events(),
+            vec![PlaybackEvent::StateChanged(PlaybackState::Playing)]
+        );
+    }
+
+    #[test]
+    fn dead_stream_times_out_into_error() {
+        let start = Instant::now();
+        let mut tracker = RcStatusTracker::new();
+        tracker.begin_loading(start);
+
+        tracker.check_timeouts(start + Duration::from_secs(1));
+        assert_eq!(tracker.state(), PlaybackState::Loading);
+
+        tracker.check_timeouts(start + LOADING_TIMEOUT);
+        assert_eq!(tracker.state(), PlaybackState::Error);
+        let events = tracker.drain_events();
+        assert!(
+            matches!(events.last(), Some(PlaybackEvent::Error(msg)) if msg.contains("did not start"))
+        );
+    }
+
+    #[test]
+    fn is_playing_zero_while_playing_reports_error() {
+        let mut tracker = RcStatusTracker::new();
+        tracker.begin_loading(Instant::now());
+        tracker.apply_line(RcLine::State(PlaybackState::Playing));
+
+        tracker.is_playing_query_sent();
+        tracker.apply_line(RcLine::Reply("0".to_string()));
+        assert_eq!(tracker.state(), PlaybackState::Error);
+    }
+
+    #[test]
+    fn is_playing_zero_while_loading_waits_for_the_timeout() {
+        let start = Instant::now();
+        let mut tracker = RcStatusTracker::new();
+        tracker.begin_loading(start);
+
+        tracker.is_playing_query_sent();
+        tracker.apply_line(RcLine::Reply("0".to_string()));
+        assert_eq!(tracker.state(), PlaybackState::Loading);
+        assert!(tracker.drain_events().is_empty());
+
+        tracker.apply_line(RcLine::State(PlaybackState::Playing));
+        assert_eq!(tracker.state(), PlaybackState::Playing);
+        tracker.check_timeouts(start + LOADING_TIMEOUT);
+        assert_eq!(tracker.state(), PlaybackState::Playing);
+    }
+
+    #[test]
+    fn stale_is_playing_reply_is_ignored_after_user_command() {
+        let mut tracker = RcStatusTracker::new();
+        tracker.begin_loading(Instant::now());
+        tracker.apply_line(RcLine::State(PlaybackState::Playing));
+
+        tracker.is_playing_query_sent();
+        tracker.user_transition(PlaybackState::Paused);
+        tracker.user_transition(PlaybackState::Playing);
+        tracker.apply_line(RcLine::Reply("0".to_string()));
+        assert_eq!(tracker.state(), PlaybackState::Playing);
+    }
+
+    #[test]
+    fn state_lines_after_user_stop_are_ignored() {
+        let mut tracker = RcStatusTracker::new();
+        tracker.begin_loading(Instant::now());
+        tracker.user_transition(PlaybackState::Stopped);
+        tracker.apply_line(RcLine::State(PlaybackState::Playing));
+        tracker.output_closed();
+        assert_eq!(tracker.state(), PlaybackState::Stopped);
+        assert!(tracker.drain_events().is_empty());
+    }
+}
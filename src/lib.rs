@@ -1,5 +1,7 @@
+pub mod cli;
 pub mod domain;
 pub mod integrations;
+pub mod logging;
 pub mod storage;
 pub mod ui;
 
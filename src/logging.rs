@@ -0,0 +1,190 @@
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Context, Result};
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::storage::config::{LogFormat, LoggingConfig};
+
+/// Installs the global subscriber writing to the configured log file. Logs never go to
+/// stdout, which belongs to the TUI.
+pub fn init(debug: bool, config: &LoggingConfig) -> Result<()> {
+    let path = config.path();
+    let writer = RotatingFileWriter::open(
+        &path,
+        config.max_size_mb.saturating_mul(1024 * 1024),
+        config.max_files,
+    )
+    .with_context(|| format!("failed to open log file: {}", path.display()))?;
+
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::new(resolve_log_filter(
+            debug,
+        )))
+        .with_writer(writer)
+        .with_ansi(false);
+    let _ = match config.format {
+        LogFormat::Text => builder.try_init(),
+        LogFormat::Json => builder.json().try_init(),
+    };
+    Ok(())
+}
+
+pub fn resolve_log_filter(debug: bool) -> String {
+    if debug {
+        "iradio=debug".to_string()
+    } else {
+        env::var("RUST_LOG").unwrap_or_else(|_| "iradio=info".to_string())
+    }
+}
+
+/// Appends to `path`, rotating to `path.1` .. `path.N` once the file would exceed `max_bytes`.
+#[derive(Clone)]
+pub struct RotatingFileWriter {
+    inner: Arc<Mutex<RotatingFile>>,
+}
+
+struct RotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFileWriter {
+    pub fn open(path: &Path, max_bytes: u64, max_files: usize) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = open_append(path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            inner: Arc::new(Mutex::new(RotatingFile {
+                path: path.to_path_buf(),
+                max_bytes,
+                max_files,
+                file,
+                size,
+            })),
+        })
+    }
+}
+
+impl RotatingFile {
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_files == 0 {
+            self.file = File::create(&self.path)?;
+        } else {
+            for idx in (1..self.max_files).rev() {
+                let from = rotated_path(&self.path, idx);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, idx + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+            self.file = open_append(&self.path)?;
+        }
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut inner = self
+            .inner
+            .lock()
+            .map_err(|_| io::Error::other("log writer lock poisoned"))?;
+        if inner.max_bytes > 0 && inner.size > 0 && inner.size + buf.len() as u64 > inner.max_bytes
+        {
+            inner.rotate()?;
+        }
+        let written = inner.file.write(buf)?;
+        inner.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut inner = self
+            .inner
+            .lock()
+            .map_err(|_| io::Error::other("log writer lock poisoned"))?;
+        inner.file.flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for RotatingFileWriter {
+    type Writer = RotatingFileWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn rotated_path(path: &Path, idx: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{idx}"));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn debug_flag_forces_debug_filter() {
+        let _guard = ENV_LOCK.lock().expect("lock env");
+        env::set_var("RUST_LOG", "iradio=trace");
+        assert_eq!(resolve_log_filter(true), "iradio=debug");
+        env::remove_var("RUST_LOG");
+    }
+
+    #[test]
+    fn rust_log_is_used_when_debug_is_disabled() {
+        let _guard = ENV_LOCK.lock().expect("lock env");
+        env::set_var("RUST_LOG", "iradio=trace");
+        assert_eq!(resolve_log_filter(false), "iradio=trace");
+        env::remove_var("RUST_LOG");
+    }
+
+    #[test]
+    fn default_log_filter_when_rust_log_absent() {
+        let _guard = ENV_LOCK.lock().expect("lock env");
+        env::remove_var("RUST_LOG");
+        assert_eq!(resolve_log_filter(false), "iradio=info");
+    }
+
+    #[test]
+    fn writer_rotates_by_size_and_keeps_max_files() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let path = dir.path().join("logs/iradio.log");
+        let mut writer = RotatingFileWriter::open(&path, 10, 2).expect("open writer");
+
+        for line in [
+            "first-line\n",
+            "second-line\n",
+            "third-line\n",
+            "fourth-line\n",
+        ] {
+            writer.write_all(line.as_bytes()).expect("write line");
+        }
+        writer.flush().expect("flush");
+
+        let read = |path: PathBuf| fs::read_to_string(path).expect("read log file");
+        assert_eq!(read(path.clone()), "fourth-line\n");
+        assert_eq!(read(rotated_path(&path, 1)), "third-line\n");
+        assert_eq!(read(rotated_path(&path, 2)), "second-line\n");
+        assert!(!rotated_path(&path, 3).exists());
+    }
+}
@@ -1,15 +1,173 @@
+use std::path::PathBuf;
+
 use anyhow::Result;
-use clap::Parser;
-use iradio::app::run;
+use clap::{Args, Parser, Subcommand};
+use iradio::app::{check_config, run, RunOptions};
+use iradio::cli::{self, CliCommand, FavoritesCommand, SearchArgs};
+use iradio::domain::models::{parse_sort_direction, StationFilters, StationSort};
+use iradio::storage::config::LogFormat;
 
 #[derive(Debug, Parser)]
 #[command(name = "iradio", version, about = "Interactive internet radio TUI")]
 struct Cli {
     #[arg(long, help = "Enable verbose debug logs")]
     debug: bool,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write logs to this file instead of the default"
+    )]
+    log_file: Option<PathBuf>,
+    #[arg(long, value_name = "FORMAT", value_parser = LogFormat::parse, help = "Log format: text or json")]
+    log_format: Option<LogFormat>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    #[command(about = "Inspect the config file")]
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommand,
+    },
+    #[command(about = "Search stations and print them (tab-separated, or JSON with --json)")]
+    Search(SearchCli),
+    #[command(
+        about = "Play a station by UUID, name or favorite index",
+        long_about = "Play a station by UUID, name or favorite index.\n\nWith a shared player ([playback] mode = \"mpv\" plus socket, or an attached VLC via rc host/port or http) the stream is handed over and the command exits. The managed cvlc player lives only as long as iradio, so play stays in the foreground printing track titles, and refuses to start without a terminal."
+    )]
+    Play {
+        #[arg(value_name = "UUID|NAME|INDEX", required = true, num_args = 1..)]
+        target: Vec<String>,
+    },
+    #[command(about = "List, add or remove favorites")]
+    Favorites {
+        #[command(subcommand)]
+        action: FavoritesCli,
+    },
+    #[command(
+        about = "Stop the shared player",
+        long_about = "Stop the shared player: the mpv on [playback] socket (mode = \"mpv\"), or the VLC attached via rc host/port or http."
+    )]
+    Stop,
+}
+
+#[derive(Debug, Args)]
+struct SearchCli {
+    #[arg(value_name = "QUERY", num_args = 0.., help = "Station name to search for")]
+    query: Vec<String>,
+    #[arg(long, help = "Country name or code")]
+    country: Option<String>,
+    #[arg(long, help = "Broadcast language")]
+    language: Option<String>,
+    #[arg(long, help = "Station tag")]
+    tag: Option<String>,
+    #[arg(long, help = "Stream codec")]
+    codec: Option<String>,
+    #[arg(long, value_name = "KBPS", help = "Minimum bitrate in kbps")]
+    min_bitrate: Option<u32>,
+    #[arg(
+        long,
+        value_name = "FILTERS",
+        help = "Filters in /filter syntax, e.g. \"tag=jazz,blues codec=mp3|aac https_only\""
+    )]
+    filter: Option<String>,
+    #[arg(long, value_name = "FIELD", value_parser = StationSort::parse, default_value = "votes", help = "name, votes, clicks, bitrate, country, language, codec, lastcheck, changed or random")]
+    sort: StationSort,
+    #[arg(long, value_name = "asc|desc", value_parser = parse_sort_direction, help = "Sort direction; defaults to the field's natural order")]
+    order: Option<bool>,
+    #[arg(
+        long,
+        default_value_t = 20,
+        help = "Maximum number of stations to print"
+    )]
+    limit: usize,
+    #[arg(long, help = "Print stations as a JSON array")]
+    json: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum FavoritesCli {
+    #[command(about = "Print favorites as index, UUID and name")]
+    List {
+        #[arg(long, help = "Print stations as a JSON array")]
+        json: bool,
+    },
+    #[command(about = "Add a station by UUID or name")]
+    Add {
+        #[arg(value_name = "UUID|NAME", required = true, num_args = 1..)]
+        target: Vec<String>,
+    },
+    #[command(about = "Remove a favorite by index, UUID or name")]
+    Remove {
+        #[arg(value_name = "UUID|NAME|INDEX", required = true, num_args = 1..)]
+        target: Vec<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum ConfigCommand {
+    #[command(about = "Report config errors and unknown keys")]
+    Check {
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Config file to check instead of the default"
+        )]
+        path: Option<PathBuf>,
+    },
+}
+
+impl SearchCli {
+    fn into_args(self) -> Result<SearchArgs> {
+        SearchArgs {
+            query: self.query.join(" "),
+            filters: StationFilters {
+                country: self.country,
+                language: self.language,
+                tag: self.tag,
+                codec: self.codec,
+                min_bitrate: self.min_bitrate,
+                ..StationFilters::default()
+            },
+            sort: self.sort,
+            descending: self
+                .order
+                .unwrap_or_else(|| self.sort.descending_by_default()),
+            limit: self.limit,
+            json: self.json,
+        }
+        .with_filter_text(self.filter.as_deref())
+    }
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
-    run(cli.debug)
+    let options = RunOptions {
+        debug: cli.debug,
+        log_file: cli.log_file,
+        log_format: cli.log_format,
+    };
+    let command = match cli.command {
+        Some(Command::Config {
+            action: ConfigCommand::Check { path },
+        }) => return check_config(path),
+        None => return run(options),
+        Some(Command::Search(search)) => CliCommand::Search(Box::new(search.into_args()?)),
+        Some(Command::Play { target }) => CliCommand::Play {
+            target: target.join(" "),
+        },
+        Some(Command::Favorites { action }) => CliCommand::Favorites(match action {
+            FavoritesCli::List { json } => FavoritesCommand::List { json },
+            FavoritesCli::Add { target } => FavoritesCommand::Add {
+                target: target.join(" "),
+            },
+            FavoritesCli::Remove { target } => FavoritesCommand::Remove {
+                target: target.join(" "),
+            },
+        }),
+        Some(Command::Stop) => CliCommand::Stop,
+    };
+    cli::run(command, &options)
 }
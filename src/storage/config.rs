@@ -1,18 +1,36 @@
+use std::collections::BTreeMap;
 use std::env;
+use std::fmt;
 use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, Context, Result};
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use toml_edit::{ImDocument, TableLike};
 
-use crate::domain::models::{StationFilters, StationSort};
+use crate::domain::actions::Action;
+use crate::domain::columns::{Column, DEFAULT_COLUMNS};
+use crate::domain::commands::parse_filters;
+use crate::domain::keymap::{KeyChord, Keymap};
+use crate::domain::models::{parse_sort_direction, StationFilters, StationSort};
+use crate::storage::searches::SavedSearch;
 
 const DEFAULT_RADIO_BROWSER_BASE: &str = "https://de1.api.radio-browser.info";
 const DEFAULT_RADIO_BROWSER_TIMEOUT_MS: u64 = 3_000;
 const DEFAULT_RADIO_BROWSER_RETRIES: usize = 2;
+const DEFAULT_RADIO_BROWSER_SERVERS_URL: &str = "https://all.api.radio-browser.info/json/servers";
+const DEFAULT_VLC_HOST: &str = "127.0.0.1";
+const DEFAULT_VLC_RC_PORT: u16 = 4212;
+const DEFAULT_VLC_HTTP_PORT: u16 = 8080;
+const DEFAULT_CACHE_TTL_HOURS: u64 = 24;
+const DEFAULT_LOG_MAX_SIZE_MB: u64 = 5;
+const DEFAULT_LOG_MAX_FILES: usize = 3;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlaybackMode {
     Rc,
     Http,
+    Mpv,
 }
 
 impl PlaybackMode {
@@ -20,8 +38,9 @@ impl PlaybackMode {
         match value.trim().to_ascii_lowercase().as_str() {
             "rc" => Ok(Self::Rc),
             "http" => Ok(Self::Http),
+            "mpv" => Ok(Self::Mpv),
             _ => Err(anyhow!(
-                "invalid playback mode '{value}' (expected rc or http)"
+                "invalid playback mode '{value}' (expected rc, http or mpv)"
             )),
         }
     }
@@ -30,6 +49,46 @@ impl PlaybackMode {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PlaybackConfig {
     pub mode: PlaybackMode,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub password: Option<String>,
+    /// Fixed mpv IPC socket. mpv started there outlives `iradio play`, and `iradio stop` finds it.
+    pub socket: Option<PathBuf>,
+}
+
+impl PlaybackConfig {
+    /// RC mode only attaches to an external VLC when a host or port is configured.
+    pub fn attaches_to_external_vlc(&self) -> bool {
+        match self.mode {
+            PlaybackMode::Rc => self.host.is_some() || self.port.is_some(),
+            PlaybackMode::Http => true,
+            PlaybackMode::Mpv => false,
+        }
+    }
+
+    /// Whether `iradio play` can leave the player running and `iradio stop` can reach it later.
+    pub fn has_shared_player(&self) -> bool {
+        self.attaches_to_external_vlc() || self.mpv_socket().is_some()
+    }
+
+    /// The configured mpv IPC socket, when mpv is the playback backend.
+    pub fn mpv_socket(&self) -> Option<&Path> {
+        match self.mode {
+            PlaybackMode::Mpv => self.socket.as_deref(),
+            PlaybackMode::Rc | PlaybackMode::Http => None,
+        }
+    }
+
+    pub fn host(&self) -> &str {
+        self.host.as_deref().unwrap_or(DEFAULT_VLC_HOST)
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port.unwrap_or(match self.mode {
+            PlaybackMode::Http => DEFAULT_VLC_HTTP_PORT,
+            PlaybackMode::Rc | PlaybackMode::Mpv => DEFAULT_VLC_RC_PORT,
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,6 +96,11 @@ pub struct RadioBrowserConfig {
     pub base_url: String,
     pub timeout_ms: u64,
     pub retries: usize,
+    /// Fallback servers tried in order when `base_url` fails.
+    pub mirrors: Vec<String>,
+    /// Ask `servers_url` for more mirrors once every configured one has failed.
+    pub discover: bool,
+    pub servers_url: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -45,11 +109,70 @@ pub struct DefaultsConfig {
     pub filters: StationFilters,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UiConfig {
+    /// Results table columns, left to right; trailing ones are hidden on narrow terminals.
+    pub columns: Vec<Column>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheConfig {
+    pub ttl_hours: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(anyhow!(
+                "invalid log format '{value}' (expected text or json)"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoggingConfig {
+    pub file: Option<PathBuf>,
+    pub format: LogFormat,
+    pub max_size_mb: u64,
+    pub max_files: usize,
+}
+
+impl LoggingConfig {
+    /// `$XDG_STATE_HOME/iradio/iradio.log`, falling back to `~/.local/state/iradio/iradio.log`.
+    pub fn default_path() -> PathBuf {
+        let state_dir = env::var("XDG_STATE_HOME")
+            .ok()
+            .filter(|dir| !dir.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home_dir().join(".local/state"));
+        state_dir.join("iradio/iradio.log")
+    }
+
+    pub fn path(&self) -> PathBuf {
+        self.file.clone().unwrap_or_else(Self::default_path)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RuntimeConfig {
     pub playback: PlaybackConfig,
     pub radio_browser: RadioBrowserConfig,
     pub defaults: DefaultsConfig,
+    pub ui: UiConfig,
+    pub cache: CacheConfig,
+    pub logging: LoggingConfig,
+    pub keys: Keymap,
+    /// `[searches.<name>]` presets, offered by `/load` next to saved searches.
+    pub searches: Vec<SavedSearch>,
 }
 
 impl Default for RuntimeConfig {
@@ -57,26 +180,44 @@ impl Default for RuntimeConfig {
         Self {
             playback: PlaybackConfig {
                 mode: PlaybackMode::Rc,
+                host: None,
+                port: None,
+                password: None,
+                socket: None,
             },
             radio_browser: RadioBrowserConfig {
                 base_url: DEFAULT_RADIO_BROWSER_BASE.to_string(),
                 timeout_ms: DEFAULT_RADIO_BROWSER_TIMEOUT_MS,
                 retries: DEFAULT_RADIO_BROWSER_RETRIES,
+                mirrors: Vec::new(),
+                discover: true,
+                servers_url: DEFAULT_RADIO_BROWSER_SERVERS_URL.to_string(),
             },
             defaults: DefaultsConfig {
                 sort: StationSort::default(),
                 filters: StationFilters::default(),
             },
+            ui: UiConfig {
+                columns: DEFAULT_COLUMNS.to_vec(),
+            },
+            cache: CacheConfig {
+                ttl_hours: DEFAULT_CACHE_TTL_HOURS,
+            },
+            logging: LoggingConfig {
+                file: None,
+                format: LogFormat::Text,
+                max_size_mb: DEFAULT_LOG_MAX_SIZE_MB,
+                max_files: DEFAULT_LOG_MAX_FILES,
+            },
+            keys: Keymap::default(),
+            searches: Vec::new(),
         }
     }
 }
 
 impl RuntimeConfig {
     pub fn default_path() -> PathBuf {
-        env::var("HOME")
-            .map(PathBuf::from)
-            .unwrap_or_else(|_| PathBuf::from("."))
-            .join(".config/internet-radio-cli/config.toml")
+        home_dir().join(".config/internet-radio-cli/config.toml")
     }
 
     pub fn load() -> Result<Self> {
@@ -84,15 +225,21 @@ impl RuntimeConfig {
     }
 
     pub fn load_from_path(path: &Path) -> Result<Self> {
+        Self::load_from_path_with_warnings(path).map(|(config, _)| config)
+    }
+
+    /// Like `load_from_path`, also returning problems that did not prevent loading
+    /// (currently unknown keys).
+    pub fn load_from_path_with_warnings(path: &Path) -> Result<(Self, Vec<ConfigWarning>)> {
         let mut config = Self::default();
-        config.merge_file(path)?;
+        let warnings = config.merge_file(path)?;
         config.merge_env()?;
-        Ok(config)
+        Ok((config, warnings))
     }
 
-    fn merge_file(&mut self, path: &Path) -> Result<()> {
+    fn merge_file(&mut self, path: &Path) -> Result<Vec<ConfigWarning>> {
         if !path.exists() {
-            return Ok(());
+            return Ok(Vec::new());
         }
 
         let content = std::fs::read_to_string(path)
@@ -101,72 +248,168 @@ impl RuntimeConfig {
             .with_context(|| format!("failed parsing config TOML: {}", path.display()))
     }
 
-    fn merge_toml_text(&mut self, content: &str) -> Result<()> {
-        let mut section = String::new();
+    fn merge_toml_text(&mut self, content: &str) -> Result<Vec<ConfigWarning>> {
+        let mut unknown_keys = Vec::new();
+        let mut file: ConfigFile =
+            serde_ignored::deserialize(toml::Deserializer::new(content), |path| {
+                unknown_keys.push(key_path(&path));
+            })
+            .map_err(|err| anyhow!("{}", err.to_string().trim_end()))?;
+        let keys = std::mem::take(&mut file.keys);
+        let searches = std::mem::take(&mut file.searches);
+        self.apply_file(file);
+        self.apply_searches(searches)?;
 
-        for (idx, raw_line) in content.lines().enumerate() {
-            let line = strip_comment(raw_line).trim();
-            if line.is_empty() {
-                continue;
-            }
+        let mut warnings: Vec<ConfigWarning> = unknown_keys
+            .into_iter()
+            .map(|keys| ConfigWarning {
+                line: key_line(content, &keys),
+                message: format!("unknown key `{}`", keys.join(".")),
+            })
+            .collect();
+        warnings.extend(self.apply_keys(keys, content)?);
+        Ok(warnings)
+    }
 
-            if line.starts_with('[') {
-                if !line.ends_with(']') {
-                    return Err(anyhow!("line {}: invalid section syntax", idx + 1));
-                }
-                section = line[1..line.len() - 1].trim().to_string();
+    fn apply_keys(
+        &mut self,
+        keys: BTreeMap<String, KeyList>,
+        content: &str,
+    ) -> Result<Vec<ConfigWarning>> {
+        let mut warnings = Vec::new();
+        let mut overrides = Vec::new();
+        for (name, list) in keys {
+            let Some(action) = Action::from_name(&name) else {
+                warnings.push(ConfigWarning {
+                    line: key_line(content, &["keys".to_string(), name.clone()]),
+                    message: format!("unknown key action `keys.{name}`"),
+                });
                 continue;
-            }
-
-            let (key, value_raw) = line
-                .split_once('=')
-                .ok_or_else(|| anyhow!("line {}: expected key=value", idx + 1))?;
-            let key = key.trim();
-            let value = parse_value(value_raw.trim())
-                .with_context(|| format!("line {}: invalid value", idx + 1))?;
-
-            self.apply_file_value(&section, key, value)?;
+            };
+            let chords = list
+                .into_vec()
+                .iter()
+                .map(|chord| KeyChord::parse(chord))
+                .collect::<Result<Vec<_>>>()
+                .with_context(|| format!("invalid [keys] {name}"))?;
+            overrides.push((action, chords));
         }
+        if !overrides.is_empty() {
+            self.keys = Keymap::with_overrides(overrides).context("conflicting [keys] bindings")?;
+        }
+        Ok(warnings)
+    }
 
+    fn apply_searches(&mut self, searches: BTreeMap<String, SearchFile>) -> Result<()> {
+        for (name, search) in searches {
+            let filters = parse_filters(search.filters.as_deref().unwrap_or_default())
+                .with_context(|| format!("invalid [searches.{name}] filters"))?;
+            let sort = search.sort.unwrap_or(self.defaults.sort);
+            self.searches.retain(|existing| existing.name != name);
+            self.searches.push(SavedSearch {
+                name,
+                query: search.query.unwrap_or_default(),
+                filters,
+                sort,
+                descending: search.order.unwrap_or_else(|| sort.descending_by_default()),
+            });
+        }
         Ok(())
     }
 
-    fn apply_file_value(&mut self, section: &str, key: &str, value: TomlValue) -> Result<()> {
-        match (section, key) {
-            ("playback", "mode") => {
-                self.playback.mode = PlaybackMode::parse(value.as_str()?)?;
-            }
-            ("radio_browser", "base_url") => {
-                self.radio_browser.base_url = value.into_string()?;
-            }
-            ("radio_browser", "timeout_ms") => {
-                self.radio_browser.timeout_ms = value.as_u64()?;
-            }
-            ("radio_browser", "retries") => {
-                self.radio_browser.retries = value.as_usize()?;
-            }
-            ("defaults", "sort") => {
-                self.defaults.sort = parse_sort(value.as_str()?)?;
-            }
-            ("defaults.filters", "country") => {
-                self.defaults.filters.country = non_empty(value.into_string()?);
-            }
-            ("defaults.filters", "language") => {
-                self.defaults.filters.language = non_empty(value.into_string()?);
-            }
-            ("defaults.filters", "tag") => {
-                self.defaults.filters.tag = non_empty(value.into_string()?);
-            }
-            ("defaults.filters", "codec") => {
-                self.defaults.filters.codec = non_empty(value.into_string()?);
-            }
-            ("defaults.filters", "min_bitrate") => {
-                self.defaults.filters.min_bitrate = Some(value.as_u32()?);
-            }
-            _ => {}
+    fn apply_file(&mut self, file: ConfigFile) {
+        let ConfigFile {
+            playback,
+            radio_browser,
+            cache,
+            logging,
+            defaults,
+            ui,
+            keys: _,
+            searches: _,
+        } = file;
+
+        if let Some(mode) = playback.mode {
+            self.playback.mode = mode;
+        }
+        if let Some(host) = playback.host {
+            self.playback.host = non_empty(host);
+        }
+        if let Some(port) = playback.port {
+            self.playback.port = Some(port);
+        }
+        if let Some(password) = playback.password {
+            self.playback.password = non_empty(password);
+        }
+        if let Some(socket) = playback.socket {
+            self.playback.socket = non_empty(socket).map(|path| expand_home(&path));
         }
 
-        Ok(())
+        if let Some(base_url) = radio_browser.base_url {
+            self.radio_browser.base_url = base_url;
+        }
+        if let Some(timeout_ms) = radio_browser.timeout_ms {
+            self.radio_browser.timeout_ms = timeout_ms;
+        }
+        if let Some(retries) = radio_browser.retries {
+            self.radio_browser.retries = retries;
+        }
+        if let Some(mirrors) = radio_browser.mirrors {
+            self.radio_browser.mirrors = mirrors;
+        }
+        if let Some(discover) = radio_browser.discover {
+            self.radio_browser.discover = discover;
+        }
+        if let Some(servers_url) = radio_browser.servers_url {
+            self.radio_browser.servers_url = servers_url;
+        }
+
+        if let Some(columns) = ui.columns {
+            self.ui.columns = columns;
+        }
+
+        if let Some(ttl_hours) = cache.ttl_hours {
+            self.cache.ttl_hours = ttl_hours;
+        }
+
+        if let Some(file) = logging.file {
+            self.logging.file = non_empty(file).map(|path| expand_home(&path));
+        }
+        if let Some(format) = logging.format {
+            self.logging.format = format;
+        }
+        if let Some(max_size_mb) = logging.max_size_mb {
+            self.logging.max_size_mb = max_size_mb;
+        }
+        if let Some(max_files) = logging.max_files {
+            self.logging.max_files = max_files;
+        }
+
+        if let Some(sort) = defaults.sort {
+            self.defaults.sort = sort;
+        }
+        let filters = match defaults.filters {
+            DefaultFiltersFile::Fields(filters) => filters,
+            DefaultFiltersFile::Syntax(filters) => {
+                self.defaults.filters = filters;
+                return;
+            }
+        };
+        if let Some(value) = filters.country {
+            self.defaults.filters.country = non_empty(value);
+        }
+        if let Some(value) = filters.language {
+            self.defaults.filters.language = non_empty(value);
+        }
+        if let Some(value) = filters.tag {
+            self.defaults.filters.tag = non_empty(value);
+        }
+        if let Some(value) = filters.codec {
+            self.defaults.filters.codec = non_empty(value);
+        }
+        if let Some(value) = filters.min_bitrate {
+            self.defaults.filters.min_bitrate = Some(value);
+        }
     }
 
     fn merge_env(&mut self) -> Result<()> {
@@ -174,6 +417,21 @@ impl RuntimeConfig {
             self.playback.mode = PlaybackMode::parse(&mode)
                 .with_context(|| "invalid IRADIO_PLAYBACK_MODE".to_string())?;
         }
+        if let Ok(host) = env::var("IRADIO_VLC_HOST") {
+            self.playback.host = non_empty(host);
+        }
+        if let Ok(port) = env::var("IRADIO_VLC_PORT") {
+            self.playback.port = Some(
+                port.parse::<u16>()
+                    .with_context(|| "invalid IRADIO_VLC_PORT".to_string())?,
+            );
+        }
+        if let Ok(password) = env::var("IRADIO_VLC_HTTP_PASSWORD") {
+            self.playback.password = non_empty(password);
+        }
+        if let Ok(socket) = env::var("IRADIO_MPV_SOCKET") {
+            self.playback.socket = non_empty(socket).map(|path| expand_home(&path));
+        }
 
         if let Ok(base_url) = env::var("IRADIO_RADIO_BROWSER_BASE") {
             self.radio_browser.base_url = base_url;
@@ -188,10 +446,43 @@ impl RuntimeConfig {
                 .parse::<usize>()
                 .with_context(|| "invalid IRADIO_RADIO_BROWSER_MAX_RETRIES".to_string())?;
         }
+        if let Ok(mirrors) = env::var("IRADIO_RADIO_BROWSER_MIRRORS") {
+            self.radio_browser.mirrors = mirrors
+                .split(',')
+                .map(str::trim)
+                .filter(|mirror| !mirror.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+        if let Ok(discover) = env::var("IRADIO_RADIO_BROWSER_DISCOVER") {
+            self.radio_browser.discover = discover
+                .parse::<bool>()
+                .with_context(|| "invalid IRADIO_RADIO_BROWSER_DISCOVER".to_string())?;
+        }
+
+        if let Ok(columns) = env::var("IRADIO_UI_COLUMNS") {
+            let names: Vec<&str> = columns.split(',').collect();
+            self.ui.columns =
+                parse_columns(&names).with_context(|| "invalid IRADIO_UI_COLUMNS".to_string())?;
+        }
+
+        if let Ok(ttl_hours) = env::var("IRADIO_CACHE_TTL_HOURS") {
+            self.cache.ttl_hours = ttl_hours
+                .parse::<u64>()
+                .with_context(|| "invalid IRADIO_CACHE_TTL_HOURS".to_string())?;
+        }
+
+        if let Ok(file) = env::var("IRADIO_LOG_FILE") {
+            self.logging.file = non_empty(file).map(|path| expand_home(&path));
+        }
+        if let Ok(format) = env::var("IRADIO_LOG_FORMAT") {
+            self.logging.format = LogFormat::parse(&format)
+                .with_context(|| "invalid IRADIO_LOG_FORMAT".to_string())?;
+        }
 
         if let Ok(sort) = env::var("IRADIO_DEFAULT_SORT") {
-            self.defaults.sort =
-                parse_sort(&sort).with_context(|| "invalid IRADIO_DEFAULT_SORT".to_string())?;
+            self.defaults.sort = StationSort::parse(&sort)
+                .with_context(|| "invalid IRADIO_DEFAULT_SORT".to_string())?;
         }
         if let Ok(value) = env::var("IRADIO_DEFAULT_FILTER_COUNTRY") {
             self.defaults.filters.country = non_empty(value);
@@ -217,15 +508,31 @@ impl RuntimeConfig {
     }
 }
 
-fn parse_sort(value: &str) -> Result<StationSort> {
-    match value.trim().to_ascii_lowercase().as_str() {
-        "name" => Ok(StationSort::Name),
-        "votes" => Ok(StationSort::Votes),
-        "clicks" => Ok(StationSort::Clicks),
-        "bitrate" => Ok(StationSort::Bitrate),
-        _ => Err(anyhow!(
-            "invalid sort '{value}' (expected name, votes, clicks, bitrate)"
-        )),
+fn parse_columns<S: AsRef<str>>(names: &[S]) -> Result<Vec<Column>> {
+    let mut columns = Vec::new();
+    for name in names {
+        let column = Column::parse(name.as_ref())?;
+        if columns.contains(&column) {
+            return Err(anyhow!("column '{}' is listed twice", column.name()));
+        }
+        columns.push(column);
+    }
+    if columns.is_empty() {
+        return Err(anyhow!("list at least one column, e.g. [\"name\"]"));
+    }
+    Ok(columns)
+}
+
+fn home_dir() -> PathBuf {
+    env::var("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("."))
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => home_dir().join(rest),
+        None => PathBuf::from(path),
     }
 }
 
@@ -237,71 +544,235 @@ fn non_empty(value: String) -> Option<String> {
     }
 }
 
-fn strip_comment(line: &str) -> &str {
-    let mut in_quotes = false;
-    for (idx, ch) in line.char_indices() {
-        match ch {
-            '"' => in_quotes = !in_quotes,
-            '#' if !in_quotes => return &line[..idx],
-            _ => {}
+/// A config problem that does not stop iradio from starting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.message),
+            None => f.write_str(&self.message),
         }
     }
-    line
 }
 
-#[derive(Debug, Clone)]
-enum TomlValue {
-    String(String),
-    Integer(u64),
+// On-disk shape of config.toml. Every field is optional so the file only overrides what it sets.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    playback: PlaybackFile,
+    radio_browser: RadioBrowserFile,
+    cache: CacheFile,
+    logging: LoggingFile,
+    defaults: DefaultsFile,
+    ui: UiFile,
+    keys: BTreeMap<String, KeyList>,
+    searches: BTreeMap<String, SearchFile>,
 }
 
-impl TomlValue {
-    fn as_str(&self) -> Result<&str> {
-        match self {
-            Self::String(value) => Ok(value.as_str()),
-            Self::Integer(_) => Err(anyhow!("expected string value")),
-        }
-    }
+/// `quit = "ctrl+q"` or `select_next = ["j", "down"]`; an empty list unbinds the action.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
 
-    fn into_string(self) -> Result<String> {
+impl KeyList {
+    fn into_vec(self) -> Vec<String> {
         match self {
-            Self::String(value) => Ok(value),
-            Self::Integer(_) => Err(anyhow!("expected string value")),
+            Self::One(chord) => vec![chord],
+            Self::Many(chords) => chords,
         }
     }
+}
 
-    fn as_u64(&self) -> Result<u64> {
-        match self {
-            Self::Integer(value) => Ok(*value),
-            Self::String(_) => Err(anyhow!("expected integer value")),
-        }
-    }
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PlaybackFile {
+    #[serde(deserialize_with = "de_playback_mode")]
+    mode: Option<PlaybackMode>,
+    host: Option<String>,
+    port: Option<u16>,
+    password: Option<String>,
+    socket: Option<String>,
+}
 
-    fn as_u32(&self) -> Result<u32> {
-        let value = self.as_u64()?;
-        u32::try_from(value).map_err(|_| anyhow!("integer value is out of range for u32"))
-    }
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RadioBrowserFile {
+    base_url: Option<String>,
+    timeout_ms: Option<u64>,
+    retries: Option<usize>,
+    mirrors: Option<Vec<String>>,
+    discover: Option<bool>,
+    servers_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct UiFile {
+    #[serde(deserialize_with = "de_columns")]
+    columns: Option<Vec<Column>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CacheFile {
+    ttl_hours: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct LoggingFile {
+    file: Option<String>,
+    #[serde(deserialize_with = "de_log_format")]
+    format: Option<LogFormat>,
+    max_size_mb: Option<u64>,
+    max_files: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DefaultsFile {
+    #[serde(deserialize_with = "de_sort")]
+    sort: Option<StationSort>,
+    filters: DefaultFiltersFile,
+}
+
+/// A preset; `filters` uses `/filter` syntax, e.g. `"tag=jazz,blues https_only"`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SearchFile {
+    query: Option<String>,
+    filters: Option<String>,
+    #[serde(deserialize_with = "de_sort")]
+    sort: Option<StationSort>,
+    #[serde(deserialize_with = "de_sort_direction")]
+    order: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct FiltersFile {
+    country: Option<String>,
+    language: Option<String>,
+    tag: Option<String>,
+    codec: Option<String>,
+    min_bitrate: Option<u32>,
+}
 
-    fn as_usize(&self) -> Result<usize> {
-        let value = self.as_u64()?;
-        usize::try_from(value).map_err(|_| anyhow!("integer value is out of range for usize"))
+/// `[defaults.filters]` as a table of single values, or `filters = "..."` in `/filter` syntax,
+/// which also covers multi-value, negated and flag filters.
+#[derive(Debug)]
+enum DefaultFiltersFile {
+    Fields(FiltersFile),
+    Syntax(StationFilters),
+}
+
+impl Default for DefaultFiltersFile {
+    fn default() -> Self {
+        Self::Fields(FiltersFile::default())
     }
 }
 
-fn parse_value(value: &str) -> Result<TomlValue> {
-    let trimmed = value.trim();
-    if trimmed.starts_with('"') {
-        if !trimmed.ends_with('"') || trimmed.len() < 2 {
-            return Err(anyhow!("unterminated string"));
+impl<'de> Deserialize<'de> for DefaultFiltersFile {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FiltersVisitor;
+
+        impl<'de> de::Visitor<'de> for FiltersVisitor {
+            type Value = DefaultFiltersFile;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a table of filters or a string in /filter syntax")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                parse_filters(value)
+                    .map(DefaultFiltersFile::Syntax)
+                    .map_err(|err| E::custom(format!("{err:#}")))
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                FiltersFile::deserialize(de::value::MapAccessDeserializer::new(map))
+                    .map(DefaultFiltersFile::Fields)
+            }
         }
-        return Ok(TomlValue::String(trimmed[1..trimmed.len() - 1].to_string()));
+
+        deserializer.deserialize_any(FiltersVisitor)
     }
+}
+
+fn de_playback_mode<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<PlaybackMode>, D::Error> {
+    de_parsed(deserializer, PlaybackMode::parse)
+}
+
+fn de_log_format<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<LogFormat>, D::Error> {
+    de_parsed(deserializer, LogFormat::parse)
+}
+
+fn de_sort<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<StationSort>, D::Error> {
+    de_parsed(deserializer, StationSort::parse)
+}
+
+fn de_columns<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<Column>>, D::Error> {
+    let names = Vec::<String>::deserialize(deserializer)?;
+    parse_columns(&names)
+        .map(Some)
+        .map_err(|err| de::Error::custom(format!("{err:#}")))
+}
 
-    if let Ok(number) = trimmed.parse::<u64>() {
-        return Ok(TomlValue::Integer(number));
+fn de_sort_direction<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+    de_parsed(deserializer, parse_sort_direction)
+}
+
+fn de_parsed<'de, D, T>(
+    deserializer: D,
+    parse: fn(&str) -> Result<T>,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    parse(&value)
+        .map(Some)
+        .map_err(|err| de::Error::custom(format!("{err:#}")))
+}
+
+fn key_path(path: &serde_ignored::Path<'_>) -> Vec<String> {
+    let mut keys = match path {
+        serde_ignored::Path::Root => return Vec::new(),
+        serde_ignored::Path::Seq { parent, .. }
+        | serde_ignored::Path::Map { parent, .. }
+        | serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => key_path(parent),
+    };
+    if let serde_ignored::Path::Map { key, .. } = path {
+        keys.push(key.clone());
     }
+    keys
+}
 
-    Ok(TomlValue::String(trimmed.to_string()))
+/// 1-based line of the dotted key `keys`, found by re-parsing with spans.
+fn key_line(content: &str, keys: &[String]) -> Option<usize> {
+    let document = ImDocument::parse(content).ok()?;
+    let (last, parents) = keys.split_last()?;
+    let mut table: &dyn TableLike = document.as_table();
+    for key in parents {
+        table = table.get(key)?.as_table_like()?;
+    }
+    let (key, _) = table.get_key_value(last)?;
+    let start = key.span()?.start;
+    Some(content[..start].matches('\n').count() + 1)
 }
 
 #[cfg(test)]
@@ -321,10 +792,15 @@ mod tests {
                     base_url = "https://example.radio.browser"
                     timeout_ms = 4500
                     retries = 4
+                    mirrors = ["https://fi1.api.radio-browser.info", "https://at1.api.radio-browser.info/"]
+                    discover = false
 
                     [defaults]
                     sort = "bitrate"
 
+                    [cache]
+                    ttl_hours = 6
+
                     [defaults.filters]
                     country = "US"
                     language = "english"
@@ -342,7 +818,16 @@ mod tests {
         );
         assert_eq!(config.radio_browser.timeout_ms, 4500);
         assert_eq!(config.radio_browser.retries, 4);
+        assert_eq!(
+            config.radio_browser.mirrors,
+            vec![
+                "https://fi1.api.radio-browser.info".to_string(),
+                "https://at1.api.radio-browser.info/".to_string(),
+            ]
+        );
+        assert!(!config.radio_browser.discover);
         assert_eq!(config.defaults.sort, StationSort::Bitrate);
+        assert_eq!(config.cache.ttl_hours, 6);
         assert_eq!(config.defaults.filters.country.as_deref(), Some("US"));
         assert_eq!(config.defaults.filters.language.as_deref(), Some("english"));
         assert_eq!(config.defaults.filters.tag.as_deref(), Some("jazz"));
@@ -363,4 +848,313 @@ mod tests {
             .expect_err("invalid sort should fail");
         assert!(err.to_string().contains("invalid sort"));
     }
+
+    #[test]
+    fn parses_playback_connection_settings() {
+        let mut config = RuntimeConfig::default();
+        assert!(!config.playback.attaches_to_external_vlc());
+
+        config
+            .merge_toml_text(
+                r#"
+                    [playback]
+                    mode = "rc"
+                    host = "10.0.0.5"
+                    port = 4300
+                "#,
+            )
+            .expect("merge config text");
+
+        assert!(config.playback.attaches_to_external_vlc());
+        assert_eq!(config.playback.host(), "10.0.0.5");
+        assert_eq!(config.playback.port(), 4300);
+    }
+
+    #[test]
+    fn mpv_socket_makes_the_player_shared() {
+        let mut config = RuntimeConfig::default();
+        assert!(!config.playback.has_shared_player());
+
+        config
+            .merge_toml_text(
+                r#"
+                    [playback]
+                    mode = "mpv"
+                    socket = "/run/user/1000/iradio-mpv.sock"
+                "#,
+            )
+            .expect("merge config text");
+
+        assert!(config.playback.has_shared_player());
+        assert_eq!(
+            config.playback.mpv_socket(),
+            Some(Path::new("/run/user/1000/iradio-mpv.sock"))
+        );
+
+        config.playback.mode = PlaybackMode::Rc;
+        assert_eq!(config.playback.mpv_socket(), None);
+        assert!(!config.playback.has_shared_player());
+    }
+
+    #[test]
+    fn playback_port_defaults_follow_mode() {
+        let mut config = RuntimeConfig::default();
+        assert_eq!(config.playback.port(), DEFAULT_VLC_RC_PORT);
+
+        config
+            .merge_toml_text(
+                r#"
+                    [playback]
+                    mode = "http"
+                    password = "secret"
+                "#,
+            )
+            .expect("merge config text");
+
+        assert_eq!(config.playback.host(), DEFAULT_VLC_HOST);
+        assert_eq!(config.playback.port(), DEFAULT_VLC_HTTP_PORT);
+        assert_eq!(config.playback.password.as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn parses_logging_settings() {
+        let mut config = RuntimeConfig::default();
+        assert_eq!(config.logging.format, LogFormat::Text);
+        assert!(config.logging.path().ends_with("iradio/iradio.log"));
+
+        config
+            .merge_toml_text(
+                r#"
+                    [logging]
+                    file = "/var/tmp/radio.log"
+                    format = "json"
+                    max_size_mb = 1
+                    max_files = 5
+                "#,
+            )
+            .expect("merge config text");
+
+        assert_eq!(config.logging.path(), PathBuf::from("/var/tmp/radio.log"));
+        assert_eq!(config.logging.format, LogFormat::Json);
+        assert_eq!(config.logging.max_size_mb, 1);
+        assert_eq!(config.logging.max_files, 5);
+    }
+
+    #[test]
+    fn default_filters_accept_filter_syntax() {
+        let mut config = RuntimeConfig::default();
+        config
+            .merge_toml_text(
+                r#"
+[defaults]
+filters = "country=US|GB tag=jazz,blues tag!=talk max_bitrate=320 https_only is_working"
+"#,
+            )
+            .expect("merge config text");
+        assert_eq!(
+            config.defaults.filters,
+            parse_filters(
+                "country=US|GB tag=jazz,blues tag!=talk max_bitrate=320 https_only is_working"
+            )
+            .expect("parse filters")
+        );
+
+        let err = RuntimeConfig::default()
+            .merge_toml_text("[defaults]\nfilters = \"genre=jazz\"\n")
+            .expect_err("unknown filter key");
+        assert!(format!("{err:#}").contains("genre"), "{err:#}");
+    }
+
+    #[test]
+    fn unknown_keys_warn_with_line_numbers() {
+        let mut config = RuntimeConfig::default();
+        let warnings = config
+            .merge_toml_text(
+                r#"
+[radio_browser]
+retires = 3
+timeout_ms = 1000
+
+[defaults.filters]
+genre = "jazz"
+
+[extra]
+enabled = true
+"#,
+            )
+            .expect("unknown keys are not fatal");
+
+        assert_eq!(config.radio_browser.timeout_ms, 1000);
+        let rendered: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rendered,
+            [
+                "line 3: unknown key `radio_browser.retires`",
+                "line 7: unknown key `defaults.filters.genre`",
+                "line 9: unknown key `extra`",
+            ]
+        );
+    }
+
+    #[test]
+    fn full_toml_syntax_is_accepted() {
+        let mut config = RuntimeConfig::default();
+        let warnings = config
+            .merge_toml_text(
+                r#"
+                    playback = { mode = "mpv", port = 4400 }
+                    defaults.sort = 'name' # literal string and dotted key
+                    radio_browser.base_url = """https://multi.line"""
+                "#,
+            )
+            .expect("merge config text");
+
+        assert!(warnings.is_empty());
+        assert_eq!(config.playback.mode, PlaybackMode::Mpv);
+        assert_eq!(config.playback.port, Some(4400));
+        assert_eq!(config.defaults.sort, StationSort::Name);
+        assert_eq!(config.radio_browser.base_url, "https://multi.line");
+    }
+
+    #[test]
+    fn type_errors_report_the_line() {
+        let mut config = RuntimeConfig::default();
+        let err = config
+            .merge_toml_text("[cache]\nttl_hours = \"soon\"\n")
+            .expect_err("string ttl should fail");
+        assert!(format!("{err:#}").contains("line 2"), "{err:#}");
+    }
+
+    #[test]
+    fn keys_section_overrides_bindings() {
+        let mut config = RuntimeConfig::default();
+        let warnings = config
+            .merge_toml_text(
+                r#"
+[keys]
+quit = "ctrl+q"
+select_next = ["n", "down"]
+toggle_favorite = []
+jump = "x"
+"#,
+            )
+            .expect("merge config text");
+
+        let chord = |value: &str| KeyChord::parse(value).expect("parse chord");
+        assert_eq!(config.keys.action_for(&chord("ctrl+q")), Some(Action::Quit));
+        assert_eq!(config.keys.action_for(&chord("q")), None);
+        assert_eq!(
+            config.keys.action_for(&chord("n")),
+            Some(Action::SelectNext)
+        );
+        assert_eq!(config.keys.action_for(&chord("f")), None);
+        assert_eq!(
+            warnings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["line 6: unknown key action `keys.jump`"]
+        );
+    }
+
+    #[test]
+    fn conflicting_keys_are_rejected_at_load() {
+        let mut config = RuntimeConfig::default();
+        let err = config
+            .merge_toml_text("[keys]\ntoggle_favorite = \"s\"\n")
+            .expect_err("s is already bound to stop");
+        assert!(
+            format!("{err:#}").contains("s is bound to both stop and toggle-favorite"),
+            "{err:#}"
+        );
+
+        let err = config
+            .merge_toml_text("[keys]\nquit = \"enter\"\n")
+            .expect_err("enter is reserved");
+        assert!(format!("{err:#}").contains("Enter is reserved"), "{err:#}");
+    }
+
+    #[test]
+    fn searches_section_defines_presets() {
+        let mut config = RuntimeConfig::default();
+        let warnings = config
+            .merge_toml_text(
+                r#"
+[defaults]
+sort = "clicks"
+
+[searches.jazz]
+filters = "tag=jazz,blues tag!=talk https_only"
+sort = "bitrate"
+order = "asc"
+
+[searches."bbc news"]
+query = "bbc"
+"#,
+            )
+            .expect("merge config text");
+
+        assert!(warnings.is_empty());
+        let names: Vec<&str> = config.searches.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["bbc news", "jazz"]);
+        assert_eq!(config.searches[0].query, "bbc");
+        assert_eq!(config.searches[0].sort, StationSort::Clicks);
+        assert!(config.searches[0].descending);
+        assert_eq!(config.searches[1].sort, StationSort::Bitrate);
+        assert!(!config.searches[1].descending);
+        assert_eq!(
+            config.searches[1].filters.to_string(),
+            "tag=jazz,blues tag!=talk https_only"
+        );
+
+        let err = config
+            .merge_toml_text("[searches.bad]\nfilters = \"genre=jazz\"\n")
+            .expect_err("unknown filter key");
+        let message = format!("{err:#}");
+        assert!(
+            message.contains("invalid [searches.bad] filters"),
+            "{message}"
+        );
+        assert!(message.contains("unknown filter key: genre"), "{message}");
+    }
+
+    #[test]
+    fn ui_columns_are_parsed_and_validated() {
+        let mut config = RuntimeConfig::default();
+        assert_eq!(config.ui.columns, DEFAULT_COLUMNS.to_vec());
+        config
+            .merge_toml_text(
+                r#"
+                    [ui]
+                    columns = ["name", "Bitrate", "clicks"]
+                "#,
+            )
+            .expect("parse ui columns");
+        assert_eq!(
+            config.ui.columns,
+            vec![Column::Name, Column::Bitrate, Column::Clicks]
+        );
+
+        let err = config
+            .merge_toml_text("[ui]\ncolumns = [\"name\", \"genre\"]\n")
+            .expect_err("unknown column");
+        assert!(err.to_string().contains("unknown column 'genre'"), "{err}");
+        let err = config
+            .merge_toml_text("[ui]\ncolumns = [\"votes\", \"votes\"]\n")
+            .expect_err("duplicate column");
+        assert!(err.to_string().contains("listed twice"), "{err}");
+    }
+
+    #[test]
+    fn out_of_range_playback_port_is_rejected() {
+        let mut config = RuntimeConfig::default();
+        let err = config
+            .merge_toml_text(
+                r#"
+                    [playback]
+                    port = 70000
+                "#,
+            )
+            .expect_err("port above u16 range should fail");
+        let message = format!("{err:#}");
+        assert!(message.contains("line 3"), "{message}");
+        assert!(message.contains("u16"), "{message}");
+    }
 }
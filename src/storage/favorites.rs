@@ -15,6 +15,10 @@ impl FavoritesStore {
         }
     }
 
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     pub fn load(&self) -> Result<Vec<String>> {
         if !self.path.exists() {
             return Ok(Vec::new());
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::unix_now;
+
+pub const HISTORY_FILE_NAME: &str = "history.json";
+pub const MAX_HISTORY_ENTRIES: usize = 1_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub station_uuid: String,
+    pub station_name: String,
+    pub title: String,
+    /// Seconds since the Unix epoch (UTC).
+    pub played_at: u64,
+}
+
+impl HistoryEntry {
+    pub fn new(
+        station_uuid: impl Into<String>,
+        station_name: impl Into<String>,
+        title: impl Into<String>,
+    ) -> Self {
+        Self {
+            station_uuid: station_uuid.into(),
+            station_name: station_name.into(),
+            title: title.into(),
+            played_at: unix_now(),
+        }
+    }
+
+    /// `YYYY-MM-DD HH:MM` in UTC.
+    pub fn played_at_label(&self) -> String {
+        let days = (self.played_at / 86_400) as i64;
+        let seconds_of_day = self.played_at % 86_400;
+        let (year, month, day) = civil_from_days(days);
+        format!(
+            "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+            seconds_of_day / 3_600,
+            (seconds_of_day % 3_600) / 60
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    path: PathBuf,
+}
+
+impl HistoryStore {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// History lives next to `favorites.json`.
+    pub fn beside(favorites_path: &Path) -> Self {
+        let dir = favorites_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::new(dir.join(HISTORY_FILE_NAME))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> Result<Vec<HistoryEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("failed to read history file: {}", self.path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse history file: {}", self.path.display()))
+    }
+
+    pub fn save(&self, entries: &[HistoryEntry]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("failed to create history directory: {}", parent.display())
+            })?;
+        }
+
+        let start = entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        let body = serde_json::to_string_pretty(&entries[start..])
+            .context("failed to serialize history")?;
+        fs::write(&self.path, body)
+            .with_context(|| format!("failed to write history file: {}", self.path.display()))?;
+
+        Ok(())
+    }
+}
+
+// Howard Hinnant's days-to-civil algorithm; avoids pulling in a date crate for one label.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry_at(played_at: u64) -> HistoryEntry {
+        HistoryEntry {
+            station_uuid: "id".to_string(),
+            station_name: "Station".to_string(),
+            title: "Song".to_string(),
+            played_at,
+        }
+    }
+
+    #[test]
+    fn played_at_label_formats_utc() {
+        assert_eq!(entry_at(0).played_at_label(), "1970-01-01 00:00");
+        assert_eq!(entry_at(951_782_400).played_at_label(), "2000-02-29 00:00");
+        assert_eq!(
+            entry_at(1_700_000_000).played_at_label(),
+            "2023-11-14 22:13"
+        );
+    }
+
+    #[test]
+    fn history_path_is_sibling_of_favorites() {
+        let store = HistoryStore::beside(Path::new("/tmp/iradio/favorites.json"));
+        assert_eq!(store.path(), Path::new("/tmp/iradio/history.json"));
+    }
+}
@@ -1,2 +1,15 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 pub mod config;
 pub mod favorites;
+pub mod history;
+pub mod searches;
+pub mod station_cache;
+
+/// Seconds since the Unix epoch, used for persisted timestamps.
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,256 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::commands::parse_filters;
+use crate::domain::models::{parse_sort_direction, StationFilters, StationSort};
+
+pub const SEARCHES_FILE_NAME: &str = "searches.json";
+
+/// A named query, filter set and sort restored by `/load`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "SearchRecord", into = "SearchRecord")]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+    pub filters: StationFilters,
+    pub sort: StationSort,
+    pub descending: bool,
+}
+
+impl SavedSearch {
+    /// `query "bbc" | tag=jazz | sort votes`, for listings.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.query.is_empty() {
+            parts.push(format!("query \"{}\"", self.query));
+        }
+        if !self.filters.is_empty() {
+            parts.push(self.filters.to_string());
+        }
+        if self.descending == self.sort.descending_by_default() {
+            parts.push(format!("sort {}", self.sort.name()));
+        } else {
+            parts.push(format!("sort {}", self.sort.label(self.descending)));
+        }
+        parts.join(" | ")
+    }
+}
+
+// On-disk shape: filters are kept in `/filter` syntax so the file stays hand-editable.
+#[derive(Serialize, Deserialize)]
+struct SearchRecord {
+    name: String,
+    #[serde(default)]
+    query: String,
+    #[serde(default)]
+    filters: String,
+    #[serde(default)]
+    sort: StationSort,
+    /// `asc` or `desc`; the sort field's default direction when missing.
+    #[serde(default)]
+    order: Option<String>,
+}
+
+impl TryFrom<SearchRecord> for SavedSearch {
+    type Error = anyhow::Error;
+
+    fn try_from(record: SearchRecord) -> Result<Self> {
+        let filters = parse_filters(&record.filters)
+            .with_context(|| format!("invalid filters for saved search '{}'", record.name))?;
+        let descending = match record.order.as_deref() {
+            Some(order) => parse_sort_direction(order)
+                .with_context(|| format!("invalid order for saved search '{}'", record.name))?,
+            None => record.sort.descending_by_default(),
+        };
+        Ok(Self {
+            name: record.name,
+            query: record.query,
+            filters,
+            sort: record.sort,
+            descending,
+        })
+    }
+}
+
+impl From<SavedSearch> for SearchRecord {
+    fn from(search: SavedSearch) -> Self {
+        Self {
+            name: search.name,
+            query: search.query,
+            filters: search.filters.to_string(),
+            sort: search.sort,
+            order: Some(if search.descending { "desc" } else { "asc" }.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchesStore {
+    path: PathBuf,
+}
+
+impl SearchesStore {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Saved searches live next to `favorites.json`.
+    pub fn beside(favorites_path: &Path) -> Self {
+        let dir = favorites_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::new(dir.join(SEARCHES_FILE_NAME))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> Result<Vec<SavedSearch>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path).with_context(|| {
+            format!(
+                "failed to read saved searches file: {}",
+                self.path.display()
+            )
+        })?;
+        serde_json::from_str(&content).with_context(|| {
+            format!(
+                "failed to parse saved searches file: {}",
+                self.path.display()
+            )
+        })
+    }
+
+    /// Like `load`, but entries that do not parse (a bad `filters` string, an unknown sort)
+    /// are skipped and described in the returned warnings.
+    pub fn load_with_warnings(&self) -> Result<(Vec<SavedSearch>, Vec<String>)> {
+        if !self.path.exists() {
+            return Ok((Vec::new(), Vec::new()));
+        }
+
+        let content = fs::read_to_string(&self.path).with_context(|| {
+            format!(
+                "failed to read saved searches file: {}",
+                self.path.display()
+            )
+        })?;
+        let records: Vec<serde_json::Value> =
+            serde_json::from_str(&content).with_context(|| {
+                format!(
+                    "failed to parse saved searches file: {}",
+                    self.path.display()
+                )
+            })?;
+
+        let mut searches = Vec::new();
+        let mut warnings = Vec::new();
+        for (index, record) in records.into_iter().enumerate() {
+            match serde_json::from_value::<SavedSearch>(record) {
+                Ok(search) => searches.push(search),
+                Err(err) => warnings.push(format!("skipped saved search #{}: {err}", index + 1)),
+            }
+        }
+        Ok((searches, warnings))
+    }
+
+    pub fn save(&self, searches: &[SavedSearch]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "failed to create saved searches directory: {}",
+                    parent.display()
+                )
+            })?;
+        }
+
+        let body =
+            serde_json::to_string_pretty(searches).context("failed to serialize saved searches")?;
+        fs::write(&self.path, body).with_context(|| {
+            format!(
+                "failed to write saved searches file: {}",
+                self.path.display()
+            )
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_searches_round_trip_through_filter_syntax() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let store = SearchesStore::beside(&dir.path().join("favorites.json"));
+        let search = SavedSearch {
+            name: "late jazz".to_string(),
+            query: "fm".to_string(),
+            filters: StationFilters {
+                tag: Some("jazz,blues".to_string()),
+                exclude_tag: Some("talk".to_string()),
+                min_bitrate: Some(128),
+                https_only: true,
+                ..StationFilters::default()
+            },
+            sort: StationSort::Bitrate,
+            descending: false,
+        };
+        assert_eq!(
+            search.summary(),
+            "query \"fm\" | tag=jazz,blues tag!=talk min_bitrate=128 https_only | sort bitrate asc"
+        );
+
+        store.save(std::slice::from_ref(&search)).expect("save");
+        let body = fs::read_to_string(store.path()).expect("read file");
+        assert!(
+            body.contains("\"filters\": \"tag=jazz,blues tag!=talk min_bitrate=128 https_only\"")
+        );
+        assert!(body.contains("\"sort\": \"bitrate\""));
+        assert!(body.contains("\"order\": \"asc\""));
+        assert_eq!(store.load().expect("load"), vec![search]);
+    }
+
+    #[test]
+    fn invalid_filters_name_the_search() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let store = SearchesStore::new(dir.path().join(SEARCHES_FILE_NAME));
+        fs::write(
+            store.path(),
+            r#"[{"name": "odd", "filters": "genre=jazz"}]"#,
+        )
+        .expect("write");
+
+        let err = store.load().expect_err("unknown filter key");
+        assert!(format!("{err:#}").contains("invalid filters for saved search 'odd'"));
+    }
+
+    #[test]
+    fn invalid_entries_are_skipped_with_a_warning() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let store = SearchesStore::new(dir.path().join(SEARCHES_FILE_NAME));
+        fs::write(
+            store.path(),
+            r#"[{"name": "odd", "filters": "genre=jazz"}, {"name": "jazz", "filters": "tag=jazz"}]"#,
+        )
+        .expect("write");
+
+        let (searches, warnings) = store.load_with_warnings().expect("load");
+        assert_eq!(searches.len(), 1);
+        assert_eq!(searches[0].name, "jazz");
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].contains("saved search #1")
+                && warnings[0].contains("invalid filters for saved search 'odd'"),
+            "{warnings:?}"
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::Station;
+
+use super::unix_now;
+
+pub const STATION_CACHE_FILE_NAME: &str = "stations.json";
+pub const DEFAULT_STATION_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const MAX_CACHED_STATIONS: usize = 5_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CachedStation {
+    pub station: Station,
+    /// Seconds since the Unix epoch when the catalog last returned this station.
+    pub fetched_at: u64,
+}
+
+/// Station metadata keyed by UUID, persisted so favorites render offline and at startup.
+#[derive(Debug, Clone)]
+pub struct StationCache {
+    path: PathBuf,
+    ttl: Duration,
+    entries: HashMap<String, CachedStation>,
+    /// Set when an entry changed in a way worth writing to disk.
+    dirty: bool,
+}
+
+impl StationCache {
+    pub fn new(path: impl AsRef<Path>, ttl: Duration) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            ttl,
+            entries: HashMap::new(),
+            dirty: false,
+        }
+    }
+
+    /// The cache lives next to `favorites.json`.
+    pub fn beside(favorites_path: &Path, ttl: Duration) -> Self {
+        let dir = favorites_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::new(dir.join(STATION_CACHE_FILE_NAME), ttl)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&mut self) -> Result<()> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+
+        let content = fs::read_to_string(&self.path).with_context(|| {
+            format!("failed to read station cache file: {}", self.path.display())
+        })?;
+        let entries: Vec<CachedStation> = serde_json::from_str(&content).with_context(|| {
+            format!(
+                "failed to parse station cache file: {}",
+                self.path.display()
+            )
+        })?;
+        self.entries = entries
+            .into_iter()
+            .map(|entry| (entry.station.station_uuid.clone(), entry))
+            .collect();
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Whether entries were added or changed since the cache was loaded or saved.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Writes the cache, keeping every `pinned` station (favorites) and filling the rest
+    /// of the `MAX_CACHED_STATIONS` budget with the most recently fetched entries.
+    pub fn save(&mut self, pinned: &[String]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "failed to create station cache directory: {}",
+                    parent.display()
+                )
+            })?;
+        }
+
+        let is_pinned = |entry: &CachedStation| pinned.contains(&entry.station.station_uuid);
+        let mut entries: Vec<&CachedStation> = self.entries.values().collect();
+        entries.sort_by(|a, b| {
+            is_pinned(b)
+                .cmp(&is_pinned(a))
+                .then_with(|| b.fetched_at.cmp(&a.fetched_at))
+                .then_with(|| a.station.station_uuid.cmp(&b.station.station_uuid))
+        });
+        let pinned_count = entries.iter().filter(|entry| is_pinned(entry)).count();
+        entries.truncate(MAX_CACHED_STATIONS.max(pinned_count));
+
+        // Compact: the file can hold thousands of entries and is rewritten during searches.
+        let body = serde_json::to_string(&entries).context("failed to serialize station cache")?;
+        fs::write(&self.path, body).with_context(|| {
+            format!(
+                "failed to write station cache file: {}",
+                self.path.display()
+            )
+        })?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    pub fn get(&self, station_uuid: &str) -> Option<&Station> {
+        self.entries.get(station_uuid).map(|entry| &entry.station)
+    }
+
+    pub fn contains(&self, station_uuid: &str) -> bool {
+        self.entries.contains_key(station_uuid)
+    }
+
+    /// Record a station freshly returned by the catalog.
+    pub fn insert(&mut self, station: Station) {
+        self.insert_fetched_at(station, unix_now());
+    }
+
+    /// Re-fetching a fresh, unchanged station only updates it in memory; new, changed or
+    /// stale entries mark the cache dirty.
+    pub fn insert_fetched_at(&mut self, station: Station, fetched_at: u64) {
+        let unchanged = self
+            .entries
+            .get(&station.station_uuid)
+            .is_some_and(|entry| {
+                entry.station == station
+                    && fetched_at.saturating_sub(entry.fetched_at) < self.ttl.as_secs()
+            });
+        self.dirty |= !unchanged;
+        self.entries.insert(
+            station.station_uuid.clone(),
+            CachedStation {
+                station,
+                fetched_at,
+            },
+        );
+    }
+
+    /// Missing stations count as stale.
+    pub fn is_stale(&self, station_uuid: &str) -> bool {
+        self.entries
+            .get(station_uuid)
+            .is_none_or(|entry| unix_now().saturating_sub(entry.fetched_at) >= self.ttl.as_secs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn station(id: &str) -> Station {
+        Station {
+            station_uuid: id.to_string(),
+            name: format!("Station {id}"),
+            url_resolved: format!("https://example.com/{id}"),
+            homepage: None,
+            favicon: None,
+            tags: Vec::new(),
+            country: None,
+            country_code: None,
+            language: None,
+            codec: Some("MP3".to_string()),
+            bitrate: Some(128),
+            votes: None,
+            click_count: None,
+            has_geo_info: false,
+            last_check_ok: None,
+            last_change_time: None,
+        }
+    }
+
+    #[test]
+    fn entries_older_than_ttl_are_stale() {
+        let mut cache = StationCache::new("unused.json", Duration::from_secs(60));
+        cache.insert(station("fresh"));
+        cache.insert_fetched_at(station("old"), unix_now() - 120);
+
+        assert!(!cache.is_stale("fresh"));
+        assert!(cache.is_stale("old"));
+        assert!(cache.is_stale("missing"));
+        assert_eq!(
+            cache.get("old").map(|s| s.name.as_str()),
+            Some("Station old")
+        );
+    }
+
+    #[test]
+    fn only_new_changed_or_stale_entries_dirty_the_cache() {
+        let mut cache = StationCache::new("unused.json", Duration::from_secs(60));
+        cache.insert_fetched_at(station("a"), 1_000);
+        assert!(cache.is_dirty());
+        cache.dirty = false;
+
+        cache.insert_fetched_at(station("a"), 1_030);
+        assert!(!cache.is_dirty());
+
+        let mut renamed = station("a");
+        renamed.name = "Renamed".to_string();
+        cache.insert_fetched_at(renamed.clone(), 1_040);
+        assert!(cache.is_dirty());
+        cache.dirty = false;
+
+        cache.insert_fetched_at(renamed, 1_100);
+        assert!(cache.is_dirty());
+    }
+
+    #[test]
+    fn save_never_evicts_pinned_favorites() {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        let mut cache = StationCache::new(dir.path().join("stations.json"), Duration::MAX);
+        cache.insert_fetched_at(station("favorite"), 0);
+        for n in 0..MAX_CACHED_STATIONS {
+            cache.insert_fetched_at(station(&n.to_string()), 1);
+        }
+        cache.save(&["favorite".to_string()]).expect("save cache");
+        assert!(!cache.is_dirty());
+
+        let mut reloaded = StationCache::new(cache.path(), Duration::MAX);
+        reloaded.load().expect("load cache");
+        assert_eq!(reloaded.entries.len(), MAX_CACHED_STATIONS);
+        assert!(reloaded.contains("favorite"));
+    }
+
+    #[test]
+    fn cache_path_is_sibling_of_favorites() {
+        let cache = StationCache::beside(
+            Path::new("/tmp/iradio/favorites.json"),
+            DEFAULT_STATION_CACHE_TTL,
+        );
+        assert_eq!(cache.path(), Path::new("/tmp/iradio/stations.json"));
+    }
+}
@@ -13,9 +13,13 @@ use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 
 use crate::app::{App, Focus};
+use crate::domain::actions::Action;
+use crate::domain::keymap::{Key, KeyChord};
+use render::ListView;
 
 pub struct Tui {
     terminal: Terminal<CrosstermBackend<Stdout>>,
+    list_view: ListView,
 }
 
 impl Tui {
@@ -25,14 +29,20 @@ impl Tui {
         stdout.execute(EnterAlternateScreen)?;
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
-        Ok(Self { terminal })
+        Ok(Self {
+            terminal,
+            list_view: ListView::default(),
+        })
     }
 
     pub fn run(&mut self, app: &mut App) -> Result<()> {
         while app.running {
+            app.tick();
+            let list_view = &mut self.list_view;
             self.terminal
-                .draw(|frame| render::render(frame, app))
+                .draw(|frame| render::render(frame, app, list_view))
                 .map_err(anyhow::Error::from)?;
+            app.set_list_page(self.list_view.page_rows);
 
             if event::poll(Duration::from_millis(100))? {
                 if let Event::Key(key) = event::read()? {
@@ -45,11 +55,14 @@ impl Tui {
     }
 
     fn handle_key_event(&mut self, app: &mut App, key: KeyEvent) -> Result<()> {
+        let ctrl_c = key.modifiers == KeyModifiers::CONTROL && key.code == KeyCode::Char('c');
+        if app.help_overlay().is_some() && !ctrl_c {
+            self.handle_help_key(app, key)?;
+            return Ok(());
+        }
+
         match (key.modifiers, key.code) {
             (KeyModifiers::CONTROL, KeyCode::Char('c')) => app.request_quit()?,
-            (KeyModifiers::CONTROL, KeyCode::Char('p')) => {
-                app.toggle_palette();
-            }
             (_, KeyCode::Esc) => app.close_overlays(),
             (_, KeyCode::Enter) => {
                 if let Err(err) = app.submit_current_input() {
@@ -57,40 +70,84 @@ impl Tui {
                 }
             }
             (_, KeyCode::Backspace) => app.backspace_input(),
-            (_, KeyCode::Up) if app.focus != Focus::Slash => app.select_previous(),
-            (_, KeyCode::Char('k')) if app.focus != Focus::Slash => app.select_previous(),
-            (_, KeyCode::Down) if app.focus != Focus::Slash => app.select_next(),
-            (_, KeyCode::Char('j')) if app.focus != Focus::Slash => app.select_next(),
-            (_, KeyCode::Tab) => app.toggle_focus(),
-            (_, KeyCode::BackTab) => app.toggle_focus_backward(),
-            (_, KeyCode::Char('/')) if app.focus != Focus::Slash => app.open_slash_input(),
-            (_, KeyCode::Char('q')) if app.focus != Focus::Slash => app.request_quit()?,
-            (_, KeyCode::Char('f')) if app.focus != Focus::Slash => {
-                if let Err(err) = app.toggle_selected_favorite() {
-                    app.status_message = format!("Error: {err}");
-                }
-            }
-            (_, KeyCode::Char('s')) if app.focus != Focus::Slash => {
-                if let Err(err) = app.stop_playback() {
-                    app.status_message = format!("Error: {err}");
+            // Tab completes in the slash input and falls back to its binding otherwise.
+            (KeyModifiers::NONE, KeyCode::Tab) if app.focus == Focus::Slash => {
+                if !app.complete_slash_input() {
+                    handle_bound_key(app, key)?;
                 }
             }
-            (_, KeyCode::Char(' ')) => {
-                if app.focus == Focus::Slash
-                    || (app.focus == Focus::Search
-                        && (!app.search_input.is_empty() || app.search_dirty()))
-                {
-                    app.push_char(' ');
-                } else if let Err(err) = app.pause_or_resume() {
-                    app.status_message = format!("Error: {err}");
-                }
-            }
-            (_, KeyCode::Char(c)) => app.push_char(c),
-            _ => {}
+            _ => handle_bound_key(app, key)?,
         }
 
         Ok(())
     }
+
+    fn handle_help_key(&mut self, app: &mut App, key: KeyEvent) -> Result<()> {
+        // The overlay border takes two rows.
+        let viewport = usize::from(self.terminal.size()?.height.saturating_sub(2));
+        let page = viewport.max(1) as isize;
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.close_overlays(),
+            KeyCode::Up | KeyCode::Char('k') => app.scroll_help(-1, viewport),
+            KeyCode::Down | KeyCode::Char('j') => app.scroll_help(1, viewport),
+            KeyCode::PageUp => app.scroll_help(-page, viewport),
+            KeyCode::PageDown | KeyCode::Char(' ') => app.scroll_help(page, viewport),
+            KeyCode::Home | KeyCode::Char('g') => app.scroll_help(isize::MIN, viewport),
+            KeyCode::End | KeyCode::Char('G') => app.scroll_help(isize::MAX, viewport),
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+fn handle_bound_key(app: &mut App, key: KeyEvent) -> Result<()> {
+    if let Some(action) = key_chord(&key).and_then(|chord| app.key_action_for(&chord)) {
+        run_key_action(app, action)?;
+    } else if let KeyCode::Char(c) = key.code {
+        app.push_char(c);
+    }
+    Ok(())
+}
+
+fn run_key_action(app: &mut App, action: Action) -> Result<()> {
+    if action == Action::Quit {
+        return app.request_quit();
+    }
+    if let Err(err) = app.run_action(action) {
+        app.status_message = format!("Error: {err}");
+    }
+    Ok(())
+}
+
+fn key_chord(event: &KeyEvent) -> Option<KeyChord> {
+    let key = match event.code {
+        KeyCode::Char(c) => Key::Char(c),
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::BackTab => Key::BackTab,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::Insert => Key::Insert,
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::F(n) => Key::F(n),
+        _ => return None,
+    };
+    // Shift is already reflected in the character (and in BackTab).
+    let folds_shift = matches!(key, Key::Char(_) | Key::BackTab);
+    Some(KeyChord {
+        key,
+        ctrl: event.modifiers.contains(KeyModifiers::CONTROL),
+        alt: event.modifiers.contains(KeyModifiers::ALT),
+        shift: event.modifiers.contains(KeyModifiers::SHIFT) && !folds_shift,
+    })
 }
 
 impl Drop for Tui {
@@ -1,12 +1,27 @@
-use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::{Line, Text};
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{
+    Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState, Wrap,
+};
 
-use crate::app::{App, Focus};
+use crate::app::{App, BrowseView, Focus, HelpOverlay, ResultsSource};
+use crate::domain::actions::Action;
+use crate::domain::columns::{fit_columns, Column, COLUMN_SPACING};
 use crate::integrations::playback::PlaybackState;
 
-pub fn render(frame: &mut ratatui::Frame<'_>, app: &App) {
+/// Scroll positions kept between frames, so the list only scrolls once the selection
+/// reaches an edge instead of jumping back to the top on every draw.
+#[derive(Debug, Default)]
+pub struct ListView {
+    results: TableState,
+    history: ListState,
+    browse: ListState,
+    /// Rows inside the list border at the last draw.
+    pub page_rows: usize,
+}
+
+pub fn render(frame: &mut ratatui::Frame<'_>, app: &App, view: &mut ListView) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -19,9 +34,14 @@ pub fn render(frame: &mut ratatui::Frame<'_>, app: &App) {
 
     let focus_label = app.focus.label();
 
+    let keymap = app.keymap();
     let header = Paragraph::new(format!(
-        "iradio | Focus: {} | Tab/Shift+Tab cycle focus | / slash | Ctrl+P palette | Esc close overlay",
-        focus_label
+        "iradio | Focus: {} | {}/{} cycle focus | {} slash | {} palette | Esc close overlay",
+        focus_label,
+        keymap.label(Action::FocusNext),
+        keymap.label(Action::FocusPrevious),
+        keymap.label(Action::OpenSlash),
+        keymap.label(Action::TogglePalette)
     ))
     .style(
         Style::default()
@@ -36,49 +56,62 @@ pub fn render(frame: &mut ratatui::Frame<'_>, app: &App) {
         .constraints([Constraint::Percentage(58), Constraint::Percentage(42)])
         .split(chunks[1]);
 
-    let list_items: Vec<ListItem<'_>> = app
-        .visible_stations()
-        .iter()
-        .enumerate()
-        .map(|(idx, station)| {
-            let mut style = Style::default();
-            if idx == app.selected_index {
-                style = style.bg(Color::Blue).fg(Color::White);
-            }
-            if app.is_favorite(station) {
-                style = style.add_modifier(Modifier::BOLD);
-            }
-            ListItem::new(Line::from(station.name.clone())).style(style)
-        })
-        .collect();
-
-    let station_title = format!(
-        "{} ({})",
+    let more_marker = if app.results_source() == ResultsSource::Stations && app.has_more_results() {
+        "+"
+    } else {
+        ""
+    };
+    let mut station_title = format!(
+        "{} ({}{more_marker})",
         app.results_source_label(),
         app.visible_stations().len()
     );
+    if let Some(filters) = app.active_filters_label() {
+        station_title.push_str(&format!(" | {filters}"));
+    }
+    if app.is_searching() {
+        station_title.push_str(" | Searching…");
+    }
     let station_block = Block::default()
         .borders(Borders::ALL)
         .title(station_title)
-        .style(if app.focus == Focus::Search {
+        .style(if app.focus == Focus::Results {
             Style::default()
                 .fg(Color::LightCyan)
                 .add_modifier(Modifier::BOLD)
         } else {
             Style::default()
         });
-    let stations = List::new(list_items).block(station_block);
-    frame.render_widget(stations, body[0]);
+    // Rows inside the border; the results table also spends one on its header.
+    view.page_rows = usize::from(body[0].height.saturating_sub(2));
+    if let Some(browse) = app.browse() {
+        render_browse(frame, app, browse, body[0], &mut view.browse);
+    } else if app.results_source() == ResultsSource::History {
+        render_history(frame, app, station_block, body[0], &mut view.history);
+    } else {
+        view.page_rows = view.page_rows.saturating_sub(1);
+        render_station_table(frame, app, station_block, body[0], &mut view.results);
+    }
 
-    let playback_status = match app.playback_state() {
-        PlaybackState::Stopped => "Stopped",
-        PlaybackState::Playing => "Playing",
-        PlaybackState::Paused => "Paused",
+    let playback_status = match (app.playback_state(), app.last_playback_error()) {
+        (PlaybackState::Error, Some(error)) => format!("Error ({error})"),
+        (state, _) => state.label().to_string(),
     };
 
     let details_lines = if let Some(station) = app.details_station() {
-        vec![
-            Line::from(format!("Name: {}", station.name)),
+        let mut lines = vec![Line::from(format!("Name: {}", station.name))];
+        if let Some(title) = app.stream_title() {
+            lines.push(Line::from(vec![
+                Span::raw("Track: "),
+                Span::styled(
+                    title.to_string(),
+                    Style::default()
+                        .fg(Color::LightGreen)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+        }
+        lines.extend([
             Line::from(format!("URL: {}", station.url_resolved)),
             Line::from(format!(
                 "Codec: {}",
@@ -97,7 +130,8 @@ pub fn render(frame: &mut ratatui::Frame<'_>, app: &App) {
                 station.language.as_deref().unwrap_or("unknown")
             )),
             Line::from(format!("Playback: {playback_status}")),
-        ]
+        ]);
+        lines
     } else {
         vec![
             Line::from("No station selected"),
@@ -153,6 +187,10 @@ pub fn render(frame: &mut ratatui::Frame<'_>, app: &App) {
 
     let input_title = match app.focus {
         Focus::Slash => "Slash Command",
+        Focus::Search | Focus::Results if app.browse().is_some() => {
+            "Browse (type to narrow, Enter filters)"
+        }
+        Focus::Results => "Search (Enter plays selected, type to search)",
         Focus::Search => {
             if app.search_dirty() {
                 "Search (Enter refreshes)"
@@ -167,18 +205,216 @@ pub fn render(frame: &mut ratatui::Frame<'_>, app: &App) {
     let input_block = Block::default()
         .borders(Borders::ALL)
         .title(input_title)
-        .style(if matches!(app.focus, Focus::Slash | Focus::Palette) {
-            Style::default()
-                .fg(Color::LightGreen)
-                .add_modifier(Modifier::BOLD)
-        } else {
+        .style(
+            if matches!(app.focus, Focus::Search | Focus::Slash | Focus::Palette) {
+                Style::default()
+                    .fg(Color::LightGreen)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            },
+        );
+    let mut input_line = vec![Span::raw(input_value)];
+    if let Some(hint) = app.slash_hint() {
+        input_line.push(Span::styled(
+            hint,
             Style::default()
-        });
-    let input = Paragraph::new(Text::from(input_value)).block(input_block);
+                .fg(Color::DarkGray)
+                .remove_modifier(Modifier::BOLD),
+        ));
+    }
+    let input = Paragraph::new(Line::from(input_line)).block(input_block);
     frame.render_widget(input, chunks[2]);
 
     let status = Paragraph::new(app.status_message.clone())
         .style(Style::default().fg(Color::Yellow))
         .wrap(Wrap { trim: true });
     frame.render_widget(status, chunks[3]);
+
+    if let Some(help) = app.help_overlay() {
+        render_help(frame, help);
+    }
+}
+
+fn render_station_table(
+    frame: &mut ratatui::Frame<'_>,
+    app: &App,
+    block: Block<'_>,
+    area: Rect,
+    state: &mut TableState,
+) {
+    let columns = fit_columns(app.columns(), area.width.saturating_sub(2));
+    let sorted_by = |column: Column| {
+        app.results_source() == ResultsSource::Stations && column.sort() == Some(app.sort())
+    };
+    let header = Row::new(columns.iter().map(|&column| {
+        let mut title = column.header().to_string();
+        if sorted_by(column) {
+            title.push_str(if app.sort_descending() {
+                " ▼"
+            } else {
+                " ▲"
+            });
+        }
+        aligned_cell(column, title)
+    }))
+    .style(
+        Style::default()
+            .fg(Color::Gray)
+            .add_modifier(Modifier::UNDERLINED),
+    );
+    let rows: Vec<Row<'_>> = app
+        .visible_stations()
+        .iter()
+        .map(|station| {
+            let style = if app.is_favorite(station) {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            Row::new(
+                columns
+                    .iter()
+                    .map(|&column| aligned_cell(column, column.value(station))),
+            )
+            .style(style)
+        })
+        .collect();
+    let widths = columns.iter().map(|&column| {
+        if column.grows() {
+            Constraint::Fill(column.min_width())
+        } else {
+            Constraint::Length(column.min_width())
+        }
+    });
+
+    let selected = (!rows.is_empty()).then_some(app.selected_index);
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .column_spacing(COLUMN_SPACING)
+        .row_highlight_style(Style::default().bg(Color::Blue).fg(Color::White));
+    state.select(selected);
+    frame.render_stateful_widget(table, area, state);
+}
+
+fn aligned_cell(column: Column, value: String) -> Cell<'static> {
+    let line = Line::from(value);
+    Cell::from(if column.is_numeric() {
+        line.right_aligned()
+    } else {
+        line
+    })
+}
+
+fn render_history(
+    frame: &mut ratatui::Frame<'_>,
+    app: &App,
+    block: Block<'_>,
+    area: Rect,
+    state: &mut ListState,
+) {
+    let items: Vec<ListItem<'_>> = app
+        .visible_stations()
+        .iter()
+        .enumerate()
+        .map(|(idx, station)| {
+            let label = match app.history_entry(idx) {
+                Some(entry) => format!(
+                    "{}  {} — {}",
+                    entry.played_at_label(),
+                    entry.title,
+                    entry.station_name
+                ),
+                None => station.name.clone(),
+            };
+            ListItem::new(Line::from(label))
+        })
+        .collect();
+
+    let selected = (!items.is_empty()).then_some(app.selected_index);
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::Blue).fg(Color::White));
+    state.select(selected);
+    frame.render_stateful_widget(list, area, state);
+}
+
+fn render_browse(
+    frame: &mut ratatui::Frame<'_>,
+    app: &App,
+    browse: &BrowseView,
+    area: Rect,
+    state: &mut ListState,
+) {
+    let values = app.browse_values();
+    let rows: Vec<ListItem<'_>> = values
+        .iter()
+        .map(|value| ListItem::new(format!("{}  ({})", value.name, value.station_count)))
+        .collect();
+
+    let mut title = format!("Browse {} ({})", browse.facet.plural(), values.len());
+    if !browse.query.is_empty() {
+        title.push_str(&format!(" matching '{}'", browse.query));
+    }
+    if app.is_loading_facet(browse.facet) {
+        title.push_str(" | Loading…");
+    }
+    let list = List::new(rows)
+        .block(
+            Block::default().borders(Borders::ALL).title(title).style(
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        )
+        .highlight_style(Style::default().bg(Color::Blue).fg(Color::White));
+    state.select(Some(browse.selected));
+    frame.render_stateful_widget(list, area, state);
+}
+
+fn render_help(frame: &mut ratatui::Frame<'_>, help: &HelpOverlay) {
+    let area = frame.area();
+    let viewport = usize::from(area.height.saturating_sub(2));
+    // Clamp again here in case the terminal shrank since the last scroll.
+    let scroll = help.scroll.min(help.lines.len().saturating_sub(viewport));
+    let lines: Vec<Line<'_>> = help
+        .lines
+        .iter()
+        .map(|line| {
+            if line.heading {
+                Line::styled(
+                    line.text.as_str(),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Line::from(line.text.as_str())
+            }
+        })
+        .collect();
+    let position = if help.lines.len() > viewport {
+        format!(
+            " {}-{} of {} ",
+            scroll + 1,
+            (scroll + viewport).min(help.lines.len()),
+            help.lines.len()
+        )
+    } else {
+        String::new()
+    };
+    let overlay = Paragraph::new(Text::from(lines))
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "{} | Esc close | Up/Down/PgUp/PgDn scroll",
+                    help.title
+                ))
+                .title_bottom(Line::from(position).right_aligned()),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(overlay, area);
 }
@@ -0,0 +1,142 @@
+//! Fixtures shared by the integration tests. Each test binary uses a different subset.
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use iradio::app::{App, AppDefaults, AppOptions};
+use iradio::domain::keymap::Keymap;
+use iradio::domain::models::Station;
+use iradio::integrations::playback::{PlaybackController, PlaybackState};
+use iradio::integrations::station_catalog::{StaticCatalog, StationCatalog};
+use iradio::storage::favorites::FavoritesStore;
+
+/// Accepts every command and never plays anything.
+pub struct NullPlayback;
+
+impl PlaybackController for NullPlayback {
+    fn play(&mut self, _stream_url: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn set_volume(&mut self, _value: u8) -> Result<()> {
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn state(&self) -> PlaybackState {
+        PlaybackState::Stopped
+    }
+}
+
+/// A station named `id` with a stream URL and no other metadata.
+/// Tests set the fields they care about with `Station { votes: Some(10), ..station("a") }`.
+pub fn station(id: &str) -> Station {
+    Station {
+        station_uuid: id.to_string(),
+        name: id.to_string(),
+        url_resolved: format!("https://example.com/{id}"),
+        homepage: None,
+        favicon: None,
+        tags: Vec::new(),
+        country: None,
+        country_code: None,
+        language: None,
+        codec: None,
+        bitrate: None,
+        votes: None,
+        click_count: None,
+        has_geo_info: false,
+        last_check_ok: None,
+        last_change_time: None,
+    }
+}
+
+/// Builds an `App` with a favorites file inside a temp dir, `NullPlayback` and an empty catalog
+/// unless told otherwise.
+pub struct AppBuilder {
+    favorites_path: PathBuf,
+    playback: Box<dyn PlaybackController>,
+    favorites: Vec<String>,
+    catalog: Box<dyn StationCatalog>,
+    defaults: AppDefaults,
+    options: AppOptions,
+}
+
+impl AppBuilder {
+    pub fn new(dir: &tempfile::TempDir) -> Self {
+        Self {
+            favorites_path: dir.path().join("favorites.json"),
+            favorites: Vec::new(),
+            playback: Box::new(NullPlayback),
+            catalog: Box::new(StaticCatalog::new(Vec::new())),
+            defaults: AppDefaults::default(),
+            options: AppOptions::default(),
+        }
+    }
+
+    pub fn playback(mut self, playback: impl PlaybackController + 'static) -> Self {
+        self.playback = Box::new(playback);
+        self
+    }
+
+    pub fn stations(self, stations: Vec<Station>) -> Self {
+        self.catalog(StaticCatalog::new(stations))
+    }
+
+    pub fn catalog(mut self, catalog: impl StationCatalog + 'static) -> Self {
+        self.catalog = Box::new(catalog);
+        self
+    }
+
+    /// Station uuids written to `favorites.json` before the app starts.
+    pub fn favorites(mut self, uuids: &[&str]) -> Self {
+        self.favorites = uuids.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    pub fn defaults(mut self, defaults: AppDefaults) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    pub fn background_search(mut self, background_search: bool) -> Self {
+        self.options.background_search = background_search;
+        self
+    }
+
+    pub fn keymap(mut self, keymap: Keymap) -> Self {
+        self.options.keymap = keymap;
+        self
+    }
+
+    pub fn build(self) -> App {
+        let store = FavoritesStore::new(&self.favorites_path);
+        if !self.favorites.is_empty() {
+            store.save(&self.favorites).expect("save favorites");
+        }
+        App::new_with_options(
+            self.playback,
+            store,
+            self.catalog,
+            self.defaults,
+            self.options,
+        )
+        .expect("create app")
+    }
+}
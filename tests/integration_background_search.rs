@@ -0,0 +1,140 @@
+mod common;
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use common::AppBuilder;
+use iradio::app::{App, Focus};
+use iradio::domain::models::{Station, StationSearchQuery};
+use iradio::integrations::station_catalog::StationCatalog;
+use iradio::storage::favorites::FavoritesStore;
+
+/// Blocks every search until the test releases it, then returns one station named after the query.
+struct GatedCatalog {
+    gate: Mutex<Receiver<()>>,
+}
+
+impl StationCatalog for GatedCatalog {
+    fn search(&self, query: &StationSearchQuery) -> Result<Vec<Station>> {
+        let _ = self.gate.lock().expect("lock gate").recv();
+        Ok(vec![Station {
+            name: format!("Result for '{}'", query.query),
+            url_resolved: "https://example.com/stream".to_string(),
+            ..common::station(&format!("id-{}", query.query))
+        }])
+    }
+
+    fn lookup(&self, station_uuids: &[String]) -> Result<Vec<Station>> {
+        Ok(station_uuids
+            .iter()
+            .map(|id| Station {
+                name: format!("Favorite {id}"),
+                url_resolved: "https://example.com/favorite".to_string(),
+                ..common::station(id)
+            })
+            .collect())
+    }
+}
+
+fn background_app(dir: &tempfile::TempDir) -> (App, Sender<()>) {
+    let (gate_tx, gate_rx) = mpsc::channel();
+    let app = AppBuilder::new(dir)
+        .catalog(GatedCatalog {
+            gate: Mutex::new(gate_rx),
+        })
+        .background_search(true)
+        .build();
+    (app, gate_tx)
+}
+
+fn tick_until_idle(app: &mut App) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while app.is_searching() {
+        assert!(Instant::now() < deadline, "search did not complete");
+        app.tick();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn search_runs_in_background_and_stale_responses_are_discarded() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let (mut app, gate) = background_app(&dir);
+
+    assert!(app.is_searching());
+    assert_eq!(app.status_message, "Searching…");
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/search jazz".to_string();
+    app.submit_current_input().expect("submit /search jazz");
+    app.focus = Focus::Slash;
+    app.slash_input = "/search rock".to_string();
+    app.submit_current_input().expect("submit /search rock");
+    assert!(app.is_searching());
+
+    // Superseded searches that already started still wait for the gate on their own threads.
+    for _ in 0..3 {
+        gate.send(()).expect("release search");
+    }
+    tick_until_idle(&mut app);
+
+    let names: Vec<&str> = app
+        .visible_stations()
+        .iter()
+        .map(|station| station.name.as_str())
+        .collect();
+    assert_eq!(names, ["Result for 'rock'"]);
+    assert_eq!(app.status_message, "Search applied (1 results)");
+}
+
+#[test]
+fn typing_a_new_query_cancels_in_flight_search() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let (mut app, gate) = background_app(&dir);
+
+    app.focus = Focus::Search;
+    app.push_char('x');
+    assert!(!app.is_searching());
+    assert_eq!(app.status_message, "Search cancelled");
+
+    gate.send(()).expect("release startup search");
+    std::thread::sleep(Duration::from_millis(50));
+    app.tick();
+    assert!(app.visible_stations().is_empty());
+
+    app.submit_current_input().expect("refresh search");
+    assert!(app.is_searching());
+    gate.send(()).expect("release refresh");
+    tick_until_idle(&mut app);
+    assert_eq!(app.visible_stations()[0].name, "Result for 'x'");
+}
+
+#[test]
+fn favorites_resolve_in_background() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    FavoritesStore::new(dir.path().join("favorites.json"))
+        .save(&["fav-1".to_string()])
+        .expect("seed favorites");
+    let (mut app, gate) = background_app(&dir);
+    gate.send(()).expect("release startup search");
+    tick_until_idle(&mut app);
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/favorites".to_string();
+    app.submit_current_input().expect("execute /favorites");
+    assert_eq!(
+        app.status_message,
+        "Showing favorites (0) | resolving 1 favorites…"
+    );
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while app.visible_stations().is_empty() {
+        assert!(Instant::now() < deadline, "lookup did not complete");
+        app.tick();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(app.visible_stations()[0].name, "Favorite fav-1");
+    assert_eq!(app.status_message, "Showing favorites (1)");
+}
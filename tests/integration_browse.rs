@@ -0,0 +1,154 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::AppBuilder;
+use iradio::app::{App, AppDefaults, Focus, ResultsSource};
+use iradio::domain::keymap::{Key, KeyChord};
+use iradio::domain::models::{Facet, Station, StationFilters};
+
+fn station(id: &str, country: &str, tags: &[&str]) -> Station {
+    Station {
+        tags: tags.iter().map(ToString::to_string).collect(),
+        country: Some(country.to_string()),
+        language: Some("english".to_string()),
+        codec: Some("MP3".to_string()),
+        bitrate: Some(128),
+        ..common::station(id)
+    }
+}
+
+fn build_app(dir: &tempfile::TempDir, background_search: bool) -> App {
+    AppBuilder::new(dir)
+        .stations(vec![
+            station("a", "Germany", &["jazz"]),
+            station("b", "United States", &["jazz", "news"]),
+            station("c", "United States", &["talk"]),
+            station("d", "United Kingdom", &["news"]),
+        ])
+        .defaults(AppDefaults {
+            filters: StationFilters {
+                tag: Some("jazz".to_string()),
+                ..StationFilters::default()
+            },
+            ..AppDefaults::default()
+        })
+        .background_search(background_search)
+        .build()
+}
+
+fn run_slash(app: &mut App, command: &str) {
+    app.open_slash_input();
+    app.slash_input = command.to_string();
+    app.submit_current_input().expect("run slash command");
+}
+
+/// Types `text` the way the TUI does: bound keys run their action, the rest are text.
+fn type_keys(app: &mut App, text: &str) {
+    for c in text.chars() {
+        match app.key_action_for(&KeyChord::new(Key::Char(c))) {
+            Some(action) => app.run_action(action).expect("run key action"),
+            None => app.push_char(c),
+        }
+    }
+}
+
+fn browse_names(app: &App) -> Vec<String> {
+    app.browse_values()
+        .iter()
+        .map(|value| format!("{} {}", value.name, value.station_count))
+        .collect()
+}
+
+#[test]
+fn browse_lists_values_by_station_count_and_narrows_by_typing() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, false);
+
+    run_slash(&mut app, "/browse countries");
+    assert_eq!(app.focus, Focus::Search);
+    assert_eq!(
+        browse_names(&app),
+        ["United States 2", "Germany 1", "United Kingdom 1"]
+    );
+
+    for c in "united".chars() {
+        app.push_char(c);
+    }
+    assert_eq!(browse_names(&app), ["United States 2", "United Kingdom 1"]);
+    assert!(
+        app.status_message.contains("Browsing countries (2)"),
+        "{}",
+        app.status_message
+    );
+}
+
+#[test]
+fn bound_letters_narrow_the_browse_list_in_either_pane() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, false);
+
+    run_slash(&mut app, "/browse countries");
+    // `g` jumps to the first row in the results list, but not while a browse list is open.
+    type_keys(&mut app, "germ");
+    assert!(app.running);
+    assert_eq!(browse_names(&app), ["Germany 1"]);
+
+    app.toggle_focus();
+    assert_eq!(app.focus, Focus::Results);
+    app.backspace_input();
+    app.backspace_input();
+    type_keys(&mut app, "rman");
+    assert_eq!(browse_names(&app), ["Germany 1"]);
+    assert_eq!(app.browse().expect("still browsing").query, "german");
+}
+
+#[test]
+fn choosing_a_value_adds_it_to_the_current_filters() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, false);
+
+    run_slash(&mut app, "/browse country");
+    app.select_next();
+    app.select_next();
+    app.select_previous();
+    app.submit_current_input().expect("apply browse selection");
+
+    assert!(app.browse().is_none());
+    assert_eq!(app.results_source(), ResultsSource::Stations);
+    assert_eq!(app.filters().country.as_deref(), Some("Germany"));
+    assert_eq!(app.filters().tag.as_deref(), Some("jazz"));
+    assert!(app.status_message.starts_with("Filters applied"));
+}
+
+#[test]
+fn esc_closes_browse_and_no_match_is_reported() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, false);
+
+    run_slash(&mut app, "/browse tag");
+    for c in "polka".chars() {
+        app.push_char(c);
+    }
+    let err = app.submit_current_input().expect_err("nothing to choose");
+    assert!(err.to_string().contains("no tag matches 'polka'"));
+
+    app.close_overlays();
+    assert!(app.browse().is_none());
+    assert_eq!(app.filters().country, None);
+}
+
+#[test]
+fn browse_values_load_in_the_background() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, true);
+
+    run_slash(&mut app, "/browse codec");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while app.is_loading_facet(Facet::Codec) {
+        assert!(Instant::now() < deadline, "codec list never arrived");
+        app.tick();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(browse_names(&app), ["MP3 4"]);
+}
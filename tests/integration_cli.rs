@@ -0,0 +1,190 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use iradio::cli::{self, SearchArgs};
+use iradio::domain::models::{Station, StationFilters, StationSort};
+use iradio::integrations::playback::{PlaybackController, PlaybackState};
+use iradio::integrations::station_catalog::StaticCatalog;
+use iradio::storage::favorites::FavoritesStore;
+
+struct RecordingPlayback {
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl PlaybackController for RecordingPlayback {
+    fn play(&mut self, stream_url: &str) -> Result<()> {
+        self.log.lock().unwrap().push(format!("play:{stream_url}"));
+        Ok(())
+    }
+
+    fn set_volume(&mut self, _value: u8) -> Result<()> {
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        Err(anyhow::anyhow!("already stopped"))
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn state(&self) -> PlaybackState {
+        PlaybackState::Stopped
+    }
+
+    fn force_stop(&mut self) -> Result<()> {
+        self.log.lock().unwrap().push("stop".to_string());
+        Ok(())
+    }
+
+    fn keep_running(&mut self) {
+        self.log.lock().unwrap().push("keep-running".to_string());
+    }
+}
+
+fn station(id: &str, name: &str, tags: &[&str], bitrate: u32) -> Station {
+    Station {
+        name: name.to_string(),
+        tags: tags.iter().map(ToString::to_string).collect(),
+        country: Some("Germany".to_string()),
+        country_code: Some("DE".to_string()),
+        language: Some("german".to_string()),
+        codec: Some("MP3".to_string()),
+        bitrate: Some(bitrate),
+        ..common::station(id)
+    }
+}
+
+fn catalog() -> StaticCatalog {
+    StaticCatalog::new(vec![
+        station("jazz-1", "Jazz One", &["jazz"], 128),
+        station("jazz-2", "Jazz Two", &["jazz", "talk"], 320),
+        station("news", "News", &["news"], 64),
+    ])
+}
+
+fn output(run: impl FnOnce(&mut Vec<u8>) -> Result<()>) -> String {
+    let mut out = Vec::new();
+    run(&mut out).expect("run command");
+    String::from_utf8(out).expect("utf-8 output")
+}
+
+#[test]
+fn search_prints_tab_separated_rows_or_json() {
+    let catalog = catalog();
+    let args = SearchArgs {
+        query: "jazz".to_string(),
+        sort: StationSort::Bitrate,
+        ..SearchArgs::default()
+    };
+    assert_eq!(
+        output(|out| cli::search(&catalog, &args, out)),
+        "jazz-2\tJazz Two\tGermany\tMP3\t320\njazz-1\tJazz One\tGermany\tMP3\t128\n"
+    );
+    let args = SearchArgs {
+        descending: false,
+        ..args
+    };
+    assert_eq!(
+        output(|out| cli::search(&catalog, &args, out)),
+        "jazz-1\tJazz One\tGermany\tMP3\t128\njazz-2\tJazz Two\tGermany\tMP3\t320\n"
+    );
+
+    let args = SearchArgs {
+        filters: StationFilters {
+            country: Some("DE".to_string()),
+            ..StationFilters::default()
+        },
+        json: true,
+        ..SearchArgs::default()
+    }
+    .with_filter_text(Some("tag=jazz tag!=talk"))
+    .expect("parse --filter");
+    let json: serde_json::Value =
+        serde_json::from_str(&output(|out| cli::search(&catalog, &args, out))).expect("json");
+    let ids: Vec<&str> = json
+        .as_array()
+        .expect("array")
+        .iter()
+        .map(|station| station["station_uuid"].as_str().expect("uuid"))
+        .collect();
+    assert_eq!(ids, ["jazz-1"]);
+}
+
+#[test]
+fn favorites_are_added_listed_and_removed_by_name_uuid_or_index() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let store = FavoritesStore::new(dir.path().join("favorites.json"));
+    let catalog = catalog();
+
+    assert_eq!(
+        output(|out| cli::add_favorite(&catalog, &store, "jazz two", out)),
+        "Favorited Jazz Two (jazz-2)\n"
+    );
+    output(|out| cli::add_favorite(&catalog, &store, "news", out));
+    assert_eq!(
+        output(|out| cli::add_favorite(&catalog, &store, "jazz-2", out)),
+        "Jazz Two is already a favorite\n"
+    );
+    assert_eq!(
+        output(|out| cli::list_favorites(&catalog, &store, false, out)),
+        "1\tjazz-2\tJazz Two\n2\tnews\tNews\n"
+    );
+
+    output(|out| cli::remove_favorite(&catalog, &store, "Jazz Two", out));
+    assert_eq!(store.load().expect("load"), ["news"]);
+    let mut out = Vec::new();
+    let err = cli::remove_favorite(&catalog, &store, "3", &mut out).expect_err("bad index");
+    assert!(err.to_string().contains("favorite index 3 is out of range"));
+    output(|out| cli::remove_favorite(&catalog, &store, "1", out));
+    assert!(store.load().expect("load").is_empty());
+}
+
+#[test]
+fn favorites_add_treats_digits_as_a_station_name() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let store = FavoritesStore::new(dir.path().join("favorites.json"));
+    let catalog = StaticCatalog::new(vec![station("hits", "1055", &["pop"], 128)]);
+
+    assert_eq!(
+        output(|out| cli::add_favorite(&catalog, &store, "1055", out)),
+        "Favorited 1055 (hits)\n"
+    );
+    assert_eq!(store.load().expect("load"), ["hits"]);
+}
+
+#[test]
+fn play_resolves_favorite_index_uuid_and_name() {
+    let catalog = catalog();
+    let favorites = vec!["news".to_string()];
+    for (target, expected) in [("1", "news"), ("jazz-1", "jazz-1"), ("JAZZ TWO", "jazz-2")] {
+        let station = cli::resolve_station(&catalog, &favorites, target).expect(target);
+        assert_eq!(station.station_uuid, expected);
+    }
+    let err = cli::resolve_station(&catalog, &favorites, "rock").expect_err("no match");
+    assert!(err.to_string().contains("no station matches 'rock'"));
+
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut playback = RecordingPlayback { log: log.clone() };
+    let station = cli::resolve_station(&catalog, &favorites, "1").expect("resolve");
+    assert_eq!(
+        output(|out| cli::play(&mut playback, &station, false, out)),
+        "Playing News (news)\n"
+    );
+    assert_eq!(output(|out| cli::stop(&mut playback, out)), "Stopped\n");
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["play:https://example.com/news", "keep-running", "stop"]
+    );
+}
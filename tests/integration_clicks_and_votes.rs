@@ -0,0 +1,183 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use common::AppBuilder;
+use iradio::app::{App, Focus};
+use iradio::domain::models::{Station, StationSearchQuery};
+use iradio::integrations::station_catalog::StationCatalog;
+
+/// Records clicks and votes; `accept_votes` decides the server's reply.
+struct FeedbackCatalog {
+    calls: Arc<Mutex<Vec<String>>>,
+    accept_votes: bool,
+    fail_clicks: bool,
+}
+
+impl StationCatalog for FeedbackCatalog {
+    fn search(&self, _query: &StationSearchQuery) -> Result<Vec<Station>> {
+        Ok(vec![sample_station()])
+    }
+
+    fn register_click(&self, station_uuid: &str) -> Result<()> {
+        self.calls
+            .lock()
+            .expect("lock calls")
+            .push(format!("click:{station_uuid}"));
+        if self.fail_clicks {
+            return Err(anyhow!("connection refused"));
+        }
+        Ok(())
+    }
+
+    fn vote(&self, station_uuid: &str) -> Result<String> {
+        self.calls
+            .lock()
+            .expect("lock calls")
+            .push(format!("vote:{station_uuid}"));
+        if self.accept_votes {
+            Ok("voted for station successfully".to_string())
+        } else {
+            Err(anyhow!(
+                "VoteError 'you are voting for the same station too often'"
+            ))
+        }
+    }
+}
+
+fn build_app(dir: &tempfile::TempDir, catalog: FeedbackCatalog, background_search: bool) -> App {
+    AppBuilder::new(dir)
+        .catalog(catalog)
+        .background_search(background_search)
+        .build()
+}
+
+fn run_slash(app: &mut App, input: &str) {
+    app.focus = Focus::Slash;
+    app.slash_input = input.to_string();
+    app.submit_current_input().expect("submit slash command");
+}
+
+#[test]
+fn playing_a_station_registers_a_click_without_surfacing_failures() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut app = build_app(
+        &dir,
+        FeedbackCatalog {
+            calls: calls.clone(),
+            accept_votes: true,
+            fail_clicks: true,
+        },
+        false,
+    );
+
+    run_slash(&mut app, "/play");
+
+    assert_eq!(
+        calls.lock().expect("lock calls").as_slice(),
+        ["click:station-1"]
+    );
+    assert_eq!(app.status_message, "Playing Sample Radio");
+    assert!(app.now_playing().is_some());
+}
+
+#[test]
+fn vote_shows_the_server_message() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut app = build_app(
+        &dir,
+        FeedbackCatalog {
+            calls: calls.clone(),
+            accept_votes: true,
+            fail_clicks: false,
+        },
+        false,
+    );
+
+    run_slash(&mut app, "/vote");
+    assert_eq!(
+        app.status_message,
+        "Voted for Sample Radio: voted for station successfully"
+    );
+
+    app.toggle_palette();
+    app.palette_input = "vote for selected".to_string();
+    app.submit_current_input().expect("execute palette vote");
+    assert_eq!(
+        calls.lock().expect("lock calls").as_slice(),
+        ["vote:station-1", "vote:station-1"]
+    );
+}
+
+#[test]
+fn refused_votes_report_why() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(
+        &dir,
+        FeedbackCatalog {
+            calls: Arc::new(Mutex::new(Vec::new())),
+            accept_votes: false,
+            fail_clicks: false,
+        },
+        false,
+    );
+
+    run_slash(&mut app, "/vote");
+    assert_eq!(
+        app.status_message,
+        "Vote for Sample Radio failed: VoteError 'you are voting for the same station too often'"
+    );
+}
+
+#[test]
+fn background_votes_report_when_the_server_answers() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut app = build_app(
+        &dir,
+        FeedbackCatalog {
+            calls: calls.clone(),
+            accept_votes: true,
+            fail_clicks: false,
+        },
+        true,
+    );
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while app.is_searching() {
+        assert!(Instant::now() < deadline, "startup search did not complete");
+        app.tick();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    run_slash(&mut app, "/play");
+    run_slash(&mut app, "/vote");
+    assert_eq!(app.status_message, "Voting for Sample Radio…");
+
+    while app.status_message == "Voting for Sample Radio…" {
+        assert!(Instant::now() < deadline, "vote did not complete");
+        app.tick();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(
+        app.status_message,
+        "Voted for Sample Radio: voted for station successfully"
+    );
+    assert_eq!(
+        calls.lock().expect("lock calls").as_slice(),
+        ["click:station-1", "vote:station-1"]
+    );
+}
+
+fn sample_station() -> Station {
+    Station {
+        name: "Sample Radio".to_string(),
+        url_resolved: "https://example.com/stream".to_string(),
+        votes: Some(10),
+        click_count: Some(15),
+        ..common::station("station-1")
+    }
+}
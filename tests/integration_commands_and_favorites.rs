@@ -1,11 +1,13 @@
+mod common;
+
 use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
-use iradio::app::{App, Focus};
+use common::AppBuilder;
+use iradio::app::Focus;
 use iradio::domain::models::{Station, StationFilters, StationSearchQuery, StationSort};
 use iradio::integrations::playback::{PlaybackController, PlaybackState};
 use iradio::integrations::station_catalog::StationCatalog;
-use iradio::storage::favorites::FavoritesStore;
 
 struct MockPlayback {
     log: Arc<Mutex<Vec<String>>>,
@@ -98,15 +100,17 @@ impl StationCatalog for MockCatalog {
 #[test]
 fn slash_play_and_favorite_updates_state_and_storage() {
     let log = Arc::new(Mutex::new(Vec::new()));
-    let playback = Box::new(MockPlayback::new(log.clone()));
+    let playback = MockPlayback::new(log.clone());
 
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
 
     let queries = Arc::new(Mutex::new(Vec::new()));
-    let catalog = Box::new(MockCatalog::new(queries, vec![sample_station()]));
+    let catalog = MockCatalog::new(queries, vec![sample_station()]);
 
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
 
     app.focus = Focus::Slash;
     app.slash_input = "/play selected".to_string();
@@ -125,18 +129,17 @@ fn slash_play_and_favorite_updates_state_and_storage() {
 #[test]
 fn favorites_command_switches_results_source_and_play_index() {
     let log = Arc::new(Mutex::new(Vec::new()));
-    let playback = Box::new(MockPlayback::new(log.clone()));
+    let playback = MockPlayback::new(log.clone());
 
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
 
     let queries = Arc::new(Mutex::new(Vec::new()));
-    let catalog = Box::new(MockCatalog::new(
-        queries,
-        vec![sample_station(), sample_station_two()],
-    ));
+    let catalog = MockCatalog::new(queries, vec![sample_station(), sample_station_two()]);
 
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
 
     app.focus = Focus::Slash;
     app.slash_input = "/fav".to_string();
@@ -162,18 +165,91 @@ fn favorites_command_switches_results_source_and_play_index() {
         .any(|entry| entry == "play:https://example.com/stream-two"));
 }
 
+struct LookupCatalog {
+    lookups: Arc<Mutex<Vec<Vec<String>>>>,
+}
+
+impl StationCatalog for LookupCatalog {
+    fn search(&self, _query: &StationSearchQuery) -> anyhow::Result<Vec<Station>> {
+        Ok(Vec::new())
+    }
+
+    fn lookup(&self, station_uuids: &[String]) -> anyhow::Result<Vec<Station>> {
+        self.lookups
+            .lock()
+            .expect("lock lookups")
+            .push(station_uuids.to_vec());
+        Ok([sample_station(), sample_station_two()]
+            .into_iter()
+            .filter(|station| station_uuids.contains(&station.station_uuid))
+            .collect())
+    }
+}
+
+#[test]
+fn favorites_are_resolved_by_uuid_in_a_fresh_session() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let playback = MockPlayback::new(log.clone());
+
+    let dir = tempfile::tempdir().expect("create tempdir");
+
+    let lookups = Arc::new(Mutex::new(Vec::new()));
+    let catalog = LookupCatalog {
+        lookups: lookups.clone(),
+    };
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .favorites(&["station-2", "gone-station", "station-1"])
+        .build();
+    assert!(app.visible_stations().is_empty());
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/favorites".to_string();
+    app.submit_current_input().expect("execute /favorites");
+
+    let names: Vec<&str> = app
+        .visible_stations()
+        .iter()
+        .map(|station| station.name.as_str())
+        .collect();
+    assert_eq!(names, ["Sample Radio Two", "Sample Radio"]);
+    assert_eq!(
+        app.status_message,
+        "Showing favorites (2) | 1 favorites are no longer listed in the station catalog"
+    );
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/play 1".to_string();
+    app.submit_current_input().expect("play first favorite");
+    assert!(log
+        .lock()
+        .expect("lock log")
+        .iter()
+        .any(|entry| entry == "play:https://example.com/stream-two"));
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/favorites".to_string();
+    app.submit_current_input().expect("reopen favorites");
+    let lookups = lookups.lock().expect("lock lookups").clone();
+    assert_eq!(lookups.len(), 2);
+    assert_eq!(lookups[1], vec!["gone-station".to_string()]);
+}
+
 #[test]
 fn filter_and_sort_commands_refresh_catalog_with_expected_state() {
     let log = Arc::new(Mutex::new(Vec::new()));
-    let playback = Box::new(MockPlayback::new(log));
+    let playback = MockPlayback::new(log);
 
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
 
     let queries = Arc::new(Mutex::new(Vec::new()));
-    let catalog = Box::new(MockCatalog::new(queries.clone(), vec![sample_station()]));
+    let catalog = MockCatalog::new(queries.clone(), vec![sample_station()]);
 
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
 
     app.focus = Focus::Slash;
     app.slash_input =
@@ -198,17 +274,59 @@ fn filter_and_sort_commands_refresh_catalog_with_expected_state() {
     assert_eq!(app.filters().country.as_deref(), Some("US"));
 }
 
+#[test]
+fn incremental_filter_edits_keep_the_other_filters() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let playback = MockPlayback::new(log);
+
+    let dir = tempfile::tempdir().expect("create tempdir");
+
+    let queries = Arc::new(Mutex::new(Vec::new()));
+    let catalog = MockCatalog::new(queries.clone(), vec![sample_station()]);
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
+    assert_eq!(app.active_filters_label(), None);
+
+    for input in [
+        "/filter country=US min_bitrate=128",
+        "/filter +tag=jazz,blues +tag!=talk",
+        "/filter -country",
+    ] {
+        app.focus = Focus::Slash;
+        app.slash_input = input.to_string();
+        app.submit_current_input().expect(input);
+    }
+
+    let queries = queries.lock().expect("lock queries").clone();
+    assert_eq!(queries[2].filters.country.as_deref(), Some("US"));
+    assert_eq!(queries[2].filters.tag.as_deref(), Some("jazz,blues"));
+    assert_eq!(queries[3].filters.country, None);
+    assert_eq!(
+        app.active_filters_label().as_deref(),
+        Some("tag=jazz,blues tag!=talk min_bitrate=128")
+    );
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/filter codec=aac".to_string();
+    app.submit_current_input().expect("replace filters");
+    assert_eq!(app.active_filters_label().as_deref(), Some("codec=aac"));
+}
+
 #[test]
 fn volume_command_applies_immediately_while_playing() {
     let log = Arc::new(Mutex::new(Vec::new()));
-    let playback = Box::new(MockPlayback::new(log.clone()));
+    let playback = MockPlayback::new(log.clone());
 
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
 
     let queries = Arc::new(Mutex::new(Vec::new()));
-    let catalog = Box::new(MockCatalog::new(queries, vec![sample_station()]));
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let catalog = MockCatalog::new(queries, vec![sample_station()]);
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
 
     app.focus = Focus::Slash;
     app.slash_input = "/play selected".to_string();
@@ -229,14 +347,16 @@ fn volume_command_applies_immediately_while_playing() {
 #[test]
 fn volume_command_deferred_when_stopped_then_applied_on_play() {
     let log = Arc::new(Mutex::new(Vec::new()));
-    let playback = Box::new(MockPlayback::new(log.clone()));
+    let playback = MockPlayback::new(log.clone());
 
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
 
     let queries = Arc::new(Mutex::new(Vec::new()));
-    let catalog = Box::new(MockCatalog::new(queries, vec![sample_station()]));
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let catalog = MockCatalog::new(queries, vec![sample_station()]);
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
 
     app.focus = Focus::Slash;
     app.slash_input = "/volume 55".to_string();
@@ -265,16 +385,20 @@ fn volume_command_deferred_when_stopped_then_applied_on_play() {
 }
 
 #[test]
-fn tab_focus_cycles_search_slash_palette() {
+fn tab_focus_cycles_search_results_slash_palette() {
     let log = Arc::new(Mutex::new(Vec::new()));
-    let playback = Box::new(MockPlayback::new(log));
+    let playback = MockPlayback::new(log);
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
-    let catalog = Box::new(MockCatalog::new(Arc::new(Mutex::new(Vec::new())), vec![]));
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let catalog = MockCatalog::new(Arc::new(Mutex::new(Vec::new())), vec![]);
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
 
     assert_eq!(app.focus, Focus::Search);
     app.toggle_focus();
+    assert_eq!(app.focus, Focus::Results);
+    app.toggle_focus();
     assert_eq!(app.focus, Focus::Slash);
     app.toggle_focus();
     assert_eq!(app.focus, Focus::Palette);
@@ -285,11 +409,13 @@ fn tab_focus_cycles_search_slash_palette() {
 #[test]
 fn palette_close_restores_previous_focus() {
     let log = Arc::new(Mutex::new(Vec::new()));
-    let playback = Box::new(MockPlayback::new(log));
+    let playback = MockPlayback::new(log);
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
-    let catalog = Box::new(MockCatalog::new(Arc::new(Mutex::new(Vec::new())), vec![]));
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let catalog = MockCatalog::new(Arc::new(Mutex::new(Vec::new())), vec![]);
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
 
     app.focus = Focus::Slash;
     app.toggle_palette();
@@ -301,11 +427,13 @@ fn palette_close_restores_previous_focus() {
 #[test]
 fn palette_action_executes_and_updates_status() {
     let log = Arc::new(Mutex::new(Vec::new()));
-    let playback = Box::new(MockPlayback::new(log.clone()));
+    let playback = MockPlayback::new(log.clone());
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
-    let catalog = Box::new(MockCatalog::new(Arc::new(Mutex::new(Vec::new())), vec![]));
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let catalog = MockCatalog::new(Arc::new(Mutex::new(Vec::new())), vec![]);
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(catalog)
+        .build();
 
     app.toggle_palette();
     app.palette_input = "stop".to_string();
@@ -320,11 +448,8 @@ fn palette_action_executes_and_updates_status() {
 
 fn sample_station() -> Station {
     Station {
-        station_uuid: "station-1".to_string(),
         name: "Sample Radio".to_string(),
         url_resolved: "https://example.com/stream".to_string(),
-        homepage: None,
-        favicon: None,
         tags: vec!["jazz".to_string()],
         country: Some("US".to_string()),
         country_code: Some("US".to_string()),
@@ -333,16 +458,14 @@ fn sample_station() -> Station {
         bitrate: Some(128),
         votes: Some(10),
         click_count: Some(15),
+        ..common::station("station-1")
     }
 }
 
 fn sample_station_two() -> Station {
     Station {
-        station_uuid: "station-2".to_string(),
         name: "Sample Radio Two".to_string(),
         url_resolved: "https://example.com/stream-two".to_string(),
-        homepage: None,
-        favicon: None,
         tags: vec!["news".to_string()],
         country: Some("US".to_string()),
         country_code: Some("US".to_string()),
@@ -351,5 +474,6 @@ fn sample_station_two() -> Station {
         bitrate: Some(96),
         votes: Some(5),
         click_count: Some(6),
+        ..common::station("station-2")
     }
 }
@@ -0,0 +1,113 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::AppBuilder;
+use iradio::app::App;
+use iradio::domain::models::Station;
+
+fn station(id: &str, name: &str, country: &str, tags: &[&str]) -> Station {
+    Station {
+        name: name.to_string(),
+        tags: tags.iter().map(ToString::to_string).collect(),
+        country: Some(country.to_string()),
+        language: Some("english".to_string()),
+        codec: Some("MP3".to_string()),
+        bitrate: Some(128),
+        ..common::station(id)
+    }
+}
+
+fn build_app(dir: &tempfile::TempDir, background_search: bool) -> App {
+    AppBuilder::new(dir)
+        .favorites(&["jazz-fm", "jazz-radio"])
+        .stations(vec![
+            station("jazz-fm", "Jazz FM", "United Kingdom", &["jazz"]),
+            station(
+                "jazz-radio",
+                "Jazz Radio",
+                "United States",
+                &["jazz", "blues"],
+            ),
+            station("npr", "NPR", "United States", &["news", "talk"]),
+        ])
+        .background_search(background_search)
+        .build()
+}
+
+fn type_slash(app: &mut App, text: &str) {
+    app.open_slash_input();
+    for c in text.trim_start_matches('/').chars() {
+        app.push_char(c);
+    }
+}
+
+#[test]
+fn tab_completes_commands_and_shows_usage_hints() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, false);
+
+    type_slash(&mut app, "/vol");
+    assert_eq!(app.slash_hint().as_deref(), Some("ume <0-100>"));
+    assert!(app.complete_slash_input());
+    assert_eq!(app.slash_input, "/volume ");
+    assert_eq!(app.slash_hint().as_deref(), Some("<0-100>"));
+
+    app.push_char('4');
+    assert!(
+        !app.complete_slash_input(),
+        "nothing to complete lets Tab fall back to its binding"
+    );
+}
+
+#[test]
+fn tab_completes_favorite_names_for_play() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, false);
+
+    type_slash(&mut app, "/play j");
+    assert!(app.complete_slash_input());
+    assert_eq!(app.slash_input, "/play Jazz ");
+
+    assert!(app.complete_slash_input());
+    assert_eq!(app.slash_input, "/play Jazz ");
+    assert_eq!(app.status_message, "Completions: Jazz FM, Jazz Radio");
+}
+
+#[test]
+fn filter_values_come_from_the_catalog_facets() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, false);
+
+    type_slash(&mut app, "/filter cou");
+    assert!(app.complete_slash_input());
+    assert_eq!(app.slash_input, "/filter country=");
+
+    app.push_char('u');
+    assert!(app.complete_slash_input());
+    assert_eq!(app.slash_input, "/filter country=United ");
+    app.push_char('S');
+    assert_eq!(app.slash_hint().as_deref(), Some("tates"));
+    assert!(app.complete_slash_input());
+    assert_eq!(app.slash_input, "/filter country=United States ");
+
+    "tag=".chars().for_each(|c| app.push_char(c));
+    assert!(app.complete_slash_input());
+    assert_eq!(app.status_message, "Completions: jazz, blues, news, talk");
+}
+
+#[test]
+fn background_facet_lists_load_while_typing() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, true);
+
+    type_slash(&mut app, "/filter tag=bl");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while app.slash_input == "/filter tag=bl" {
+        assert!(Instant::now() < deadline, "tag values never arrived");
+        app.tick();
+        app.complete_slash_input();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(app.slash_input, "/filter tag=blues ");
+}
@@ -0,0 +1,177 @@
+mod common;
+
+use anyhow::Result;
+use common::AppBuilder;
+use iradio::app::Focus;
+use iradio::domain::models::{Station, StationSearchQuery};
+use iradio::integrations::playback::{PlaybackController, PlaybackEvent, PlaybackState};
+use iradio::integrations::station_catalog::StationCatalog;
+use iradio::storage::history::HistoryStore;
+
+struct TitlePlayback {
+    state: PlaybackState,
+    pending: Vec<PlaybackEvent>,
+}
+
+impl TitlePlayback {
+    fn new() -> Self {
+        Self {
+            state: PlaybackState::Stopped,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl PlaybackController for TitlePlayback {
+    fn play(&mut self, _stream_url: &str) -> Result<()> {
+        self.state = PlaybackState::Playing;
+        self.pending = vec![
+            PlaybackEvent::StreamTitle("Artist - First".to_string()),
+            PlaybackEvent::StreamTitle("Artist - First".to_string()),
+            PlaybackEvent::StreamTitle("Artist - Second".to_string()),
+        ];
+        Ok(())
+    }
+
+    fn set_volume(&mut self, _value: u8) -> Result<()> {
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&mut self) -> Result<()> {
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn state(&self) -> PlaybackState {
+        self.state
+    }
+
+    fn poll_events(&mut self) -> Vec<PlaybackEvent> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+struct OneStationCatalog;
+
+impl StationCatalog for OneStationCatalog {
+    fn search(&self, _query: &StationSearchQuery) -> Result<Vec<Station>> {
+        Ok(vec![Station {
+            name: "Sample Radio".to_string(),
+            url_resolved: "https://example.com/stream".to_string(),
+            tags: vec!["jazz".to_string()],
+            country: Some("US".to_string()),
+            country_code: Some("US".to_string()),
+            language: Some("english".to_string()),
+            codec: Some("mp3".to_string()),
+            bitrate: Some(128),
+            votes: Some(10),
+            click_count: Some(15),
+            ..common::station("station-1")
+        }])
+    }
+}
+
+#[test]
+fn track_changes_are_persisted_and_listed_newest_first() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let favorites_path = dir.path().join("favorites.json");
+    let mut app = AppBuilder::new(&dir)
+        .playback(TitlePlayback::new())
+        .catalog(OneStationCatalog)
+        .build();
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/play selected".to_string();
+    app.submit_current_input().expect("execute /play");
+    app.tick();
+
+    let saved = HistoryStore::beside(&favorites_path)
+        .load()
+        .expect("load saved history");
+    let titles: Vec<&str> = saved.iter().map(|entry| entry.title.as_str()).collect();
+    assert_eq!(titles, ["Artist - First", "Artist - Second"]);
+    assert_eq!(saved[0].station_uuid, "station-1");
+    assert_eq!(saved[0].station_name, "Sample Radio");
+    assert!(saved[0].played_at > 0);
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/history".to_string();
+    app.submit_current_input().expect("execute /history");
+    assert_eq!(app.results_source_label(), "History");
+    assert_eq!(app.visible_stations().len(), 2);
+    assert_eq!(
+        app.history_entry(0).map(|entry| entry.title.as_str()),
+        Some("Artist - Second")
+    );
+    assert_eq!(app.status_message, "Showing track history (2)");
+}
+
+#[test]
+fn history_is_loaded_on_startup_and_uncached_stations_cannot_play() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let favorites_path = dir.path().join("favorites.json");
+    let mut entry =
+        iradio::storage::history::HistoryEntry::new("gone-station", "Gone FM", "Old Song");
+    entry.played_at = 1_700_000_000;
+    HistoryStore::beside(&favorites_path)
+        .save(&[entry])
+        .expect("seed history");
+
+    let mut app = AppBuilder::new(&dir)
+        .playback(TitlePlayback::new())
+        .catalog(OneStationCatalog)
+        .build();
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/history".to_string();
+    app.submit_current_input().expect("execute /history");
+    assert_eq!(app.visible_stations()[0].name, "Gone FM");
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/play selected".to_string();
+    let err = app
+        .submit_current_input()
+        .expect_err("uncached history station should not play");
+    assert!(err.to_string().contains("no stream URL cached for Gone FM"));
+    assert!(app.now_playing().is_none());
+}
+
+#[test]
+fn corrupt_history_starts_empty_instead_of_failing_startup() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let favorites_path = dir.path().join("favorites.json");
+    std::fs::write(
+        HistoryStore::beside(&favorites_path).path(),
+        "[{\"station_uuid\": ",
+    )
+    .expect("write corrupt history");
+
+    let mut app = AppBuilder::new(&dir)
+        .playback(TitlePlayback::new())
+        .catalog(OneStationCatalog)
+        .build();
+    assert!(
+        app.status_message
+            .starts_with("Track history unreadable, starting empty"),
+        "{}",
+        app.status_message
+    );
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/history".to_string();
+    app.submit_current_input().expect("execute /history");
+    assert!(app.visible_stations().is_empty());
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use common::AppBuilder;
+use iradio::app::{App, Focus};
+use iradio::domain::actions::Action;
+use iradio::domain::keymap::{KeyChord, Keymap};
+
+fn app_with_keymap(dir: &tempfile::TempDir, keymap: Keymap) -> App {
+    AppBuilder::new(dir).keymap(keymap).build()
+}
+
+fn chord(value: &str) -> KeyChord {
+    KeyChord::parse(value).expect("parse chord")
+}
+
+#[test]
+fn slash_opens_the_slash_input_at_startup() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = app_with_keymap(&dir, Keymap::default());
+
+    assert_eq!(app.focus, Focus::Search);
+    let action = app.key_action_for(&chord("/")).expect("/ is bound");
+    app.run_action(action).expect("open slash input");
+    assert_eq!(app.focus, Focus::Slash);
+    assert!(app.search_input.is_empty());
+}
+
+#[test]
+fn plain_keys_are_text_once_a_search_is_typed() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = app_with_keymap(&dir, Keymap::default());
+
+    assert_eq!(app.focus, Focus::Search);
+    assert_eq!(app.key_action_for(&chord("j")), Some(Action::SelectNext));
+    assert_eq!(app.key_action_for(&chord("down")), Some(Action::SelectNext));
+    assert_eq!(app.key_action_for(&chord("end")), Some(Action::SelectLast));
+    assert_eq!(
+        app.key_action_for(&chord("ctrl+p")),
+        Some(Action::TogglePalette)
+    );
+
+    app.toggle_focus();
+    assert_eq!(app.focus, Focus::Results);
+    assert_eq!(app.key_action_for(&chord("q")), Some(Action::Quit));
+    assert_eq!(app.key_action_for(&chord("G")), Some(Action::SelectLast));
+    assert_eq!(
+        app.key_action_for(&chord("space")),
+        Some(Action::PauseResume)
+    );
+
+    // Text typed in the list goes to the search input, and stays text from then on.
+    app.push_char('r');
+    assert_eq!(app.focus, Focus::Search);
+    assert_eq!(app.search_input, "r");
+    assert_eq!(app.key_action_for(&chord("q")), None);
+    assert_eq!(app.key_action_for(&chord("/")), None);
+    assert_eq!(app.key_action_for(&chord("space")), None);
+}
+
+#[test]
+fn submitting_a_search_moves_focus_to_the_results() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = app_with_keymap(&dir, Keymap::default());
+
+    for c in "radio".chars() {
+        assert_eq!(app.key_action_for(&chord(&c.to_string())), None);
+        app.push_char(c);
+    }
+    app.submit_current_input().expect("run search");
+    assert_eq!(app.focus, Focus::Results);
+    assert_eq!(app.key_action_for(&chord("s")), Some(Action::Stop));
+}
+
+#[test]
+fn palette_and_slash_inputs_only_see_non_text_bindings() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = app_with_keymap(&dir, Keymap::default());
+
+    app.toggle_palette();
+    assert_eq!(app.focus, Focus::Palette);
+    assert_eq!(app.key_action_for(&chord("f")), None);
+    assert_eq!(
+        app.key_action_for(&chord("up")),
+        Some(Action::SelectPrevious)
+    );
+
+    app.close_overlays();
+    app.open_slash_input();
+    assert_eq!(app.key_action_for(&chord("s")), None);
+    assert_eq!(app.key_action_for(&chord("down")), None);
+    assert_eq!(app.key_action_for(&chord("pagedown")), None);
+    assert_eq!(app.key_action_for(&chord("tab")), Some(Action::FocusNext));
+}
+
+#[test]
+fn help_lists_the_effective_keymap() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let keymap = Keymap::with_overrides([(Action::Quit, vec![chord("ctrl+q")])]).expect("keymap");
+    let mut app = app_with_keymap(&dir, keymap);
+
+    app.open_slash_input();
+    app.slash_input = "/help".to_string();
+    app.submit_current_input().expect("execute /help");
+
+    let help = app.help_overlay().expect("help overlay is open");
+    let texts: Vec<&str> = help.lines.iter().map(|line| line.text.as_str()).collect();
+    assert!(
+        texts
+            .iter()
+            .any(|text| text.contains("Ctrl+Q") && text.contains("Quit iradio")),
+        "{texts:#?}"
+    );
+    assert!(!texts.iter().any(|text| text.trim_start().starts_with("q ")));
+}
+
+#[test]
+fn help_for_a_command_shows_its_argument_syntax() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = app_with_keymap(&dir, Keymap::default());
+
+    app.open_slash_input();
+    app.slash_input = "/help filter".to_string();
+    app.submit_current_input().expect("execute /help filter");
+
+    let help = app.help_overlay().expect("help overlay is open");
+    assert!(help.title.contains("/filter"), "{}", help.title);
+    assert!(help
+        .lines
+        .iter()
+        .any(|line| line.text.contains("min_bitrate=<kbps>")));
+    assert!(app.key_action_for(&chord("q")).is_none());
+}
+
+#[test]
+fn help_scroll_is_clamped_and_esc_closes_it() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = app_with_keymap(&dir, Keymap::default());
+
+    app.open_slash_input();
+    app.slash_input = "/help".to_string();
+    app.submit_current_input().expect("execute /help");
+    let len = app
+        .help_overlay()
+        .expect("help overlay is open")
+        .lines
+        .len();
+
+    app.scroll_help(isize::MAX, 10);
+    assert_eq!(app.help_overlay().expect("open").scroll, len - 10);
+    app.scroll_help(-3, 10);
+    assert_eq!(app.help_overlay().expect("open").scroll, len - 13);
+    app.scroll_help(isize::MIN, 10);
+    assert_eq!(app.help_overlay().expect("open").scroll, 0);
+
+    app.close_overlays();
+    assert!(app.help_overlay().is_none());
+}
+
+#[test]
+fn actions_needing_arguments_prompt_in_the_slash_input() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = app_with_keymap(&dir, Keymap::default());
+
+    app.toggle_focus();
+    let action = app.key_action_for(&chord("v")).expect("v is bound");
+    app.run_action(action).expect("run volume action");
+    assert_eq!(app.focus, Focus::Slash);
+    assert_eq!(app.slash_input, "/volume ");
+    assert_eq!(app.status_message, "Usage: /volume <0-100>");
+
+    app.slash_input.clear();
+    app.focus = Focus::Search;
+    app.toggle_palette();
+    app.palette_input = "filter stations".to_string();
+    app.submit_current_input().expect("run palette item");
+    assert_eq!(app.focus, Focus::Slash);
+    assert!(app.slash_input.starts_with("/filter "));
+}
@@ -0,0 +1,170 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use common::AppBuilder;
+use iradio::app::{App, Focus};
+use iradio::domain::models::{Station, StationSearchQuery};
+use iradio::integrations::station_catalog::StationCatalog;
+use iradio::ui::render::{render, ListView};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+/// Serves 60 stations. Later pages start two rows early, like a live catalog whose ordering
+/// shifted between requests, so the app has to de-duplicate.
+struct PagedCatalog {
+    offsets: Arc<Mutex<Vec<usize>>>,
+}
+
+impl StationCatalog for PagedCatalog {
+    fn search(&self, query: &StationSearchQuery) -> Result<Vec<Station>> {
+        self.offsets
+            .lock()
+            .expect("lock offsets")
+            .push(query.offset);
+        let start = query.offset.saturating_sub(2);
+        let end = (query.offset + query.limit).min(60);
+        Ok((start..end).map(station).collect())
+    }
+}
+
+fn station(idx: usize) -> Station {
+    Station {
+        name: format!("Station {idx}"),
+        url_resolved: format!("https://example.com/{idx}"),
+        ..common::station(&format!("station-{idx}"))
+    }
+}
+
+fn paged_app(dir: &tempfile::TempDir) -> (App, Arc<Mutex<Vec<usize>>>) {
+    let offsets = Arc::new(Mutex::new(Vec::new()));
+    let app = AppBuilder::new(dir)
+        .catalog(PagedCatalog {
+            offsets: offsets.clone(),
+        })
+        .build();
+    (app, offsets)
+}
+
+#[test]
+fn more_command_appends_next_page_without_duplicates() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let (mut app, offsets) = paged_app(&dir);
+    assert_eq!(app.visible_stations().len(), 50);
+    assert!(app.has_more_results());
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/more".to_string();
+    app.submit_current_input().expect("execute /more");
+
+    assert_eq!(app.visible_stations().len(), 60);
+    assert_eq!(app.visible_stations()[59].station_uuid, "station-59");
+    assert_eq!(app.status_message, "Loaded 10 more stations (60 total)");
+    assert!(!app.has_more_results());
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/more".to_string();
+    app.submit_current_input().expect("execute /more again");
+    assert_eq!(app.status_message, "No more results (60 total)");
+    assert_eq!(offsets.lock().expect("lock offsets").as_slice(), [0, 50]);
+}
+
+#[test]
+fn selection_near_end_fetches_next_page() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let (mut app, offsets) = paged_app(&dir);
+
+    for _ in 0..44 {
+        app.select_next();
+    }
+    assert_eq!(app.visible_stations().len(), 50);
+
+    app.select_next();
+    assert_eq!(app.selected_index, 45);
+    assert_eq!(app.visible_stations().len(), 60);
+    assert_eq!(offsets.lock().expect("lock offsets").as_slice(), [0, 50]);
+}
+
+#[test]
+fn new_search_resets_paging() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let (mut app, offsets) = paged_app(&dir);
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/more".to_string();
+    app.submit_current_input().expect("execute /more");
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/search station".to_string();
+    app.submit_current_input().expect("execute /search");
+    assert_eq!(app.visible_stations().len(), 50);
+    assert!(app.has_more_results());
+    assert_eq!(offsets.lock().expect("lock offsets").as_slice(), [0, 50, 0]);
+}
+
+#[test]
+fn page_keys_move_by_the_visible_rows() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let (mut app, offsets) = paged_app(&dir);
+    app.set_list_page(20);
+
+    app.select_page_down();
+    assert_eq!(app.selected_index, 20);
+    app.select_page_down();
+    assert_eq!(app.selected_index, 40);
+    assert_eq!(app.visible_stations().len(), 50);
+
+    app.select_last();
+    assert_eq!(app.selected_index, 49);
+    assert_eq!(
+        app.visible_stations().len(),
+        60,
+        "jumping to the end loads more"
+    );
+    app.select_last();
+    assert_eq!(app.selected_index, 59);
+    app.select_page_down();
+    assert_eq!(app.selected_index, 59, "paging stops at the last row");
+    app.select_page_up();
+    assert_eq!(app.selected_index, 39);
+    app.select_first();
+    assert_eq!(app.selected_index, 0);
+    app.select_page_up();
+    assert_eq!(app.selected_index, 0);
+    app.select_previous();
+    assert_eq!(app.selected_index, 59, "single steps still wrap");
+    assert_eq!(offsets.lock().expect("lock offsets").as_slice(), [0, 50]);
+}
+
+#[test]
+fn rendered_list_keeps_the_selection_in_view() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let (mut app, _) = paged_app(&dir);
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).expect("create terminal");
+    let mut view = ListView::default();
+
+    let draw = |terminal: &mut Terminal<TestBackend>, app: &App, view: &mut ListView| {
+        terminal
+            .draw(|frame| render(frame, app, view))
+            .expect("draw");
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    draw(&mut terminal, &app, &mut view);
+    assert!(view.page_rows > 0);
+    app.set_list_page(view.page_rows);
+    app.select_page_down();
+    app.select_page_down();
+    let screen = draw(&mut terminal, &app, &mut view);
+    assert!(screen.contains(&format!("Station {} ", app.selected_index)));
+    assert!(!screen.contains("Station 0 "));
+}
@@ -1,9 +1,11 @@
+mod common;
+
 use anyhow::{anyhow, Result};
-use iradio::app::{App, Focus};
+use common::AppBuilder;
+use iradio::app::Focus;
 use iradio::domain::models::{Station, StationSearchQuery};
-use iradio::integrations::playback::{PlaybackController, PlaybackState};
+use iradio::integrations::playback::{PlaybackController, PlaybackEvent, PlaybackState};
 use iradio::integrations::station_catalog::StationCatalog;
-use iradio::storage::favorites::FavoritesStore;
 
 struct FailingPlayback {
     state: PlaybackState,
@@ -54,16 +56,110 @@ impl PlaybackController for FailingPlayback {
     }
 }
 
+struct DeadStreamPlayback {
+    state: PlaybackState,
+    pending: Vec<PlaybackEvent>,
+}
+
+impl PlaybackController for DeadStreamPlayback {
+    fn play(&mut self, _stream_url: &str) -> Result<()> {
+        self.state = PlaybackState::Loading;
+        self.pending = vec![
+            PlaybackEvent::StateChanged(PlaybackState::Error),
+            PlaybackEvent::Error("stream failed to start".to_string()),
+        ];
+        Ok(())
+    }
+
+    fn set_volume(&mut self, _value: u8) -> Result<()> {
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        Err(anyhow!("cannot pause"))
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        Err(anyhow!("cannot resume"))
+    }
+
+    fn shutdown(&mut self) -> Result<()> {
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn state(&self) -> PlaybackState {
+        self.state
+    }
+
+    fn poll_events(&mut self) -> Vec<PlaybackEvent> {
+        if self
+            .pending
+            .iter()
+            .any(|event| matches!(event, PlaybackEvent::StateChanged(PlaybackState::Error)))
+        {
+            self.state = PlaybackState::Error;
+        }
+        std::mem::take(&mut self.pending)
+    }
+}
+
+struct TitledStreamPlayback {
+    state: PlaybackState,
+    pending: Vec<PlaybackEvent>,
+}
+
+impl PlaybackController for TitledStreamPlayback {
+    fn play(&mut self, _stream_url: &str) -> Result<()> {
+        self.state = PlaybackState::Playing;
+        self.pending = vec![PlaybackEvent::StreamTitle("Artist - Song".to_string())];
+        Ok(())
+    }
+
+    fn set_volume(&mut self, _value: u8) -> Result<()> {
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        self.state = PlaybackState::Stopped;
+        self.pending = vec![PlaybackEvent::StreamTitle("Late Title".to_string())];
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&mut self) -> Result<()> {
+        self.state = PlaybackState::Stopped;
+        Ok(())
+    }
+
+    fn state(&self) -> PlaybackState {
+        self.state
+    }
+
+    fn poll_events(&mut self) -> Vec<PlaybackEvent> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
 struct StaticOneStationCatalog;
 
 impl StationCatalog for StaticOneStationCatalog {
     fn search(&self, _query: &StationSearchQuery) -> Result<Vec<Station>> {
         Ok(vec![Station {
-            station_uuid: "station-1".to_string(),
             name: "Sample FM".to_string(),
             url_resolved: "https://example.com/stream".to_string(),
-            homepage: None,
-            favicon: None,
             tags: vec!["jazz".to_string()],
             country: Some("US".to_string()),
             country_code: Some("US".to_string()),
@@ -72,17 +168,19 @@ impl StationCatalog for StaticOneStationCatalog {
             bitrate: Some(128),
             votes: Some(10),
             click_count: Some(11),
+            ..common::station("station-1")
         }])
     }
 }
 
 #[test]
 fn playback_errors_do_not_crash_submit_flow() {
-    let playback = Box::new(FailingPlayback::new());
+    let playback = FailingPlayback::new();
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
-    let catalog = Box::new(StaticOneStationCatalog);
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(StaticOneStationCatalog)
+        .build();
 
     app.focus = Focus::Slash;
     app.slash_input = "/play selected".to_string();
@@ -112,11 +210,12 @@ fn playback_errors_do_not_crash_submit_flow() {
 
 #[test]
 fn volume_errors_do_not_crash_submit_flow() {
-    let playback = Box::new(FailingPlayback::new_playing());
+    let playback = FailingPlayback::new_playing();
     let dir = tempfile::tempdir().expect("create tempdir");
-    let store = FavoritesStore::new(dir.path().join("favorites.json"));
-    let catalog = Box::new(StaticOneStationCatalog);
-    let mut app = App::new_with_catalog(playback, store, catalog).expect("create app");
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(StaticOneStationCatalog)
+        .build();
 
     app.focus = Focus::Slash;
     app.slash_input = "/volume 20".to_string();
@@ -124,3 +223,56 @@ fn volume_errors_do_not_crash_submit_flow() {
         .expect("volume failure should be handled gracefully");
     assert!(app.status_message.contains("Playback volume failed"));
 }
+
+#[test]
+fn dead_stream_error_event_surfaces_in_status() {
+    let playback = DeadStreamPlayback {
+        state: PlaybackState::Stopped,
+        pending: Vec::new(),
+    };
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(StaticOneStationCatalog)
+        .build();
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/play selected".to_string();
+    app.submit_current_input().expect("execute /play");
+    assert_eq!(app.playback_state(), PlaybackState::Loading);
+
+    app.tick();
+    assert_eq!(app.playback_state(), PlaybackState::Error);
+    assert_eq!(app.last_playback_error(), Some("stream failed to start"));
+    assert_eq!(app.status_message, "Playback error: stream failed to start");
+    assert!(app.now_playing().is_some());
+}
+
+#[test]
+fn stream_title_events_update_now_playing_track() {
+    let playback = TitledStreamPlayback {
+        state: PlaybackState::Stopped,
+        pending: Vec::new(),
+    };
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = AppBuilder::new(&dir)
+        .playback(playback)
+        .catalog(StaticOneStationCatalog)
+        .build();
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/play selected".to_string();
+    app.submit_current_input().expect("execute /play");
+    assert_eq!(app.stream_title(), None);
+
+    app.tick();
+    assert_eq!(app.stream_title(), Some("Artist - Song"));
+
+    app.focus = Focus::Slash;
+    app.slash_input = "/stop".to_string();
+    app.submit_current_input().expect("execute /stop");
+    assert_eq!(app.stream_title(), None);
+
+    app.tick();
+    assert_eq!(app.stream_title(), None);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::AppBuilder;
+use iradio::app::{App, AppDefaults};
+use iradio::domain::columns::Column;
+use iradio::domain::models::{Station, StationSort};
+use iradio::ui::render::{render, ListView};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn station(id: &str, name: &str, bitrate: u32, votes: u32) -> Station {
+    Station {
+        name: name.to_string(),
+        tags: vec!["jazz".to_string()],
+        country: Some("United Kingdom".to_string()),
+        country_code: Some("GB".to_string()),
+        codec: Some("AAC".to_string()),
+        bitrate: Some(bitrate),
+        votes: Some(votes),
+        ..common::station(id)
+    }
+}
+
+fn build_app(dir: &tempfile::TempDir, columns: Vec<Column>) -> App {
+    AppBuilder::new(dir)
+        .stations(vec![
+            station("a", "Jazz FM", 64, 10),
+            station("b", "Jazz FM", 320, 5),
+            station("c", "Jazz FM", 128, 50),
+        ])
+        .defaults(AppDefaults {
+            columns,
+            ..AppDefaults::default()
+        })
+        .build()
+}
+
+fn uuids(app: &App) -> Vec<&str> {
+    app.visible_stations()
+        .iter()
+        .map(|station| station.station_uuid.as_str())
+        .collect()
+}
+
+fn screen(app: &App, width: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, 20)).expect("create terminal");
+    terminal
+        .draw(|frame| render(frame, app, &mut ListView::default()))
+        .expect("draw");
+    let buffer = terminal.backend().buffer();
+    (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn cycle_sort_walks_the_sortable_columns_in_both_directions() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, vec![Column::Name, Column::Bitrate, Column::Votes]);
+    assert_eq!(uuids(&app), ["c", "a", "b"]);
+
+    app.cycle_sort().expect("cycle to votes ascending");
+    assert_eq!(
+        (app.sort(), app.sort_descending()),
+        (StationSort::Votes, false)
+    );
+    assert_eq!(uuids(&app), ["b", "a", "c"]);
+    assert_eq!(app.status_message, "Sort applied: votes asc (3 results)");
+
+    app.cycle_sort().expect("cycle to name");
+    assert_eq!(
+        (app.sort(), app.sort_descending()),
+        (StationSort::Name, false)
+    );
+    app.cycle_sort().expect("cycle to name descending");
+    app.cycle_sort().expect("cycle to bitrate");
+    assert_eq!(
+        (app.sort(), app.sort_descending()),
+        (StationSort::Bitrate, true)
+    );
+    assert_eq!(uuids(&app), ["b", "c", "a"]);
+}
+
+#[test]
+fn table_shows_configured_columns_and_the_sort_marker() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir, vec![Column::Name, Column::Bitrate, Column::Votes]);
+    app.cycle_sort().expect("cycle to votes ascending");
+
+    let wide = screen(&app, 120);
+    assert!(wide.contains("Name"), "{wide}");
+    assert!(wide.contains("kbps"), "{wide}");
+    assert!(wide.contains("Votes ▲"), "{wide}");
+    assert!(wide.contains("320"), "{wide}");
+
+    // The results pane gets 58% of 48 columns: room for the name and bitrate only.
+    let narrow = screen(&app, 48);
+    let header = narrow
+        .lines()
+        .find(|line| line.starts_with("│Name"))
+        .expect("table header row");
+    assert!(header.contains("kbps"), "{narrow}");
+    assert!(!header.contains("Votes"), "{narrow}");
+}
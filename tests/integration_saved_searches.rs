@@ -0,0 +1,144 @@
+mod common;
+
+use common::AppBuilder;
+use iradio::app::{App, AppDefaults};
+use iradio::domain::models::{Station, StationFilters, StationSort};
+use iradio::storage::searches::{SavedSearch, SearchesStore};
+
+fn station(id: &str, country: &str, tags: &[&str]) -> Station {
+    Station {
+        tags: tags.iter().map(ToString::to_string).collect(),
+        country: Some(country.to_string()),
+        language: Some("english".to_string()),
+        codec: Some("MP3".to_string()),
+        bitrate: Some(128),
+        ..common::station(id)
+    }
+}
+
+fn build_app(dir: &tempfile::TempDir) -> App {
+    AppBuilder::new(dir)
+        .stations(vec![
+            station("a", "Germany", &["jazz"]),
+            station("b", "United States", &["jazz", "news"]),
+            station("c", "United States", &["talk"]),
+        ])
+        .defaults(AppDefaults {
+            searches: vec![SavedSearch {
+                name: "talk".to_string(),
+                query: String::new(),
+                filters: StationFilters {
+                    tag: Some("talk".to_string()),
+                    ..StationFilters::default()
+                },
+                sort: StationSort::Name,
+                descending: false,
+            }],
+            ..AppDefaults::default()
+        })
+        .build()
+}
+
+fn run_slash(app: &mut App, command: &str) -> anyhow::Result<()> {
+    app.open_slash_input();
+    app.slash_input = command.to_string();
+    app.submit_current_input()
+}
+
+fn station_ids(app: &App) -> Vec<&str> {
+    app.visible_stations()
+        .iter()
+        .map(|station| station.station_uuid.as_str())
+        .collect()
+}
+
+#[test]
+fn saved_searches_persist_and_restore_query_filters_and_sort() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir);
+    run_slash(&mut app, "/filter tag=jazz country!=Germany").expect("filter");
+    run_slash(&mut app, "/sort bitrate asc").expect("sort");
+    run_slash(&mut app, "/save-search us jazz").expect("save search");
+    assert_eq!(
+        app.status_message,
+        "Saved search 'us jazz': country!=Germany tag=jazz | sort bitrate asc"
+    );
+
+    let stored = SearchesStore::beside(&dir.path().join("favorites.json"))
+        .load()
+        .expect("load saved searches");
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].name, "us jazz");
+
+    let mut app = build_app(&dir);
+    assert_eq!(app.search_names(), ["us jazz", "talk"]);
+    run_slash(&mut app, "/load US Jazz").expect("load search");
+    assert_eq!(app.sort(), StationSort::Bitrate);
+    assert!(!app.sort_descending());
+    assert_eq!(app.filters().exclude_country.as_deref(), Some("Germany"));
+    assert_eq!(station_ids(&app), ["b"]);
+    assert_eq!(app.status_message, "Saved search loaded (1 results)");
+
+    run_slash(&mut app, "/load talk").expect("load preset");
+    assert_eq!(station_ids(&app), ["c"]);
+    assert_eq!(app.sort(), StationSort::Name);
+
+    let err = run_slash(&mut app, "/load rock").expect_err("unknown search");
+    assert!(err.to_string().contains("no saved search named 'rock'"));
+}
+
+#[test]
+fn searches_are_listed_and_offered_in_the_palette() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let mut app = build_app(&dir);
+    run_slash(&mut app, "/filter tag=jazz").expect("filter");
+    run_slash(&mut app, "/save-search jazz").expect("save search");
+
+    run_slash(&mut app, "/searches").expect("list searches");
+    let overlay = app.help_overlay().expect("searches overlay");
+    let text: Vec<&str> = overlay
+        .lines
+        .iter()
+        .map(|line| line.text.as_str())
+        .collect();
+    assert!(text.contains(&"Saved searches"), "{text:?}");
+    assert!(text.contains(&"  jazz  tag=jazz | sort votes"), "{text:?}");
+    assert!(text.contains(&"  talk  tag=talk | sort name"), "{text:?}");
+
+    app.close_overlays();
+    app.toggle_palette();
+    "load search".chars().for_each(|c| app.push_char(c));
+    let labels: Vec<String> = app
+        .palette_preview(10)
+        .into_iter()
+        .map(|item| item.label)
+        .collect();
+    assert!(
+        labels.contains(&"Load search: jazz".to_string()),
+        "{labels:?}"
+    );
+    assert!(
+        labels.contains(&"Load search: talk".to_string()),
+        "{labels:?}"
+    );
+}
+
+#[test]
+fn invalid_saved_searches_are_skipped_at_startup() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let store = SearchesStore::beside(&dir.path().join("favorites.json"));
+    std::fs::write(
+        store.path(),
+        r#"[{"name": "odd", "filters": "genre=jazz"}, {"name": "jazz", "filters": "tag=jazz"}]"#,
+    )
+    .expect("write saved searches");
+
+    let app = build_app(&dir);
+    assert_eq!(app.search_names(), ["jazz", "talk"]);
+    assert!(
+        app.status_message
+            .starts_with("Skipped 1 invalid saved search(es)"),
+        "{}",
+        app.status_message
+    );
+}
@@ -0,0 +1,216 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod common;
+
+use anyhow::{anyhow, Result};
+use common::AppBuilder;
+use iradio::app::{App, Focus};
+use iradio::domain::models::{Station, StationSearchQuery};
+use iradio::integrations::playback::{PlaybackController, PlaybackState};
+use iradio::integrations::station_catalog::StationCatalog;
+use iradio::storage::favorites::FavoritesStore;
+use iradio::storage::station_cache::{StationCache, DEFAULT_STATION_CACHE_TTL};
+
+struct RecordingPlayback {
+    played: Arc<Mutex<Vec<String>>>,
+}
+
+impl PlaybackController for RecordingPlayback {
+    fn play(&mut self, stream_url: &str) -> Result<()> {
+        self.played
+            .lock()
+            .expect("lock played")
+            .push(stream_url.to_string());
+        Ok(())
+    }
+
+    fn set_volume(&mut self, _value: u8) -> Result<()> {
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn pause(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn state(&self) -> PlaybackState {
+        PlaybackState::Stopped
+    }
+}
+
+struct OnlineCatalog {
+    name: &'static str,
+    listed_in_search: bool,
+}
+
+impl StationCatalog for OnlineCatalog {
+    fn search(&self, _query: &StationSearchQuery) -> Result<Vec<Station>> {
+        if self.listed_in_search {
+            Ok(vec![station(self.name)])
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn lookup(&self, station_uuids: &[String]) -> Result<Vec<Station>> {
+        Ok(vec![station(self.name)]
+            .into_iter()
+            .filter(|station| station_uuids.contains(&station.station_uuid))
+            .collect())
+    }
+}
+
+struct OfflineCatalog;
+
+impl StationCatalog for OfflineCatalog {
+    fn search(&self, _query: &StationSearchQuery) -> Result<Vec<Station>> {
+        Err(anyhow!("network unreachable"))
+    }
+
+    fn lookup(&self, _station_uuids: &[String]) -> Result<Vec<Station>> {
+        Err(anyhow!("network unreachable"))
+    }
+}
+
+fn station(name: &str) -> Station {
+    Station {
+        name: name.to_string(),
+        url_resolved: "https://example.com/stream".to_string(),
+        tags: vec!["jazz".to_string()],
+        country: Some("US".to_string()),
+        country_code: Some("US".to_string()),
+        language: Some("english".to_string()),
+        codec: Some("mp3".to_string()),
+        bitrate: Some(128),
+        votes: Some(10),
+        click_count: Some(15),
+        ..common::station("station-1")
+    }
+}
+
+fn app_with(
+    dir: &tempfile::TempDir,
+    catalog: impl StationCatalog + 'static,
+) -> (App, Arc<Mutex<Vec<String>>>) {
+    let played = Arc::new(Mutex::new(Vec::new()));
+    let app = AppBuilder::new(dir)
+        .playback(RecordingPlayback {
+            played: played.clone(),
+        })
+        .catalog(catalog)
+        .build();
+    (app, played)
+}
+
+fn run_slash(app: &mut App, command: &str) -> Result<()> {
+    app.focus = Focus::Slash;
+    app.slash_input = command.to_string();
+    app.submit_current_input()
+}
+
+#[test]
+fn favorites_render_and_play_offline_from_persisted_cache() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    {
+        let (mut app, _) = app_with(
+            &dir,
+            OnlineCatalog {
+                name: "Jazz FM",
+                listed_in_search: true,
+            },
+        );
+        run_slash(&mut app, "/fav").expect("favorite station");
+    }
+    assert!(dir.path().join("stations.json").exists());
+
+    let (mut app, played) = app_with(&dir, OfflineCatalog);
+    assert!(app
+        .status_message
+        .starts_with("Station discovery unavailable"));
+
+    run_slash(&mut app, "/favorites").expect("open favorites");
+    assert_eq!(app.status_message, "Showing favorites (1)");
+    let favorite = &app.visible_stations()[0];
+    assert_eq!(favorite.name, "Jazz FM");
+    assert_eq!(favorite.codec.as_deref(), Some("mp3"));
+
+    run_slash(&mut app, "/play 1").expect("play cached favorite");
+    assert_eq!(
+        played.lock().expect("lock played").as_slice(),
+        ["https://example.com/stream"]
+    );
+}
+
+#[test]
+fn stale_favorites_are_refreshed_when_opened() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    FavoritesStore::new(dir.path().join("favorites.json"))
+        .save(&["station-1".to_string()])
+        .expect("seed favorites");
+    let mut cache = StationCache::beside(
+        &dir.path().join("favorites.json"),
+        DEFAULT_STATION_CACHE_TTL,
+    );
+    let two_days_ago = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock after epoch")
+        .saturating_sub(Duration::from_secs(2 * 24 * 60 * 60))
+        .as_secs();
+    cache.insert_fetched_at(station("Old Name FM"), two_days_ago);
+    cache.save(&[]).expect("seed station cache");
+
+    let (mut offline, _) = app_with(&dir, OfflineCatalog);
+    run_slash(&mut offline, "/favorites").expect("open favorites offline");
+    assert_eq!(offline.visible_stations()[0].name, "Old Name FM");
+    assert!(offline
+        .status_message
+        .contains("using cached details; refresh failed"));
+    drop(offline);
+
+    let (mut online, _) = app_with(
+        &dir,
+        OnlineCatalog {
+            name: "New Name FM",
+            listed_in_search: false,
+        },
+    );
+    run_slash(&mut online, "/favorites").expect("open favorites online");
+    assert_eq!(online.visible_stations()[0].name, "New Name FM");
+    assert_eq!(online.status_message, "Showing favorites (1)");
+}
+
+#[test]
+fn unreadable_station_cache_is_reported_at_startup() {
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let cache = StationCache::beside(
+        &dir.path().join("favorites.json"),
+        DEFAULT_STATION_CACHE_TTL,
+    );
+    std::fs::write(cache.path(), "{\"stations\": ").expect("write corrupt cache");
+
+    let (app, _) = app_with(
+        &dir,
+        OnlineCatalog {
+            name: "Jazz FM",
+            listed_in_search: true,
+        },
+    );
+    assert!(
+        app.status_message.starts_with("Station cache unreadable"),
+        "{}",
+        app.status_message
+    );
+    assert_eq!(app.visible_stations()[0].name, "Jazz FM");
+}
@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use iradio::domain::actions::{palette_items, Action, ArgSchema};
+use iradio::domain::commands::SlashCommand;
+use iradio::domain::keymap::Keymap;
+
+#[test]
+fn names_and_aliases_are_unique() {
+    let mut seen = HashSet::new();
+    for action in Action::ALL {
+        let spec = action.spec();
+        for name in std::iter::once(&spec.name).chain(spec.aliases) {
+            assert!(seen.insert(*name), "duplicate action name {name}");
+            assert_eq!(Action::from_name(name), Some(action));
+        }
+    }
+    assert_eq!(Action::from_name("select_next"), Some(Action::SelectNext));
+}
+
+#[test]
+fn every_slash_action_parses_or_reports_its_usage() {
+    for action in Action::ALL.into_iter().filter(|a| a.spec().slash) {
+        let parsed = SlashCommand::parse(&format!("/{}", action.name()));
+        match action.spec().args {
+            ArgSchema::Required(_) => {
+                let err = parsed.expect_err("missing arguments should fail");
+                assert_eq!(err.to_string(), format!("usage: {}", action.usage()));
+            }
+            ArgSchema::None | ArgSchema::Optional(_) => {
+                parsed.unwrap_or_else(|err| panic!("/{} failed: {err}", action.name()));
+            }
+        }
+    }
+}
+
+#[test]
+fn ui_only_actions_are_not_slash_commands() {
+    let err = SlashCommand::parse("/select-next").expect_err("not a slash command");
+    assert_eq!(err.to_string(), "unknown command: select-next");
+}
+
+#[test]
+fn palette_items_are_runnable_commands() {
+    let items = palette_items();
+    assert!(items.iter().any(|item| item.label == "Search stations"));
+    assert!(items.iter().any(|item| item.label == "Filter stations"));
+
+    for item in items {
+        let name = item.action.split_whitespace().next().expect("action name");
+        let action = Action::from_slash_name(name).expect("palette action is a slash command");
+        if item.action != action.name() || !action.requires_args() {
+            SlashCommand::parse(&format!("/{}", item.action))
+                .unwrap_or_else(|err| panic!("palette item {:?} failed: {err}", item.label));
+        }
+    }
+}
+
+#[test]
+fn default_keys_are_valid_and_conflict_free() {
+    let keymap = Keymap::with_overrides([]).expect("defaults do not conflict");
+    for action in Action::ALL {
+        assert_eq!(
+            keymap.keys_for(action).len(),
+            action.spec().default_keys.len(),
+            "{}",
+            action.name()
+        );
+    }
+}
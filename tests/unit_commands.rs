@@ -1,5 +1,6 @@
-use iradio::domain::commands::{PlayTarget, SlashCommand};
-use iradio::domain::models::{StationFilters, StationSort};
+use iradio::domain::actions::Action;
+use iradio::domain::commands::{FilterUpdate, PlayTarget, SlashCommand};
+use iradio::domain::models::{Facet, StationFilters, StationSort};
 
 #[test]
 fn parse_play_command() {
@@ -34,6 +35,18 @@ fn parse_favorites_command() {
     assert_eq!(cmd, SlashCommand::Favorites);
 }
 
+#[test]
+fn parse_more_command() {
+    let cmd = SlashCommand::parse("/more").expect("parse /more");
+    assert_eq!(cmd, SlashCommand::More);
+}
+
+#[test]
+fn parse_history_command() {
+    let cmd = SlashCommand::parse("/history").expect("parse /history");
+    assert_eq!(cmd, SlashCommand::History);
+}
+
 #[test]
 fn parse_volume_command_bounds() {
     let low = SlashCommand::parse("/volume 0").expect("parse /volume 0");
@@ -56,20 +69,185 @@ fn parse_filter_command() {
     .expect("parse /filter command");
     assert_eq!(
         cmd,
-        SlashCommand::Filter(StationFilters {
+        SlashCommand::Filter(Box::new(FilterUpdate::replace(StationFilters {
             country: Some("US".to_string()),
             language: Some("english".to_string()),
             tag: Some("jazz".to_string()),
             codec: Some("mp3".to_string()),
             min_bitrate: Some(128),
-        })
+            ..StationFilters::default()
+        })))
     );
 }
 
+#[test]
+fn parse_filter_values_with_spaces() {
+    let cmd = SlashCommand::parse("/filter country=United States tag=jazz")
+        .expect("parse /filter with spaces");
+    assert_eq!(
+        cmd,
+        SlashCommand::Filter(Box::new(FilterUpdate::replace(StationFilters {
+            country: Some("United States".to_string()),
+            tag: Some("jazz".to_string()),
+            ..StationFilters::default()
+        })))
+    );
+
+    let err = SlashCommand::parse("/filter jazz").expect_err("bare word should fail");
+    assert!(err.to_string().contains("invalid filter syntax"));
+}
+
+#[test]
+fn parse_multi_value_negated_and_flag_filters() {
+    let cmd = SlashCommand::parse(
+        "/filter tag=jazz,blues tag!=talk codec=mp3|aac max_bitrate=320 has_geo=false https_only is_working",
+    )
+    .expect("parse extended /filter");
+    assert_eq!(
+        cmd,
+        SlashCommand::Filter(Box::new(FilterUpdate::replace(StationFilters {
+            tag: Some("jazz,blues".to_string()),
+            exclude_tag: Some("talk".to_string()),
+            codec: Some("mp3|aac".to_string()),
+            max_bitrate: Some(320),
+            has_geo: Some(false),
+            https_only: true,
+            is_working: true,
+            ..StationFilters::default()
+        })))
+    );
+}
+
+#[test]
+fn parse_incremental_filter_edits() {
+    let cmd = SlashCommand::parse("/filter +tag=jazz -country +https_only=false")
+        .expect("parse incremental /filter");
+    assert_eq!(
+        cmd,
+        SlashCommand::Filter(Box::new(FilterUpdate {
+            incremental: true,
+            set: StationFilters {
+                tag: Some("jazz".to_string()),
+                ..StationFilters::default()
+            },
+            remove: vec!["country".to_string(), "https_only".to_string()],
+        }))
+    );
+
+    let SlashCommand::Filter(update) = cmd else {
+        unreachable!()
+    };
+    let current = StationFilters {
+        country: Some("US".to_string()),
+        exclude_country: Some("CA".to_string()),
+        tag: Some("news".to_string()),
+        min_bitrate: Some(128),
+        https_only: true,
+        ..StationFilters::default()
+    };
+    assert_eq!(
+        update.apply(&current).expect("apply edit"),
+        StationFilters {
+            tag: Some("jazz".to_string()),
+            min_bitrate: Some(128),
+            ..StationFilters::default()
+        }
+    );
+
+    let SlashCommand::Filter(update) =
+        SlashCommand::parse("/filter +max_bitrate=96").expect("parse max_bitrate edit")
+    else {
+        unreachable!()
+    };
+    let err = update.apply(&current).expect_err("range check after merge");
+    assert!(err
+        .to_string()
+        .contains("min_bitrate=128 is above max_bitrate=96"));
+
+    let err = SlashCommand::parse("/filter -tag=jazz").expect_err("removal takes no value");
+    assert!(err.to_string().contains("write -tag to remove"));
+}
+
+#[test]
+fn reject_malformed_extended_filters() {
+    for (input, expected) in [
+        ("/filter tag=jazz,", "empty value in tag=jazz,"),
+        ("/filter min_bitrate!=128", "min_bitrate cannot be negated"),
+        (
+            "/filter https_only=maybe",
+            "https_only must be true or false",
+        ),
+        (
+            "/filter is_working=no tag=jazz",
+            "is_working=no only applies to edits",
+        ),
+        (
+            "/filter min_bitrate=320 max_bitrate=128",
+            "min_bitrate=320 is above max_bitrate=128",
+        ),
+    ] {
+        let err = SlashCommand::parse(input).expect_err(input);
+        assert!(err.to_string().contains(expected), "{input}: {err}");
+    }
+}
+
+#[test]
+fn parse_saved_search_commands() {
+    assert_eq!(
+        SlashCommand::parse("/save-search  late   jazz ").expect("parse /save-search"),
+        SlashCommand::SaveSearch("late jazz".to_string())
+    );
+    assert_eq!(
+        SlashCommand::parse("/load late jazz").expect("parse /load"),
+        SlashCommand::LoadSearch("late jazz".to_string())
+    );
+    assert_eq!(
+        SlashCommand::parse("/searches").expect("parse /searches"),
+        SlashCommand::Searches
+    );
+    let err = SlashCommand::parse("/load").expect_err("name is required");
+    assert!(err.to_string().contains("usage: /load <name>"));
+}
+
+#[test]
+fn parse_browse_command() {
+    assert_eq!(
+        SlashCommand::parse("/browse tags").expect("parse /browse"),
+        SlashCommand::Browse(Facet::Tag)
+    );
+    let err = SlashCommand::parse("/browse genre").expect_err("unknown facet should fail");
+    assert!(err
+        .to_string()
+        .contains("invalid browse facet: genre (expected country, language, tag, codec)"));
+}
+
 #[test]
 fn parse_sort_command() {
     let cmd = SlashCommand::parse("/sort clicks").expect("parse /sort command");
-    assert_eq!(cmd, SlashCommand::Sort(StationSort::Clicks));
+    assert_eq!(
+        cmd,
+        SlashCommand::Sort {
+            sort: StationSort::Clicks,
+            descending: true,
+        }
+    );
+
+    let cmd = SlashCommand::parse("/sort Name desc").expect("parse /sort with direction");
+    assert_eq!(
+        cmd,
+        SlashCommand::Sort {
+            sort: StationSort::Name,
+            descending: true,
+        }
+    );
+    let cmd = SlashCommand::parse("/sort changed").expect("parse /sort changed");
+    assert_eq!(
+        cmd,
+        SlashCommand::Sort {
+            sort: StationSort::Changed,
+            descending: true,
+        }
+    );
 }
 
 #[test]
@@ -88,6 +266,11 @@ fn reject_unknown_filter_key() {
 fn reject_unknown_sort_field() {
     let err = SlashCommand::parse("/sort listeners").expect_err("invalid should fail");
     assert!(err.to_string().contains("invalid sort field"));
+
+    let err = SlashCommand::parse("/sort votes up").expect_err("invalid direction should fail");
+    assert!(err
+        .to_string()
+        .contains("invalid sort direction 'up' (expected asc or desc)"));
 }
 
 #[test]
@@ -96,6 +279,26 @@ fn reject_unknown_command() {
     assert!(err.to_string().contains("unknown command"));
 }
 
+#[test]
+fn parse_help_with_and_without_topic() {
+    assert_eq!(
+        SlashCommand::parse("/help").expect("parse"),
+        SlashCommand::Help(None)
+    );
+    assert_eq!(
+        SlashCommand::parse("/help /filter").expect("parse"),
+        SlashCommand::Help(Some(Action::Filter))
+    );
+}
+
+#[test]
+fn reject_help_for_unknown_command() {
+    let err = SlashCommand::parse("/help listen").expect_err("unknown topic should fail");
+    assert!(err
+        .to_string()
+        .contains("no help for unknown command: listen"));
+}
+
 #[test]
 fn reject_zero_play_index() {
     let err = SlashCommand::parse("/play 0").expect_err("index 0 should fail");
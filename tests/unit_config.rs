@@ -88,6 +88,7 @@ fn env_vars_override_file_values() {
     env::set_var("IRADIO_DEFAULT_SORT", "votes");
     env::set_var("IRADIO_DEFAULT_FILTER_COUNTRY", "US");
     env::set_var("IRADIO_DEFAULT_FILTER_MIN_BITRATE", "192");
+    env::set_var("IRADIO_CACHE_TTL_HOURS", "48");
 
     let config = RuntimeConfig::load_from_path(&config_path).expect("load config from path");
     assert_eq!(config.playback.mode, PlaybackMode::Http);
@@ -97,10 +98,43 @@ fn env_vars_override_file_values() {
     assert_eq!(config.defaults.sort, StationSort::Votes);
     assert_eq!(config.defaults.filters.country.as_deref(), Some("US"));
     assert_eq!(config.defaults.filters.min_bitrate, Some(192));
+    assert_eq!(config.cache.ttl_hours, 48);
 
     restore_env(&previous);
 }
 
+#[test]
+fn env_vars_override_playback_connection_settings() {
+    let _guard = ENV_LOCK.lock().expect("lock env");
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(
+        &config_path,
+        r#"
+            [playback]
+            mode = "http"
+            host = "file.host"
+            port = 8081
+            password = "file-secret"
+        "#,
+    )
+    .expect("write config");
+
+    let previous = snapshot_env();
+    clear_tracked_env();
+    env::set_var("IRADIO_VLC_HOST", "env.host");
+    env::set_var("IRADIO_VLC_PORT", "9090");
+    env::set_var("IRADIO_VLC_HTTP_PASSWORD", "env-secret");
+
+    let config = RuntimeConfig::load_from_path(&config_path).expect("load config from path");
+    restore_env(&previous);
+
+    assert_eq!(config.playback.mode, PlaybackMode::Http);
+    assert_eq!(config.playback.host(), "env.host");
+    assert_eq!(config.playback.port(), 9090);
+    assert_eq!(config.playback.password.as_deref(), Some("env-secret"));
+}
+
 fn snapshot_env() -> Vec<(&'static str, Option<String>)> {
     tracked_env_keys()
         .into_iter()
@@ -123,14 +157,49 @@ fn restore_env(previous: &[(&str, Option<String>)]) {
     }
 }
 
-fn tracked_env_keys() -> [&'static str; 7] {
+fn tracked_env_keys() -> [&'static str; 20] {
     [
         "IRADIO_PLAYBACK_MODE",
+        "IRADIO_LOG_FILE",
+        "IRADIO_LOG_FORMAT",
+        "IRADIO_VLC_HOST",
+        "IRADIO_VLC_PORT",
+        "IRADIO_VLC_HTTP_PASSWORD",
+        "IRADIO_MPV_SOCKET",
         "IRADIO_RADIO_BROWSER_BASE",
         "IRADIO_RADIO_BROWSER_TIMEOUT_MS",
         "IRADIO_RADIO_BROWSER_MAX_RETRIES",
+        "IRADIO_RADIO_BROWSER_MIRRORS",
+        "IRADIO_RADIO_BROWSER_DISCOVER",
         "IRADIO_DEFAULT_SORT",
         "IRADIO_DEFAULT_FILTER_COUNTRY",
+        "IRADIO_DEFAULT_FILTER_LANGUAGE",
+        "IRADIO_DEFAULT_FILTER_TAG",
+        "IRADIO_DEFAULT_FILTER_CODEC",
         "IRADIO_DEFAULT_FILTER_MIN_BITRATE",
+        "IRADIO_CACHE_TTL_HOURS",
+        "IRADIO_UI_COLUMNS",
     ]
 }
+
+#[test]
+fn unknown_keys_are_reported_as_warnings() {
+    let _guard = ENV_LOCK.lock().expect("lock env");
+    let dir = tempfile::tempdir().expect("create tempdir");
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(&config_path, "[radio_browser]\nretires = 3\nretries = 4\n")
+        .expect("write config");
+
+    let previous = snapshot_env();
+    clear_tracked_env();
+    let (config, warnings) =
+        RuntimeConfig::load_from_path_with_warnings(&config_path).expect("load config from path");
+    restore_env(&previous);
+
+    assert_eq!(config.radio_browser.retries, 4);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].to_string(),
+        "line 2: unknown key `radio_browser.retires`"
+    );
+}
@@ -0,0 +1,37 @@
+use iradio::storage::history::{HistoryEntry, HistoryStore, MAX_HISTORY_ENTRIES};
+
+fn entry(title: &str, played_at: u64) -> HistoryEntry {
+    HistoryEntry {
+        station_uuid: "station-1".to_string(),
+        station_name: "Sample Radio".to_string(),
+        title: title.to_string(),
+        played_at,
+    }
+}
+
+#[test]
+fn save_and_load_history_round_trip() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let store = HistoryStore::new(dir.path().join("history.json"));
+
+    assert!(store.load().expect("load missing history").is_empty());
+
+    let entries = vec![entry("First Song", 10), entry("Second Song", 20)];
+    store.save(&entries).expect("save history");
+    assert_eq!(store.load().expect("load history"), entries);
+}
+
+#[test]
+fn save_keeps_only_most_recent_entries() {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    let store = HistoryStore::new(dir.path().join("history.json"));
+
+    let entries: Vec<HistoryEntry> = (0..MAX_HISTORY_ENTRIES as u64 + 5)
+        .map(|idx| entry(&format!("Song {idx}"), idx))
+        .collect();
+    store.save(&entries).expect("save history");
+
+    let loaded = store.load().expect("load history");
+    assert_eq!(loaded.len(), MAX_HISTORY_ENTRIES);
+    assert_eq!(loaded[0].title, "Song 5");
+}
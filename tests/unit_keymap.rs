@@ -0,0 +1,71 @@
+use iradio::domain::actions::Action;
+use iradio::domain::keymap::{Key, KeyChord, Keymap};
+
+fn chord(value: &str) -> KeyChord {
+    KeyChord::parse(value).expect("parse chord")
+}
+
+#[test]
+fn parses_and_displays_chords() {
+    assert_eq!(chord("q"), KeyChord::new(Key::Char('q')));
+    assert_eq!(chord("shift+q"), KeyChord::new(Key::Char('Q')));
+    assert_eq!(chord("Ctrl+P"), KeyChord::ctrl(Key::Char('P')));
+    assert_eq!(chord("shift+tab"), KeyChord::new(Key::BackTab));
+    assert_eq!(chord("ctrl++"), KeyChord::ctrl(Key::Char('+')));
+    assert_eq!(chord("pgdn"), KeyChord::new(Key::PageDown));
+
+    assert_eq!(chord("ctrl+p").to_string(), "Ctrl+P");
+    assert_eq!(chord("space").to_string(), "Space");
+    assert_eq!(chord("alt+f5").to_string(), "Alt+F5");
+    assert_eq!(chord("shift+up").to_string(), "Shift+Up");
+}
+
+#[test]
+fn rejects_unknown_keys_and_modifiers() {
+    let err = KeyChord::parse("hyper+q").expect_err("unknown modifier");
+    assert!(err.to_string().contains("unknown modifier 'hyper'"));
+    let err = KeyChord::parse("ctrl+banana").expect_err("unknown key");
+    assert!(err.to_string().contains("unknown key 'banana'"));
+    assert!(KeyChord::parse("f13").is_err());
+}
+
+#[test]
+fn default_keymap_matches_documented_keys() {
+    let keymap = Keymap::default();
+    assert_eq!(keymap.action_for(&chord("q")), Some(Action::Quit));
+    assert_eq!(keymap.action_for(&chord("j")), Some(Action::SelectNext));
+    assert_eq!(
+        keymap.action_for(&chord("ctrl+p")),
+        Some(Action::TogglePalette)
+    );
+    assert_eq!(keymap.label(Action::SelectNext), "Down/j");
+    assert_eq!(keymap.action_for(&chord("x")), None);
+}
+
+#[test]
+fn overrides_replace_and_unbind_default_keys() {
+    let keymap = Keymap::with_overrides([
+        (Action::Quit, vec![chord("ctrl+q")]),
+        (Action::Stop, Vec::new()),
+    ])
+    .expect("valid overrides");
+
+    assert_eq!(keymap.action_for(&chord("q")), None);
+    assert_eq!(keymap.action_for(&chord("ctrl+q")), Some(Action::Quit));
+    assert_eq!(keymap.label(Action::Stop), "unbound");
+    assert!(keymap.keys_for(Action::Stop).is_empty());
+}
+
+#[test]
+fn overrides_that_collide_with_defaults_are_rejected() {
+    let err = Keymap::with_overrides([(Action::Quit, vec![chord("j")])])
+        .expect_err("j already selects next");
+    assert_eq!(
+        err.to_string(),
+        "j is bound to both quit and select-next; rebind or unbind one of them"
+    );
+
+    let err = Keymap::with_overrides([(Action::Stop, vec![chord("ctrl+c")])])
+        .expect_err("ctrl+c is reserved");
+    assert!(err.to_string().contains("Ctrl+C is reserved"));
+}